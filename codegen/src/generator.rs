@@ -14,6 +14,8 @@ impl Module {
         format!(
             "{docs}
 
+            #[allow(unused_imports)]
+            use std::borrow::Cow;
             #[allow(unused_imports)]
             use std::collections::HashMap;
             use std::convert::From;
@@ -154,6 +156,8 @@ impl Method {
                     {send_call}
                 }}
 
+                {iter}
+
                 {request}
 
                 {response}
@@ -182,29 +186,193 @@ impl Method {
                     .map(Param::get_pair)
                     .collect::<Vec<String>>()
                     .join(",\n"),
-                send_call = send_call
+                send_call = send_call,
+                iter = self.get_iter_fn(&fn_name, &request_struct_name, &error_enum_name)
             )
         }
     }
 
+    fn get_iter_fn(&self, fn_name: &str, request_type: &str, error_type: &str) -> String {
+        let pagination = match Pagination::for_method(&self.name) {
+            Some(p) => p,
+            None => return String::new(),
+        };
+        let lifetime = if self.params.iter()
+            .filter(|p| p.ty != "auth_token")
+            .any(|p| p.is_str()) { "<'a>" } else { "" };
+        format!("\
+            /// Like [`{method_name}`], but returns an iterator over every item, requesting further pages as
+            /// needed.
+            ///
+            /// [`{method_name}`]: fn.{method_name}.html
+            pub fn {method_name}_iter<'a, R>(
+                client: &'a R,
+                token: &'a str,
+                request: &{request_type}{lifetime},
+            ) -> ::paging::Items<'a, {item_type}, {error_type}<R::Error>>
+                where R: SlackWebRequestSender
+            {{
+                let mut request = request.clone();
+                ::paging::Items::new(move |next| {{
+                    if let Some(next) = next {{
+                        {apply}
+                    }}
+                    {method_name}(client, token, &request).map(|response| {{
+                        let next = {next};
+                        ::paging::Page {{ items: {items}.unwrap_or_default(), next }}
+                    }})
+                }})
+            }}",
+            method_name = fn_name,
+            request_type = request_type,
+            lifetime = lifetime,
+            item_type = pagination.item_type,
+            error_type = error_type,
+            apply = pagination.scheme.apply(),
+            next = pagination.next,
+            items = pagination.items
+        )
+    }
+
     fn get_request_struct(&self, ty_name: &str) -> String {
+        let params = self.params.iter()
+            .filter(|p| p.ty != "auth_token") // passed in method params instead
+            .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
+            .collect::<Vec<_>>();
+        let borrows = params.iter().any(|p| p.is_str());
+        let lifetime = if borrows { "<'a>" } else { "" };
+        let required = params.iter().filter(|p| !p.optional).collect::<Vec<_>>();
+
+        // Requests with required parameters must go through `new` so they can't silently default to "".
+        let constructor = if required.is_empty() {
+            String::new()
+        } else {
+            format!("\
+                /// Creates a request with all required parameters set.
+                pub fn new({args}) -> Self {{
+                    {request_type} {{
+                        {fields}
+                    }}
+                }}
+                ",
+                request_type = ty_name,
+                args = required.iter().map(|p| p.builder_arg()).collect::<Vec<_>>().join(", "),
+                fields = params.iter().map(|p| p.constructor_field()).collect::<Vec<_>>().join("\n")
+            )
+        };
+
+        let into_owned = if borrows {
+            format!("\
+                /// Converts this request into one that owns all of its data, so it can be stored or sent
+                /// across threads.
+                pub fn into_owned(self) -> {request_type}<'static> {{
+                    {request_type} {{
+                        {fields}
+                    }}
+                }}",
+                request_type = ty_name,
+                fields = params.iter().map(|p| p.owned_field()).collect::<Vec<_>>().join("\n")
+            )
+        } else {
+            String::new()
+        };
+
         format!("\
-            #[derive(Clone, Default, Debug)]
+            #[derive(Clone, {default}Debug, Serialize, Deserialize)]
             pub struct {request_type}{lifetime} {{
                 {request_params}
+            }}
+
+            impl{lifetime} {request_type}{lifetime} {{
+                {constructor}
+                {setters}
+                {into_owned}
             }}",
+            default = if required.is_empty() { "Default, " } else { "" },
             request_type = ty_name,
-            request_params = self.params.iter()
-                .filter(|p| p.ty != "auth_token") // passed in method params instead
-                .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
-                .map(Param::generate).collect::<Vec<String>>().join("\n"),
-            lifetime = if self.params.iter()
-                .filter(|p| p.ty != "auth_token")
-                .all(|p| p.ty == "integer" || p.ty == "boolean") { "" } else { "<'a>" }
+            request_params = params.iter().map(|p| p.generate()).collect::<Vec<String>>().join("\n"),
+            lifetime = lifetime,
+            constructor = constructor,
+            setters = params.iter().filter_map(|p| p.setter()).collect::<Vec<_>>().join("\n"),
+            into_owned = into_owned
         )
     }
 }
 
+enum PagingScheme {
+    /// Numbered pages, described by a `Paging` object.
+    Page,
+    /// Opaque cursors, returned in `response_metadata.next_cursor`.
+    Cursor,
+    /// Windows of time, moving `latest` back to the oldest message returned while `has_more` is set.
+    /// Only the first window honours `inclusive`; later ones would repeat the previous oldest message.
+    Window,
+}
+
+impl PagingScheme {
+    fn apply(&self) -> &'static str {
+        match *self {
+            PagingScheme::Page => "request.page = next.page();",
+            PagingScheme::Cursor => "request.cursor = next.cursor().map(|cursor| Cow::Owned(cursor.to_owned()));",
+            PagingScheme::Window => {
+                "request.latest = next.latest().map(|latest| Cow::Owned(latest.to_owned()));
+                 request.inclusive = None;"
+            }
+        }
+    }
+}
+
+/// Describes how to follow pagination for the methods that get a generated `*_iter` function.
+struct Pagination {
+    scheme: PagingScheme,
+    item_type: &'static str,
+    /// Expression taking the `Option<Vec<_>>` of items out of `response`.
+    items: &'static str,
+    /// Expression computing the `Option<NextPage>` from `response`.
+    next: &'static str,
+}
+
+impl Pagination {
+    fn for_method(name: &str) -> Option<Pagination> {
+        let page = |item_type, items| Pagination {
+            scheme: PagingScheme::Page,
+            item_type: item_type,
+            items: items,
+            next: "response.paging.as_ref().and_then(::paging::next_page)",
+        };
+        let cursor = |item_type, items| Pagination {
+            scheme: PagingScheme::Cursor,
+            item_type: item_type,
+            items: items,
+            next: "response.response_metadata.as_ref().and_then(::paging::next_cursor)",
+        };
+        let window = Pagination {
+            scheme: PagingScheme::Window,
+            item_type: "::Message",
+            items: "response.messages",
+            next: "::paging::next_window(response.has_more, response.messages.as_deref())",
+        };
+        match name {
+            "channels.history" | "groups.history" | "im.history" | "mpim.history" => Some(window),
+            "channels.list" => Some(cursor("::Channel", "response.channels")),
+            "conversations.history" => Some(cursor("::Message", "response.messages")),
+            "conversations.list" => Some(cursor("::Conversation", "response.channels")),
+            "conversations.members" => Some(cursor("String", "response.members")),
+            "conversations.replies" => Some(cursor("::Message", "response.messages")),
+            "users.list" => Some(cursor("::User", "response.members")),
+            "files.list" => Some(page("::File", "response.files")),
+            "reactions.list" => Some(page("ListResponseItem", "response.items")),
+            "stars.list" => Some(page("ListResponseItem", "response.items")),
+            "team.accessLogs" => Some(page("AccessLogsResponseLogin", "response.logins")),
+            "search.messages" => Some(Pagination {
+                next: "response.messages.as_ref().and_then(|m| m.paging.as_ref()).and_then(::paging::next_page)",
+                ..page("::Message", "response.messages.and_then(|m| m.matches)")
+            }),
+            _ => None,
+        }
+    }
+}
+
 pub trait Okable {
     fn has_ok(&self) -> bool;
 }
@@ -460,26 +628,76 @@ impl Param {
                 format!("Some((\"{name}\", &{name}[..]))", name = self.name)
             },
             (_, true) => {
-                format!("request.{name}.map(|{name}| (\"{name}\", {name}))", name = self.name)
+                format!("request.{name}.as_ref().map(|{name}| (\"{name}\", &{name}[..]))", name = self.name)
             },
             (_, false) => {
-                format!("Some((\"{name}\", request.{name}))", name = self.name)
+                format!("Some((\"{name}\", &request.{name}[..]))", name = self.name)
             }
         }
     }
 
-    fn get_rust_type(&self) -> String {
-        let ty = match &self.ty[..] {
+    fn is_str(&self) -> bool {
+        self.ty != "integer" && self.ty != "boolean"
+    }
+
+    fn get_base_type(&self) -> &'static str {
+        match &self.ty[..] {
             "boolean" => "bool",
             "integer" => "u32",
-            _ => "&'a str",
-        };
+            _ => "Cow<'a, str>",
+        }
+    }
+
+    fn get_rust_type(&self) -> String {
+        let ty = self.get_base_type();
         if self.optional {
             format!("Option<{}>", ty)
         } else {
             ty.to_owned()
         }
     }
+
+    fn builder_arg(&self) -> String {
+        if self.is_str() {
+            format!("{}: impl Into<Cow<'a, str>>", self.name)
+        } else {
+            format!("{}: {}", self.name, self.get_base_type())
+        }
+    }
+
+    fn constructor_field(&self) -> String {
+        match (self.is_str(), self.optional) {
+            (_, true) => format!("{}: None,", self.name),
+            (true, false) => format!("{name}: {name}.into(),", name = self.name),
+            (false, false) => format!("{},", self.name),
+        }
+    }
+
+    fn owned_field(&self) -> String {
+        match (self.is_str(), self.optional) {
+            (true, true) => format!("{name}: self.{name}.map(|{name}| Cow::Owned({name}.into_owned())),", name = self.name),
+            (true, false) => format!("{name}: Cow::Owned(self.{name}.into_owned()),", name = self.name),
+            (false, _) => format!("{name}: self.{name},", name = self.name),
+        }
+    }
+
+    fn setter(&self) -> Option<String> {
+        if !self.optional {
+            return None;
+        }
+        Some(format!("\
+            {documentation}
+            pub fn {name}(mut self, {arg}) -> Self {{
+                self.{name} = Some({name}{into});
+                self
+            }}
+            ",
+            documentation = format_docs("///", &self.description),
+            name = self.name,
+            arg = self.builder_arg(),
+            into = if self.is_str() { ".into()" } else { "" }
+        ))
+    }
 }
 
 impl JsonObjectFieldInfo {
@@ -494,6 +712,10 @@ impl JsonObjectFieldInfo {
             prefix.push_str("#[serde(default)]\n");
         }
 
+        if let PropType::Optional(_) = self.ty {
+            prefix.push_str("#[serde(skip_serializing_if = \"Option::is_none\")]\n");
+        }
+
         if self.name == "ok" {
             prefix.push_str("#[serde(default)]");
         } else if self.name != "error" && self.name != "ok" {
@@ -526,13 +748,34 @@ impl JsonEnumVariant {
 
 impl JsonEnum {
     pub fn to_code(&self) -> String {
-        // Hack to work around message having a different identifier here
-        let (variant_field, on_missing_field) = if self.name == "Message" {
+        // Hack to work around message having a different identifier here. Slack adds message
+        // subtypes regularly, so unrecognised ones are kept as raw JSON rather than failing the
+        // whole response.
+        let (variant_field, on_missing_field, unknown_variant, on_unknown) = if self.name == "Message" {
             ("subtype", "::serde_json::from_value::<MessageStandard>(value.clone())
                .map(Message::Standard)
-               .map_err(|e| D::Error::custom(&format!(\"{}\", e)))")
+               .map_err(|e| D::Error::custom(&format!(\"{}\", e)))",
+             "/// A message with a subtype this crate does not model yet.
+              Unknown {
+                  /// The unrecognised `subtype`.
+                  subtype: String,
+                  /// The complete message as received.
+                  raw: ::serde_json::Value,
+              },",
+             "Ok(Message::Unknown { subtype: ty.to_owned(), raw: value.clone() })")
         } else {
-            ("type", "Err(D::Error::missing_field(\"type\"))")
+            ("type", "Err(D::Error::missing_field(\"type\"))", "",
+             "Err(D::Error::unknown_variant(ty, VARIANTS))")
+        };
+        let variant_names = if unknown_variant.is_empty() {
+            format!("const VARIANTS: &'static [&'static str] = &[{}];",
+                    self.variants
+                        .iter()
+                        .map(|v| format!("\"{}\"", v.name.to_snake_case()))
+                        .collect::<Vec<_>>()
+                        .join(","))
+        } else {
+            String::new()
         };
 
         let mut subobjs = self.variants.clone();
@@ -548,6 +791,7 @@ impl JsonEnum {
             #[derive(Clone, Debug)]
             pub enum {name} {{
                 {variants}
+                {unknown_variant}
             }}
 
             impl<'de> ::serde::Deserialize<'de> for {name} {{
@@ -556,14 +800,14 @@ impl JsonEnum {
                 {{
                     use ::serde::de::Error as SerdeError;
 
-                    const VARIANTS: &'static [&'static str] = &[{variant_names}];
+                    {variant_names}
 
                     let value = ::serde_json::Value::deserialize(deserializer)?;
                     if let Some(ty_val) = value.get(\"{variant_field}\") {{
                         if let Some(ty) = ty_val.as_str() {{
                             match ty {{
                                 {variant_matches}
-                                _ => Err(D::Error::unknown_variant(ty, VARIANTS))
+                                _ => {on_unknown}
                             }}
                         }} else {{
                             Err(D::Error::invalid_type(::serde::de::Unexpected::Unit, &\"a string\"))
@@ -574,18 +818,35 @@ impl JsonEnum {
                 }}
             }}
 
+            impl ::serde::Serialize for {name} {{
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where S: ::serde::Serializer
+                {{
+                    match *self {{
+                        {variant_serializers}
+                        {on_serialize_unknown}
+                    }}
+                }}
+            }}
+
             {subobjs}",
             name = self.name,
-            variants = self.variants
+            variant_serializers = self.variants
                 .iter()
-                .map(|v| v.to_code())
+                .map(|v| format!("{}(ref inner) => inner.serialize(serializer),", v.qualified_name))
                 .collect::<Vec<_>>()
                 .join("\n"),
-            variant_names = self.variants
+            on_serialize_unknown = if unknown_variant.is_empty() {
+                ""
+            } else {
+                "Message::Unknown { ref raw, .. } => raw.serialize(serializer),"
+            },
+            variants = self.variants
                 .iter()
-                .map(|v| format!("\"{}\"", v.name.to_snake_case()))
+                .map(|v| v.to_code())
                 .collect::<Vec<_>>()
-                .join(","),
+                .join("\n"),
+            variant_names = variant_names,
             variant_matches = self.variants
                 .iter()
                 .map(|v| format!("\
@@ -602,11 +863,21 @@ impl JsonEnum {
                 .join("\n"),
             subobjs = subobjs,
             variant_field = variant_field,
-            on_missing_field = on_missing_field
+            on_missing_field = on_missing_field,
+            unknown_variant = unknown_variant,
+            on_unknown = on_unknown
         )
     }
 }
 
+/// Objects which callers build to send to Slack as well as receive, and so derive `Default` to be
+/// built with struct update syntax.
+const BUILT_BY_CALLERS: &'static [&'static str] = &[
+    "MessageStandardAttachment",
+    "MessageStandardAttachmentAction",
+    "MessageStandardAttachmentField",
+];
+
 fn obj_recur(prop: &PropType) -> Vec<String> {
     match *prop {
         PropType::Obj(ref o) => vec![o.to_code()],
@@ -633,12 +904,13 @@ impl JsonObject {
             .collect::<Vec<_>>();
 
         format!("\
-            #[derive(Clone, Debug, Deserialize)]
+            #[derive(Clone, Debug, {default}Deserialize, Serialize)]
             pub struct {name} {{
                 {fields}
             }}
 
             {subobjs}",
+            default = if BUILT_BY_CALLERS.contains(&&self.name[..]) { "Default, " } else { "" },
             name = self.name,
             fields = fields.join("\n"),
             subobjs = subobjs.join("\n")
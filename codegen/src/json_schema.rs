@@ -22,6 +22,10 @@ pub struct JsonSchema {
     pub additional_properties: bool,
     #[serde(rename = "$ref")]
     pub definition_ref: Option<String>,
+    // Non-standard keyword naming a crate type (relative to the crate root) to use as-is
+    // where the schemas have no equivalent, e.g. `blocks::Block`.
+    #[serde(rename = "x-rust-type")]
+    pub rust_type: Option<String>,
     #[serde(rename = "oneOf")]
     pub one_of: Option<Vec<JsonSchema>>,
 }
@@ -72,6 +76,10 @@ pub enum PropType {
 
 impl PropType {
     pub fn from_schema(schema: &JsonSchema, name: &str) -> Self {
+        if let Some(ref rust_type) = schema.rust_type {
+            return PropType::Ref(rust_type.clone());
+        }
+
         if let Some(ref def) = schema.definition_ref {
             // TODO: This ignores `#/` and assumes filenames refer to an existing struct with that
             //       name.
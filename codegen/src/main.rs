@@ -6,10 +6,14 @@ extern crate inflector;
 extern crate clap;
 extern crate rustfmt;
 
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
 use std::fs::{self, File, OpenOptions};
 use std::io::{self, Read, Write};
 use std::path::Path;
 
+use serde_json::Value;
+
 use inflector::Inflector;
 use clap::{Arg, App};
 
@@ -21,6 +25,87 @@ use generator::*;
 
 const SCHEMA_DIR: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/slack-api-schemas");
 const DEFAULT_OUT_DIR: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/../src");
+/// Schemas for parts of the API that the upstream schemas don't describe yet. See `read_schemas`.
+const OVERRIDES_DIR: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/overrides");
+
+/// Reads the schemas in `dir` of the upstream schemas, with the ones in the same directory of
+/// `overrides` merged in.
+///
+/// An override with the same file name as an upstream schema is merged into it, and any other is
+/// generated on its own, so regenerating keeps methods, parameters, errors and fields that were
+/// added here.
+fn read_schemas(dir: &str) -> io::Result<BTreeMap<String, Value>> {
+    let mut schemas = BTreeMap::new();
+
+    for root in &[SCHEMA_DIR, OVERRIDES_DIR] {
+        let schema_path = Path::new(root).join(dir);
+        if !schema_path.is_dir() {
+            continue;
+        }
+
+        for entry in fs::read_dir(schema_path)? {
+            if let Ok(e) = entry {
+                let path = e.path();
+                if path.is_file() {
+                    let mut schema_file = File::open(&path)?;
+                    let mut schema_contents = String::new();
+                    schema_file.read_to_string(&mut schema_contents)?;
+
+                    let schema = serde_json::from_str::<Value>(&schema_contents)
+                        .expect(&format!("Could not parse schema {}", path.display()));
+
+                    match schemas.entry(path.file_name().unwrap().to_str().unwrap().to_owned()) {
+                        Entry::Occupied(mut e) => merge(e.get_mut(), schema),
+                        Entry::Vacant(e) => {
+                            e.insert(schema);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(schemas)
+}
+
+/// Merges an override into a schema. Objects are merged by key, and arrays by item, matching items
+/// by their `name`, `title` or `id` (so methods, params, errors and `oneOf` variants can be amended
+/// one at a time) and appending the ones that don't match. Anything else is replaced.
+fn merge(schema: &mut Value, patch: Value) {
+    match (schema, patch) {
+        (&mut Value::Object(ref mut schema), Value::Object(patch)) => {
+            for (key, value) in patch {
+                if let Some(existing) = schema.get_mut(&key) {
+                    merge(existing, value);
+                    continue;
+                }
+                schema.insert(key, value);
+            }
+        }
+        (&mut Value::Array(ref mut schema), Value::Array(patch)) => {
+            for item in patch {
+                if let Some(existing) = schema.iter_mut().find(|existing| same_item(existing, &item)) {
+                    merge(existing, item);
+                    continue;
+                }
+                schema.push(item);
+            }
+        }
+        (schema, patch) => *schema = patch,
+    }
+}
+
+fn same_item(a: &Value, b: &Value) -> bool {
+    // `oneOf` variants are named after their title or id, the same way `PropType::from_schema` does.
+    let key = |v: &Value| {
+        v.get("name")
+            .or_else(|| v.get("title"))
+            .or_else(|| v.get("id"))
+            .and_then(Value::as_str)
+            .map(|key| key.to_pascal_case())
+    };
+    a == b || (key(a).is_some() && key(a) == key(b))
+}
 
 fn generate_types(output_path: &Path) -> io::Result<()> {
     let codegen_filepath = output_path.join("types.rs");
@@ -33,30 +118,19 @@ fn generate_types(output_path: &Path) -> io::Result<()> {
 
     types_file.write_all(b"use std::collections::HashMap;\n\n")?;
 
-    let schema_path = Path::new(SCHEMA_DIR);
-
-    for entry in fs::read_dir(schema_path.join("objects"))? {
-        if let Ok(e) = entry {
-            let path = e.path();
-            if path.is_file() {
-                let mut schema_file = File::open(&path)?;
-                let mut schema_contents = String::new();
-                schema_file.read_to_string(&mut schema_contents)?;
+    for (name, schema) in read_schemas("objects")? {
+        let schema = serde_json::from_value::<JsonSchema>(schema)
+            .expect(&format!("Could not parse object schema for {}", name));
 
-                let schema = serde_json::from_str::<JsonSchema>(&schema_contents)
-                    .expect(&format!("Could not parse object schema for {}", path.display()));
+        let ty_name = name.trim_end_matches(".json").to_pascal_case();
 
-                let ty_name = path.file_stem().unwrap().to_str().unwrap().to_pascal_case();
+        let ty = match PropType::from_schema(&schema, &ty_name) {
+            PropType::Obj(ref o) => o.to_code(),
+            PropType::Enum(ref e) => e.to_code(),
+            _ => panic!("Object schema is not an object."),
+        };
 
-                let ty = match PropType::from_schema(&schema, &ty_name) {
-                    PropType::Obj(ref o) => o.to_code(),
-                    PropType::Enum(ref e) => e.to_code(),
-                    _ => panic!("Object schema is not an object."),
-                };
-
-                types_file.write_all(ty.as_bytes())?;
-            }
-        }
+        types_file.write_all(ty.as_bytes())?;
     }
 
     {
@@ -71,36 +145,25 @@ fn generate_types(output_path: &Path) -> io::Result<()> {
 fn generate_modules(output_path: &Path) -> io::Result<()> {
     let mut mods = vec![];
 
-    let schema_path = Path::new(SCHEMA_DIR);
-
-    for entry in fs::read_dir(schema_path.join("web"))? {
-        if let Ok(e) = entry {
-            let path = e.path();
-            if path.is_file() {
-                let mut schema_file = File::open(&path)?;
-                let mut schema_contents = String::new();
-                schema_file.read_to_string(&mut schema_contents)?;
+    for (name, schema) in read_schemas("web")? {
+        let module = serde_json::from_value::<Module>(schema)
+            .expect(&format!("Could not parse module schema for {}", name));
+        mods.push(module.get_safe_name());
 
-                let module = serde_json::from_str::<Module>(&schema_contents)
-                    .expect(&format!("Could not parse module schema for {}", path.display()));
-                mods.push(module.get_safe_name());
+        let out_filepath = output_path.join(format!("{}.rs", module.get_safe_name()));
 
-                let out_filepath = output_path.join(format!("{}.rs", module.get_safe_name()));
+        let mut out_file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(&out_filepath)?;
 
-                let mut out_file = OpenOptions::new()
-                    .write(true)
-                    .truncate(true)
-                    .create(true)
-                    .open(&out_filepath)?;
+        out_file.write_all(module.generate().as_bytes())?;
 
-                out_file.write_all(module.generate().as_bytes())?;
-
-                {
-                    let mut rustfmt_config = rustfmt::config::Config::default();
-                    rustfmt_config.set().write_mode(rustfmt::config::WriteMode::Overwrite);
-                    let _ = rustfmt::run(rustfmt::Input::File(out_filepath), &rustfmt_config);
-                }
-            }
+        {
+            let mut rustfmt_config = rustfmt::config::Config::default();
+            rustfmt_config.set().write_mode(rustfmt::config::WriteMode::Overwrite);
+            let _ = rustfmt::run(rustfmt::Input::File(out_filepath), &rustfmt_config);
         }
     }
 
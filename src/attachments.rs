@@ -0,0 +1,170 @@
+//! Building [message attachments](https://api.slack.com/reference/messaging/attachments).
+//!
+//! Attachments are sent and received as the same types, so an attachment read from a message can
+//! be modified and posted again:
+//!
+//! ```
+//! use slack_api::attachments::{Attachment, AttachmentField};
+//! use slack_api::chat::PostMessageRequest;
+//!
+//! let attachment = Attachment::new("Build 42 failed")
+//!     .color("danger")
+//!     .title("Build 42")
+//!     .field(AttachmentField::new("Branch", "main").short(true));
+//! let request = PostMessageRequest::new("#ci", "").with_attachments(&[attachment]);
+//! # let _ = request;
+//! ```
+
+use serde_json;
+
+use chat::{PostMessageRequest, UpdateRequest};
+use types::{MessageStandardAttachment, MessageStandardAttachmentAction,
+            MessageStandardAttachmentField, Timestamp};
+
+pub type Attachment = MessageStandardAttachment;
+pub type AttachmentAction = MessageStandardAttachmentAction;
+pub type AttachmentField = MessageStandardAttachmentField;
+
+/// Serializes `attachments` to the JSON string expected by the `attachments` parameter of `chat`
+/// methods.
+pub fn to_json(attachments: &[Attachment]) -> String {
+    serde_json::to_string(attachments).expect("attachments always serialize to JSON")
+}
+
+impl MessageStandardAttachment {
+    /// Creates an attachment with the plain-text summary shown by clients which can't display it.
+    pub fn new(fallback: impl Into<String>) -> Self {
+        MessageStandardAttachment { fallback: Some(fallback.into()), ..Default::default() }
+    }
+
+    /// Appends a field to the table at the bottom of the attachment.
+    pub fn field(mut self, field: AttachmentField) -> Self {
+        self.fields.get_or_insert_with(Vec::new).push(field);
+        self
+    }
+
+    /// Appends a button. Buttons also require `callback_id` to be set.
+    pub fn action(mut self, action: AttachmentAction) -> Self {
+        self.actions.get_or_insert_with(Vec::new).push(action);
+        self
+    }
+}
+
+setters!(MessageStandardAttachment {
+    /// Either `good`, `warning`, `danger` or a hex colour such as `#439FE0`.
+    color: String,
+    /// Text shown above the attachment.
+    pretext: String,
+    author_name: String,
+    author_link: String,
+    author_icon: String,
+    title: String,
+    title_link: String,
+    text: String,
+    /// Replaces all fields.
+    fields: Vec<AttachmentField>,
+    image_url: String,
+    thumb_url: String,
+    footer: String,
+    footer_icon: String,
+    /// The time shown next to the footer.
+    ts: Timestamp,
+    /// Replaces all actions.
+    actions: Vec<AttachmentAction>,
+    /// Identifies the attachment in the payloads sent when its actions are used.
+    callback_id: String,
+    /// The names of the fields (`pretext`, `text` or `fields`) which are formatted as mrkdwn.
+    mrkdwn_in: Vec<String>,
+});
+
+impl MessageStandardAttachmentField {
+    pub fn new(title: impl Into<String>, value: impl Into<String>) -> Self {
+        MessageStandardAttachmentField {
+            title: Some(title.into()),
+            value: Some(value.into()),
+            short: None,
+        }
+    }
+}
+
+setters!(MessageStandardAttachmentField {
+    /// Whether the field is narrow enough to be shown next to another.
+    short: bool,
+});
+
+impl MessageStandardAttachmentAction {
+    /// Creates a button which sends `value` when clicked.
+    pub fn button(name: impl Into<String>, text: impl Into<String>, value: impl Into<String>) -> Self {
+        MessageStandardAttachmentAction {
+            name: Some(name.into()),
+            text: Some(text.into()),
+            ty: Some("button".to_owned()),
+            value: Some(value.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a button which opens `url`.
+    pub fn link_button(text: impl Into<String>, url: impl Into<String>) -> Self {
+        MessageStandardAttachmentAction {
+            text: Some(text.into()),
+            ty: Some("button".to_owned()),
+            url: Some(url.into()),
+            ..Default::default()
+        }
+    }
+}
+
+setters!(MessageStandardAttachmentAction {
+    /// Either `primary` or `danger`.
+    style: String,
+});
+
+impl<'a> PostMessageRequest<'a> {
+    /// Sets `attachments` to the JSON encoding of `attachments`.
+    pub fn with_attachments(self, attachments: &[Attachment]) -> Self {
+        self.attachments(to_json(attachments))
+    }
+}
+
+impl<'a> UpdateRequest<'a> {
+    /// Sets `attachments` to the JSON encoding of `attachments`.
+    pub fn with_attachments(self, attachments: &[Attachment]) -> Self {
+        self.attachments(to_json(attachments))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use tests::{param, Replay};
+    use chat;
+
+    #[test]
+    fn test_attachments_serialize_and_parse_back() {
+        let attachments = vec![
+            Attachment::new("deploy")
+                .color("good")
+                .author_name("ci")
+                .field(AttachmentField::new("Env", "prod").short(true))
+                .callback_id("deploy")
+                .action(AttachmentAction::button("go", "Go", "1").style("primary"))
+                .ts(Timestamp::new(1500000000.0)),
+        ];
+        let client = Replay::new(vec![r#"{"ok": true}"#]);
+        let request = chat::PostMessageRequest::new("C1", "").with_attachments(&attachments);
+        chat::post_message(&client, "token", &request).unwrap();
+
+        let json = param(&client.requests.borrow()[0], "attachments").unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!("good", value[0]["color"]);
+        assert_eq!(1500000000.0, value[0]["ts"]);
+        assert_eq!("button", value[0]["actions"][0]["type"]);
+        assert!(value[0].get("pretext").is_none());
+
+        let parsed: Vec<Attachment> = serde_json::from_str(&json).unwrap();
+        assert_eq!(Some(true), parsed[0].fields.as_ref().unwrap()[0].short);
+        assert_eq!(Some(1500000000.0), parsed[0].ts.as_ref().map(Timestamp::as_f64));
+    }
+}
@@ -0,0 +1,770 @@
+//! Typed [Block Kit](https://api.slack.com/block-kit) layouts.
+//!
+//! Blocks are built from the `*Block` structs, which contain text objects and interactive
+//! `*Element`s. Each has a `new` function taking its required fields, and a setter for every
+//! optional one:
+//!
+//! ```
+//! use slack_api::blocks::{self, ButtonElement, ButtonStyle, SectionBlock, Text};
+//!
+//! let blocks = vec![
+//!     SectionBlock::new(Text::markdown("*Deploy* `main` to production?"))
+//!         .accessory(ButtonElement::new(Text::plain("Deploy"), "deploy").style(ButtonStyle::Primary))
+//!         .into(),
+//! ];
+//! let request = slack_api::chat::PostMessageRequest::new("#ops", "Deploy main to production?")
+//!     .blocks(blocks::to_json(&blocks));
+//! # let _ = request;
+//! ```
+//!
+//! Block and element types this crate does not model yet are kept as `Unknown`, so messages using
+//! them still deserialize and serialize back unchanged.
+
+use serde_json::{self, Value};
+
+/// Serializes `blocks` to the JSON string expected by the `blocks` parameter of `chat` methods.
+pub fn to_json(blocks: &[Block]) -> String {
+    serde_json::to_string(blocks).expect("blocks always serialize to JSON")
+}
+
+/// A text object.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Text {
+    #[serde(rename = "plain_text")]
+    Plain(PlainText),
+    #[serde(rename = "mrkdwn")]
+    Markdown(MarkdownText),
+}
+
+impl Text {
+    /// Creates a `plain_text` object.
+    pub fn plain(text: impl Into<String>) -> Self {
+        Text::Plain(PlainText::new(text))
+    }
+
+    /// Creates a `mrkdwn` object.
+    pub fn markdown(text: impl Into<String>) -> Self {
+        Text::Markdown(MarkdownText::new(text))
+    }
+
+    /// The text, without any formatting applied.
+    pub fn text(&self) -> &str {
+        match *self {
+            Text::Plain(ref t) => &t.text,
+            Text::Markdown(ref t) => &t.text,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlainText {
+    pub text: String,
+    /// Whether emoji shortcodes such as `:smile:` are shown as emoji.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<bool>,
+}
+
+impl PlainText {
+    pub fn new(text: impl Into<String>) -> Self {
+        PlainText { text: text.into(), emoji: None }
+    }
+}
+
+setters!(PlainText {
+    /// Whether emoji shortcodes such as `:smile:` are shown as emoji.
+    emoji: bool,
+});
+
+impl From<PlainText> for Text {
+    fn from(text: PlainText) -> Self {
+        Text::Plain(text)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MarkdownText {
+    pub text: String,
+    /// Disables the automatic linking of URLs, channel names and mentions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verbatim: Option<bool>,
+}
+
+impl MarkdownText {
+    pub fn new(text: impl Into<String>) -> Self {
+        MarkdownText { text: text.into(), verbatim: None }
+    }
+}
+
+setters!(MarkdownText {
+    /// Disables the automatic linking of URLs, channel names and mentions.
+    verbatim: bool,
+});
+
+impl From<MarkdownText> for Text {
+    fn from(text: MarkdownText) -> Self {
+        Text::Markdown(text)
+    }
+}
+
+/// An option of a select menu or overflow menu.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SelectOption {
+    pub text: Text,
+    /// The value sent in the interaction payload when this option is chosen.
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Text>,
+    /// A URL to open when this option is chosen. Only supported in overflow menus.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl SelectOption {
+    pub fn new(text: Text, value: impl Into<String>) -> Self {
+        SelectOption { text, value: value.into(), description: None, url: None }
+    }
+}
+
+setters!(SelectOption {
+    description: Text,
+    /// A URL to open when this option is chosen. Only supported in overflow menus.
+    url: String,
+});
+
+/// A labelled group of options in a select menu.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OptionGroup {
+    pub label: Text,
+    pub options: Vec<SelectOption>,
+}
+
+impl OptionGroup {
+    pub fn new(label: Text, options: Vec<SelectOption>) -> Self {
+        OptionGroup { label, options }
+    }
+}
+
+/// A dialog asking the user to confirm an action before it is sent.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConfirmationDialog {
+    pub title: Text,
+    pub text: Text,
+    /// The label of the button which confirms the action.
+    pub confirm: Text,
+    /// The label of the button which cancels the action.
+    pub deny: Text,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+}
+
+impl ConfirmationDialog {
+    pub fn new(title: Text, text: Text, confirm: Text, deny: Text) -> Self {
+        ConfirmationDialog { title, text, confirm, deny, style: None }
+    }
+}
+
+setters!(ConfirmationDialog {
+    style: ButtonStyle,
+});
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ButtonStyle {
+    Primary,
+    Danger,
+}
+
+tagged_enum! {
+    /// A layout block.
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Block {
+        Section(SectionBlock) = "section",
+        Actions(ActionsBlock) = "actions",
+        Context(ContextBlock) = "context",
+        Divider(DividerBlock) = "divider",
+        Image(ImageBlock) = "image",
+        Input(InputBlock) = "input",
+        Header(HeaderBlock) = "header",
+    }
+}
+
+impl Block {
+    /// The block's `block_id`, if it has one.
+    pub fn block_id(&self) -> Option<&str> {
+        let block_id = match *self {
+            Block::Section(ref b) => &b.block_id,
+            Block::Actions(ref b) => &b.block_id,
+            Block::Context(ref b) => &b.block_id,
+            Block::Divider(ref b) => &b.block_id,
+            Block::Image(ref b) => &b.block_id,
+            Block::Input(ref b) => &b.block_id,
+            Block::Header(ref b) => &b.block_id,
+            Block::Unknown { ref raw, .. } => return raw.get("block_id").and_then(Value::as_str),
+        };
+        block_id.as_deref()
+    }
+}
+
+/// Text, optionally laid out in two columns of fields, with an optional element beside it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SectionBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<Text>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessory: Option<BlockElement>,
+}
+
+impl SectionBlock {
+    /// Creates a section showing `text`. Use `Default` for a section with only fields.
+    pub fn new(text: Text) -> Self {
+        SectionBlock { text: Some(text), ..Default::default() }
+    }
+}
+
+setters!(SectionBlock {
+    text: Text,
+    block_id: String,
+    fields: Vec<Text>,
+    accessory: BlockElement,
+});
+
+/// A row of interactive elements.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActionsBlock {
+    pub elements: Vec<BlockElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl ActionsBlock {
+    pub fn new(elements: Vec<BlockElement>) -> Self {
+        ActionsBlock { elements, block_id: None }
+    }
+}
+
+setters!(ActionsBlock {
+    block_id: String,
+});
+
+/// A line of small text and images.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContextBlock {
+    pub elements: Vec<ContextElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl ContextBlock {
+    pub fn new(elements: Vec<ContextElement>) -> Self {
+        ContextBlock { elements, block_id: None }
+    }
+}
+
+setters!(ContextBlock {
+    block_id: String,
+});
+
+/// A horizontal rule.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DividerBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl DividerBlock {
+    pub fn new() -> Self {
+        DividerBlock::default()
+    }
+}
+
+setters!(DividerBlock {
+    block_id: String,
+});
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageBlock {
+    pub image_url: String,
+    /// A plain-text summary of the image.
+    pub alt_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl ImageBlock {
+    pub fn new(image_url: impl Into<String>, alt_text: impl Into<String>) -> Self {
+        ImageBlock { image_url: image_url.into(), alt_text: alt_text.into(), title: None, block_id: None }
+    }
+}
+
+setters!(ImageBlock {
+    title: Text,
+    block_id: String,
+});
+
+/// A labelled input element, used in modals and messages.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InputBlock {
+    pub label: Text,
+    pub element: BlockElement,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    /// Help text shown below the element.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Text>,
+    /// Whether the input may be left empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+    /// Whether changing the element sends a `block_actions` payload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action: Option<bool>,
+}
+
+impl InputBlock {
+    pub fn new(label: Text, element: impl Into<BlockElement>) -> Self {
+        InputBlock {
+            label,
+            element: element.into(),
+            block_id: None,
+            hint: None,
+            optional: None,
+            dispatch_action: None,
+        }
+    }
+}
+
+setters!(InputBlock {
+    block_id: String,
+    /// Help text shown below the element.
+    hint: Text,
+    /// Whether the input may be left empty.
+    optional: bool,
+    /// Whether changing the element sends a `block_actions` payload.
+    dispatch_action: bool,
+});
+
+/// Large, bold plain text.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HeaderBlock {
+    pub text: Text,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl HeaderBlock {
+    pub fn new(text: impl Into<String>) -> Self {
+        HeaderBlock { text: Text::plain(text), block_id: None }
+    }
+}
+
+setters!(HeaderBlock {
+    block_id: String,
+});
+
+tagged_enum! {
+    /// An element of a context block.
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum ContextElement {
+        Image(ImageElement) = "image",
+        Plain(PlainText) = "plain_text",
+        Markdown(MarkdownText) = "mrkdwn",
+    }
+}
+
+impl From<Text> for ContextElement {
+    fn from(text: Text) -> Self {
+        match text {
+            Text::Plain(text) => ContextElement::Plain(text),
+            Text::Markdown(text) => ContextElement::Markdown(text),
+        }
+    }
+}
+
+tagged_enum! {
+    /// An interactive element, or an image.
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum BlockElement {
+        Button(ButtonElement) = "button",
+        StaticSelect(StaticSelectElement) = "static_select",
+        ExternalSelect(ExternalSelectElement) = "external_select",
+        UsersSelect(UsersSelectElement) = "users_select",
+        ConversationsSelect(ConversationsSelectElement) = "conversations_select",
+        ChannelsSelect(ChannelsSelectElement) = "channels_select",
+        Overflow(OverflowElement) = "overflow",
+        Datepicker(DatepickerElement) = "datepicker",
+        PlainTextInput(PlainTextInputElement) = "plain_text_input",
+        Image(ImageElement) = "image",
+    }
+}
+
+impl BlockElement {
+    /// The element's `action_id`, which identifies it in interaction payloads.
+    pub fn action_id(&self) -> Option<&str> {
+        let action_id = match *self {
+            BlockElement::Button(ref e) => &e.action_id,
+            BlockElement::StaticSelect(ref e) => &e.action_id,
+            BlockElement::ExternalSelect(ref e) => &e.action_id,
+            BlockElement::UsersSelect(ref e) => &e.action_id,
+            BlockElement::ConversationsSelect(ref e) => &e.action_id,
+            BlockElement::ChannelsSelect(ref e) => &e.action_id,
+            BlockElement::Overflow(ref e) => &e.action_id,
+            BlockElement::Datepicker(ref e) => &e.action_id,
+            BlockElement::PlainTextInput(ref e) => &e.action_id,
+            BlockElement::Image(_) => return None,
+            BlockElement::Unknown { ref raw, .. } => {
+                return raw.get("action_id").and_then(Value::as_str)
+            }
+        };
+        Some(&action_id[..])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ButtonElement {
+    pub text: Text,
+    pub action_id: String,
+    /// A URL to open in the user's browser when the button is clicked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The value sent in the interaction payload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl ButtonElement {
+    pub fn new(text: Text, action_id: impl Into<String>) -> Self {
+        ButtonElement {
+            text,
+            action_id: action_id.into(),
+            url: None,
+            value: None,
+            style: None,
+            confirm: None,
+        }
+    }
+}
+
+setters!(ButtonElement {
+    /// A URL to open in the user's browser when the button is clicked.
+    url: String,
+    /// The value sent in the interaction payload.
+    value: String,
+    style: ButtonStyle,
+    confirm: ConfirmationDialog,
+});
+
+/// A select menu with a fixed list of options.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StaticSelectElement {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<SelectOption>>,
+    /// Options in labelled groups, used instead of `options`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option_groups: Option<Vec<OptionGroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl StaticSelectElement {
+    pub fn new(placeholder: Text, action_id: impl Into<String>, options: Vec<SelectOption>) -> Self {
+        StaticSelectElement {
+            placeholder,
+            action_id: action_id.into(),
+            options: Some(options),
+            option_groups: None,
+            initial_option: None,
+            confirm: None,
+        }
+    }
+}
+
+setters!(StaticSelectElement {
+    /// Options in labelled groups, used instead of `options`.
+    option_groups: Vec<OptionGroup>,
+    initial_option: SelectOption,
+    confirm: ConfirmationDialog,
+});
+
+/// A select menu whose options are loaded from your app's options load URL.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExternalSelectElement {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<SelectOption>,
+    /// How many characters must be typed before options are requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_query_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl ExternalSelectElement {
+    pub fn new(placeholder: Text, action_id: impl Into<String>) -> Self {
+        ExternalSelectElement {
+            placeholder,
+            action_id: action_id.into(),
+            initial_option: None,
+            min_query_length: None,
+            confirm: None,
+        }
+    }
+}
+
+setters!(ExternalSelectElement {
+    initial_option: SelectOption,
+    /// How many characters must be typed before options are requested.
+    min_query_length: u32,
+    confirm: ConfirmationDialog,
+});
+
+/// A select menu listing the users of the workspace.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UsersSelectElement {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl UsersSelectElement {
+    pub fn new(placeholder: Text, action_id: impl Into<String>) -> Self {
+        UsersSelectElement { placeholder, action_id: action_id.into(), initial_user: None, confirm: None }
+    }
+}
+
+setters!(UsersSelectElement {
+    initial_user: String,
+    confirm: ConfirmationDialog,
+});
+
+/// A select menu listing public channels, private channels and direct messages.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConversationsSelectElement {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_conversation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl ConversationsSelectElement {
+    pub fn new(placeholder: Text, action_id: impl Into<String>) -> Self {
+        ConversationsSelectElement {
+            placeholder,
+            action_id: action_id.into(),
+            initial_conversation: None,
+            confirm: None,
+        }
+    }
+}
+
+setters!(ConversationsSelectElement {
+    initial_conversation: String,
+    confirm: ConfirmationDialog,
+});
+
+/// A select menu listing public channels.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChannelsSelectElement {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl ChannelsSelectElement {
+    pub fn new(placeholder: Text, action_id: impl Into<String>) -> Self {
+        ChannelsSelectElement {
+            placeholder,
+            action_id: action_id.into(),
+            initial_channel: None,
+            confirm: None,
+        }
+    }
+}
+
+setters!(ChannelsSelectElement {
+    initial_channel: String,
+    confirm: ConfirmationDialog,
+});
+
+/// A "…" button which opens a menu of options.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OverflowElement {
+    pub action_id: String,
+    pub options: Vec<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl OverflowElement {
+    pub fn new(action_id: impl Into<String>, options: Vec<SelectOption>) -> Self {
+        OverflowElement { action_id: action_id.into(), options, confirm: None }
+    }
+}
+
+setters!(OverflowElement {
+    confirm: ConfirmationDialog,
+});
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DatepickerElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    /// The date selected initially, formatted as `YYYY-MM-DD`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl DatepickerElement {
+    pub fn new(action_id: impl Into<String>) -> Self {
+        DatepickerElement { action_id: action_id.into(), placeholder: None, initial_date: None, confirm: None }
+    }
+}
+
+setters!(DatepickerElement {
+    placeholder: Text,
+    /// The date selected initially, formatted as `YYYY-MM-DD`.
+    initial_date: String,
+    confirm: ConfirmationDialog,
+});
+
+/// A free-text field, for use in input blocks.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlainTextInputElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiline: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u32>,
+}
+
+impl PlainTextInputElement {
+    pub fn new(action_id: impl Into<String>) -> Self {
+        PlainTextInputElement {
+            action_id: action_id.into(),
+            placeholder: None,
+            initial_value: None,
+            multiline: None,
+            min_length: None,
+            max_length: None,
+        }
+    }
+}
+
+setters!(PlainTextInputElement {
+    placeholder: Text,
+    initial_value: String,
+    multiline: bool,
+    min_length: u32,
+    max_length: u32,
+});
+
+/// An image, for use in section accessories and context blocks.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageElement {
+    pub image_url: String,
+    /// A plain-text summary of the image.
+    pub alt_text: String,
+}
+
+impl ImageElement {
+    pub fn new(image_url: impl Into<String>, alt_text: impl Into<String>) -> Self {
+        ImageElement { image_url: image_url.into(), alt_text: alt_text.into() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks_round_trip() {
+        let blocks: Vec<Block> = vec![
+            HeaderBlock::new("Release").into(),
+            SectionBlock::new(Text::markdown("*v1.2* is ready"))
+                .accessory(ImageElement::new("https://example.com/a.png", "logo"))
+                .into(),
+            DividerBlock::new().into(),
+            ActionsBlock::new(vec![
+                ButtonElement::new(Text::plain("Ship"), "ship").value("1.2").style(ButtonStyle::Primary).into(),
+                StaticSelectElement::new(Text::plain("Env"), "env", vec![SelectOption::new(Text::plain("Prod"), "prod")]).into(),
+                OverflowElement::new("more", vec![SelectOption::new(Text::plain("Logs"), "logs")]).into(),
+                DatepickerElement::new("when").initial_date("2020-01-02").into(),
+            ]).block_id("actions").into(),
+            ContextBlock::new(vec![Text::plain("by U1").into()]).into(),
+            InputBlock::new(Text::plain("Notes"), PlainTextInputElement::new("notes").multiline(true))
+                .optional(true)
+                .into(),
+        ];
+
+        let json = to_json(&blocks);
+        let parsed: Vec<Block> = serde_json::from_str(&json).unwrap();
+        assert_eq!(blocks, parsed);
+
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!("header", value[0]["type"]);
+        assert_eq!("plain_text", value[0]["text"]["type"]);
+        assert_eq!("button", value[3]["elements"][0]["type"]);
+        assert_eq!("primary", value[3]["elements"][0]["style"]);
+        assert!(value[2].get("block_id").is_none());
+        assert_eq!(Some("actions"), parsed[3].block_id());
+    }
+
+    #[test]
+    fn test_unknown_block_kept_verbatim() {
+        let json = r#"{"type": "rich_text", "block_id": "x", "elements": [{"type": "rich_text_section", "elements": []}]}"#;
+        let block: Block = serde_json::from_str(json).unwrap();
+
+        assert_eq!("rich_text", block.ty());
+        assert_eq!(Some("x"), block.block_id());
+        assert_eq!(
+            serde_json::from_str::<Value>(json).unwrap(),
+            serde_json::to_value(&block).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_message_blocks_deserialize() {
+        let message: ::Message = serde_json::from_str(r#"{"type": "message", "ts": "1.0", "text": "hi", "blocks": [
+            {"type": "section", "text": {"type": "mrkdwn", "text": "*hi*"}},
+            {"type": "rich_text", "elements": []}]}"#).unwrap();
+        let blocks = message.blocks();
+
+        assert_eq!(2, blocks.len());
+        match blocks[0] {
+            Block::Section(ref section) => assert_eq!("*hi*", section.text.as_ref().unwrap().text()),
+            ref other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!("rich_text", blocks[1].ty());
+    }
+}
@@ -0,0 +1,497 @@
+//! Typed events, as sent by the [Real Time Messaging API](https://api.slack.com/rtm) and, inside
+//! an [`events_api::EventCallback`], by the Events API.
+//!
+//! Every event is an object with a `type` field, which selects the variant of [`Event`]. Types
+//! this crate does not model yet are kept as `Unknown` with their JSON intact; the others
+//! serialize back to their `type` and modelled fields, dropping any the crate doesn't know about:
+//!
+//! ```
+//! use slack_api::event::Event;
+//!
+//! let event: Event = serde_json::from_str(
+//!     r#"{"type": "user_typing", "channel": "C024BE91L", "user": "U024BE7LH"}"#,
+//! ).unwrap();
+//! match event {
+//!     Event::UserTyping(ref typing) => assert_eq!(Some("U024BE7LH"), typing.user.as_deref()),
+//!     _ => unreachable!(),
+//! }
+//! ```
+//!
+//! [`Event`]: enum.Event.html
+//! [`events_api::EventCallback`]: ../events_api/struct.EventCallback.html
+
+use serde_json::Value;
+
+use types::{Bot, Channel, File, FileComment, Group, Im, Message, Mpim, User, Usergroup};
+
+tagged_enum! {
+    shared;
+    /// An event, distinguished by its `type`.
+    #[derive(Clone, Debug)]
+    pub enum Event {
+        Hello(Hello) = "hello",
+        Goodbye(Goodbye) = "goodbye",
+        /// The reply to a `ping`.
+        Pong(Pong) = "pong",
+        /// Slack couldn't handle something sent over the connection.
+        Error(ErrorEvent) = "error",
+        ReconnectUrl(ReconnectUrl) = "reconnect_url",
+        /// A message, or a change to one, described by its `subtype`.
+        Message(Message) = "message",
+        /// The app was mentioned in a message. Only sent by the Events API.
+        AppMention(AppMention) = "app_mention",
+        UserTyping(UserTyping) = "user_typing",
+        PresenceChange(PresenceChange) = "presence_change",
+        ManualPresenceChange(ManualPresenceChange) = "manual_presence_change",
+        ReactionAdded(ReactionEvent) = "reaction_added",
+        ReactionRemoved(ReactionEvent) = "reaction_removed",
+        ChannelCreated(ChannelCreated) = "channel_created",
+        ChannelDeleted(ConversationEvent) = "channel_deleted",
+        ChannelRename(ConversationRename) = "channel_rename",
+        ChannelArchive(ConversationEvent) = "channel_archive",
+        ChannelUnarchive(ConversationEvent) = "channel_unarchive",
+        ChannelJoined(ChannelJoined) = "channel_joined",
+        ChannelLeft(ConversationEvent) = "channel_left",
+        ChannelMarked(MarkedEvent) = "channel_marked",
+        GroupJoined(GroupJoined) = "group_joined",
+        GroupLeft(ConversationEvent) = "group_left",
+        GroupRename(ConversationRename) = "group_rename",
+        GroupArchive(ConversationEvent) = "group_archive",
+        GroupUnarchive(ConversationEvent) = "group_unarchive",
+        GroupMarked(MarkedEvent) = "group_marked",
+        ImCreated(ImCreated) = "im_created",
+        ImOpen(ConversationEvent) = "im_open",
+        ImClose(ConversationEvent) = "im_close",
+        ImMarked(MarkedEvent) = "im_marked",
+        MpimJoined(MpimJoined) = "mpim_joined",
+        MemberJoinedChannel(MembershipEvent) = "member_joined_channel",
+        MemberLeftChannel(MembershipEvent) = "member_left_channel",
+        TeamJoin(UserEvent) = "team_join",
+        UserChange(UserEvent) = "user_change",
+        TeamRename(TeamRename) = "team_rename",
+        TeamDomainChange(TeamDomainChange) = "team_domain_change",
+        EmojiChanged(EmojiChanged) = "emoji_changed",
+        PinAdded(PinEvent) = "pin_added",
+        PinRemoved(PinEvent) = "pin_removed",
+        StarAdded(StarEvent) = "star_added",
+        StarRemoved(StarEvent) = "star_removed",
+        DndUpdated(DndEvent) = "dnd_updated",
+        DndUpdatedUser(DndEvent) = "dnd_updated_user",
+        FileCreated(FileEvent) = "file_created",
+        FileShared(FileEvent) = "file_shared",
+        FileUnshared(FileEvent) = "file_unshared",
+        FilePublic(FileEvent) = "file_public",
+        FileChange(FileEvent) = "file_change",
+        FileDeleted(FileEvent) = "file_deleted",
+        BotAdded(BotEvent) = "bot_added",
+        BotChanged(BotEvent) = "bot_changed",
+        SubteamCreated(SubteamEvent) = "subteam_created",
+        SubteamUpdated(SubteamEvent) = "subteam_updated",
+        PrefChange(PrefChange) = "pref_change",
+    }
+}
+
+/// The first event on a new connection.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Hello {}
+
+/// Sent before Slack closes the connection, after which the client should reconnect.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Goodbye {}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Pong {
+    /// The `id` of the ping.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ErrorEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorEventError>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ErrorEventError {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msg: Option<String>,
+}
+
+/// A URL which can be used to resume the session for a short time.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReconnectUrl {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AppMention {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserTyping {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// The thread being replied to, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+}
+
+/// A change to the presence of one user, or of several in `users` when presence changes are
+/// batched.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PresenceChange {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<String>>,
+    /// Either `active` or `away`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence: Option<String>,
+}
+
+/// The connected user set their own presence.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ManualPresenceChange {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReactionEvent {
+    /// The user who added or removed the reaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// The name of the emoji, without colons.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reaction: Option<String>,
+    /// The author of the item reacted to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<ReactionItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<String>,
+}
+
+/// Identifies the message, file or file comment reacted to.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReactionItem {
+    /// Either `message`, `file` or `file_comment`.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_comment: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChannelCreated {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<ConversationInfo>,
+}
+
+/// The parts of a conversation included when it is created or renamed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConversationInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConversationRename {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<ConversationInfo>,
+}
+
+/// An event which only identifies a conversation, and sometimes the user responsible.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConversationEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+/// The connected user joined a channel.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChannelJoined {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<Channel>,
+}
+
+/// The connected user joined a private channel.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GroupJoined {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<Group>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ImCreated {
+    /// The other user in the conversation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<Im>,
+}
+
+/// The connected user read a conversation up to `ts`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarkedEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_count_display: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<String>,
+}
+
+/// The connected user was added to a multi-person IM.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MpimJoined {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<Mpim>,
+}
+
+/// A user joined or left a conversation.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MembershipEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    /// `C` for a public channel or `G` for a private one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    /// The user who added them, if they didn't join by themselves.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inviter: Option<String>,
+}
+
+/// A user joined the team or changed their profile.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UserEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TeamRename {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TeamDomainChange {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+}
+
+/// A custom emoji was added or removed, or an alias changed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EmojiChanged {
+    /// Either `add` or `remove`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    /// The emoji added.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The emoji removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub names: Option<Vec<String>>,
+    /// The image URL, or `alias:name` for an alias.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PinEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<Item>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StarEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<Item>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<String>,
+}
+
+/// A pinned or starred message, file or file comment.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Item {
+    /// One of `message`, `file`, `file_comment`, `channel`, `im` or `group`.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<FileComment>,
+}
+
+/// A change to a user's Do Not Disturb settings.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DndEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dnd_status: Option<DndStatus>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DndStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dnd_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_dnd_start_ts: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_dnd_end_ts: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snooze_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snooze_endtime: Option<i64>,
+}
+
+/// A file was created, shared, changed or deleted. Only its id is included, and the file can be
+/// read with `files.info`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FileEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<FileRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FileRef {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BotEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot: Option<Bot>,
+}
+
+/// A user group was created or changed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SubteamEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subteam: Option<Usergroup>,
+}
+
+/// One of the connected user's preferences changed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PrefChange {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_events_round_trip() {
+        let events = vec![
+            serde_json::json!({"type": "hello"}),
+            serde_json::json!({"type": "message", "channel": "C1", "user": "U1", "text": "hi", "ts": "1.0"}),
+            serde_json::json!({"type": "message", "subtype": "me_message", "channel": "C1", "user": "U1", "text": "waves", "ts": "2.0"}),
+            serde_json::json!({"type": "message", "subtype": "brand_new", "channel": "C1", "ts": "3.0", "extra": [1]}),
+            serde_json::json!({"type": "reaction_added", "user": "U1", "reaction": "+1", "item_user": "U2",
+                   "item": {"type": "message", "channel": "C1", "ts": "1.0"}, "event_ts": "4.0"}),
+            serde_json::json!({"type": "presence_change", "users": ["U1", "U2"], "presence": "away"}),
+            serde_json::json!({"type": "team_join", "user": {"id": "U3", "name": "new"}}),
+            serde_json::json!({"type": "dnd_updated", "user": "U1", "dnd_status": {"dnd_enabled": true, "next_dnd_start_ts": 1}}),
+            serde_json::json!({"type": "file_shared", "file_id": "F1", "user_id": "U1", "file": {"id": "F1"}}),
+            serde_json::json!({"type": "something_new", "data": {"nested": true}}),
+        ];
+        for json in events {
+            let event: Event = serde_json::from_value(json.clone()).unwrap();
+            assert_eq!(json, serde_json::to_value(&event).unwrap(), "{:?}", event);
+        }
+    }
+
+    #[test]
+    fn test_event_variants() {
+        let event: Event = serde_json::from_str(r#"{"type": "reaction_removed", "reaction": "tada"}"#).unwrap();
+        match event {
+            Event::ReactionRemoved(ref e) => assert_eq!(Some("tada"), e.reaction.as_deref()),
+            ref other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!("reaction_removed", event.ty());
+
+        let event: Event = serde_json::from_str(r#"{"type": "message", "subtype": "bot_message", "bot_id": "B1"}"#).unwrap();
+        match event {
+            Event::Message(ref message) => assert_eq!(Some("B1"), message.bot_id()),
+            ref other => panic!("unexpected {:?}", other),
+        }
+
+        let event: Event = serde_json::from_str(r#"{"type": "accounts_changed"}"#).unwrap();
+        assert_eq!("accounts_changed", event.ty());
+    }
+}
@@ -0,0 +1,211 @@
+//! Requests sent by the [Events API](https://api.slack.com/events-api) to an app's endpoint.
+//!
+//! Slack POSTs a JSON [`Payload`] for every event the app subscribes to, wrapped in an
+//! `event_callback`. When the endpoint is first configured, Slack sends a `url_verification`
+//! instead, whose challenge must be sent back as the body of the response.
+//!
+//! [`Receiver`] checks that requests came from Slack before parsing them, independently of the
+//! HTTP server used:
+//!
+//! ```no_run
+//! # #[cfg(feature = "signing")]
+//! # fn run(timestamp: Option<&str>, signature: Option<&str>, body: &[u8]) {
+//! use slack_api::event::Event;
+//! use slack_api::events_api::{Payload, Receiver};
+//!
+//! let receiver = Receiver::new("8f742231b10e8888abcd99yyyzzz85a5");
+//! match receiver.receive(timestamp, signature, body) {
+//!     Ok(Payload::UrlVerification(ref verification)) => { /* respond with the challenge */ }
+//!     Ok(Payload::EventCallback(ref callback)) => match *callback.event {
+//!         Event::AppMention(ref mention) => println!("{:?}", mention.text),
+//!         _ => {}
+//!     },
+//!     Ok(_) => {}
+//!     Err(e) => { /* respond with 401 Unauthorized */ }
+//! }
+//! # }
+//! ```
+//!
+//! The receiver requires the `signing` feature, but the payloads can be parsed without it.
+//!
+//! [`Payload`]: enum.Payload.html
+//! [`Receiver`]: type.Receiver.html
+
+#[cfg(feature = "signing")]
+use serde_json;
+
+use event::Event;
+#[cfg(feature = "signing")]
+use signature::{self, SignedPayload};
+
+tagged_enum! {
+    /// The body of a request to an Events API endpoint.
+    #[derive(Clone, Debug)]
+    pub enum Payload {
+        UrlVerification(UrlVerification) = "url_verification",
+        EventCallback(EventCallback) = "event_callback",
+        /// Slack stopped sending events for a minute because the app exceeded its rate limit.
+        AppRateLimited(AppRateLimited) = "app_rate_limited",
+    }
+}
+
+impl Payload {
+    /// The legacy verification token included in the payload.
+    pub fn token(&self) -> Option<&str> {
+        match *self {
+            Payload::UrlVerification(ref p) => p.token.as_deref(),
+            Payload::EventCallback(ref p) => p.token.as_deref(),
+            Payload::AppRateLimited(ref p) => p.token.as_deref(),
+            Payload::Unknown { ref raw, .. } => raw.get("token").and_then(|token| token.as_str()),
+        }
+    }
+
+    /// The challenge of a `url_verification`, which must be the body of the response.
+    pub fn challenge(&self) -> Option<&str> {
+        match *self {
+            Payload::UrlVerification(ref p) => p.challenge.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Sent to check that the endpoint belongs to the app.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UrlVerification {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<String>,
+}
+
+/// An event, and the workspace and app it was sent for.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventCallback {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_app_id: Option<String>,
+    pub event: Box<Event>,
+    /// Identifies the event across retries, so that repeated deliveries can be ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<String>,
+    /// When the event happened, in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_time: Option<i64>,
+    /// The installations the event is visible to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizations: Option<Vec<Authorization>>,
+    /// Deprecated in favour of `authorizations`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authed_users: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_ext_shared_channel: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_context: Option<String>,
+}
+
+/// An installation of the app which can see an event.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Authorization {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_bot: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enterprise_install: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AppRateLimited {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The start of the minute in which events were dropped, in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minute_rate_limited: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_app_id: Option<String>,
+}
+
+/// Checks and parses requests to an Events API endpoint. Requires the `signing` feature.
+#[cfg(feature = "signing")]
+pub type Receiver = signature::Receiver<Payload>;
+
+#[cfg(feature = "signing")]
+impl SignedPayload for Payload {
+    fn parse(body: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(body)
+    }
+
+    fn token(&self) -> Option<&str> {
+        Payload::token(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    const CALLBACK: &str = r#"{
+        "token": "XXYYZZ",
+        "team_id": "T1",
+        "api_app_id": "A1",
+        "event": {"type": "app_mention", "user": "U1", "text": "<@U2> hi", "ts": "1.0", "channel": "C1", "event_ts": "1.0"},
+        "type": "event_callback",
+        "event_id": "Ev1",
+        "event_time": 1234567890,
+        "authorizations": [{"team_id": "T1", "user_id": "U2", "is_bot": true, "is_enterprise_install": false}]
+    }"#;
+
+    #[test]
+    fn test_parse_payloads() {
+        let payload: Payload = serde_json::from_str(CALLBACK).unwrap();
+        match payload {
+            Payload::EventCallback(ref callback) => {
+                assert_eq!(Some("Ev1"), callback.event_id.as_deref());
+                assert_eq!(Some(true), callback.authorizations.as_ref().unwrap()[0].is_bot);
+                match *callback.event {
+                    Event::AppMention(ref mention) => assert_eq!(Some("<@U2> hi"), mention.text.as_deref()),
+                    ref other => panic!("unexpected {:?}", other),
+                }
+            }
+            ref other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(Some("XXYYZZ"), payload.token());
+
+        let payload: Payload = serde_json::from_str(r#"{"token": "XXYYZZ", "challenge": "abc", "type": "url_verification"}"#).unwrap();
+        assert_eq!(Some("abc"), payload.challenge());
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn test_receiver() {
+        use signature::{ReceiveError, SignatureError, Verifier};
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let now = UNIX_EPOCH + Duration::from_secs(1600000000);
+        let signature = Verifier::new("secret").sign("1600000000", CALLBACK.as_bytes());
+        let receiver = Receiver::new("secret").verification_token("XXYYZZ");
+        let payload = receiver.receive_at(Some("1600000000"), Some(&signature), CALLBACK.as_bytes(), now).unwrap();
+        assert_eq!("event_callback", payload.ty());
+
+        match Receiver::new("other").receive_at(Some("1600000000"), Some(&signature), CALLBACK.as_bytes(), now) {
+            Err(ReceiveError::Signature(SignatureError::Mismatch)) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+        match Receiver::new("secret").verification_token("nope").receive_at(Some("1600000000"), Some(&signature), CALLBACK.as_bytes(), now) {
+            Err(ReceiveError::InvalidToken) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
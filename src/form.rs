@@ -0,0 +1,38 @@
+//! Decoding `application/x-www-form-urlencoded` text, as sent in query strings and the bodies of
+//! slash command and interactivity requests.
+
+/// Splits a form body into its decoded names and values, in order.
+pub fn parse(body: &str) -> Vec<(String, String)> {
+    body.split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_at(pair.find('=').unwrap_or(pair.len()));
+            (percent_decode(name), percent_decode(value.trim_start_matches('=')))
+        })
+        .collect()
+}
+
+/// Decodes `%XX` escapes and `+` for space. Invalid escapes are kept as they are.
+pub fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = value.get(i + 1..i + 3).and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (b'+', _) => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
@@ -0,0 +1,474 @@
+//! Walk the complete message history of a channel, private group, IM or MPIM.
+//!
+//! The `*::history` methods return one batch of messages and a `has_more` flag, leaving it to the
+//! caller to repeat the request with `latest` set to the oldest message received so far. [`walk`]
+//! does this for you and yields every message between two timestamps.
+//!
+//! [`walk`]: fn.walk.html
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+
+use requests::SlackWebRequestSender;
+use types::{Conversation, Message, ThreadInfo, Timestamp};
+use {channels, groups, im, mpim};
+
+/// The kind of conversation, which determines the family of methods used to read it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConversationKind {
+    /// A public channel, read with the `channels` methods.
+    Channel,
+    /// A private channel, read with the `groups` methods.
+    Group,
+    /// A direct message, read with the `im` methods.
+    Im,
+    /// A multi-person direct message, read with the `mpim` methods.
+    Mpim,
+}
+
+impl ConversationKind {
+    /// The kind of a conversation described by the `conversations` methods. Private channels are
+    /// read with the `groups` methods, whatever their id.
+    pub fn of(conversation: &Conversation) -> Self {
+        if conversation.is_im == Some(true) {
+            ConversationKind::Im
+        } else if conversation.is_mpim == Some(true) {
+            ConversationKind::Mpim
+        } else if conversation.is_private == Some(true) || conversation.is_group == Some(true) {
+            ConversationKind::Group
+        } else {
+            ConversationKind::Channel
+        }
+    }
+}
+
+/// The order in which [`walk`](fn.walk.html) yields messages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Start at `latest` and walk back in time. Messages are yielded as each batch arrives.
+    NewestFirst,
+    /// Start at `oldest` and walk forward in time. Slack only pages backwards, so the whole range
+    /// is fetched before the first message is yielded.
+    OldestFirst,
+}
+
+#[derive(Clone, Debug)]
+pub struct WalkRequest<'a> {
+    /// The kind of conversation `channel` refers to.
+    pub kind: ConversationKind,
+    /// Conversation to fetch history for.
+    pub channel: Cow<'a, str>,
+    /// Start of time range of messages to include. Defaults to the beginning of the conversation.
+    pub oldest: Option<Timestamp>,
+    /// End of time range of messages to include. Defaults to the current time.
+    pub latest: Option<Timestamp>,
+    /// Include messages with exactly the `oldest` or `latest` timestamp.
+    pub inclusive: bool,
+    /// The order messages are yielded in.
+    pub direction: Direction,
+    /// Number of messages to request at a time. Slack's default is used if unset.
+    pub page_size: Option<u32>,
+    /// Fetch the replies of every thread parent and yield them, oldest first, directly after it.
+    pub expand_threads: bool,
+}
+
+impl<'a> WalkRequest<'a> {
+    /// Creates a request for the entire history of `channel`, newest message first.
+    pub fn new(kind: ConversationKind, channel: impl Into<Cow<'a, str>>) -> Self {
+        WalkRequest {
+            kind,
+            channel: channel.into(),
+            oldest: None,
+            latest: None,
+            inclusive: false,
+            direction: Direction::NewestFirst,
+            page_size: None,
+            expand_threads: false,
+        }
+    }
+
+    /// Start of time range of messages to include.
+    pub fn oldest(mut self, oldest: Timestamp) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+
+    /// End of time range of messages to include.
+    pub fn latest(mut self, latest: Timestamp) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    /// Include messages with exactly the `oldest` or `latest` timestamp.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = inclusive;
+        self
+    }
+
+    /// The order messages are yielded in.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Number of messages to request at a time.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Fetch the replies of every thread parent and yield them directly after it.
+    pub fn expand_threads(mut self, expand_threads: bool) -> Self {
+        self.expand_threads = expand_threads;
+        self
+    }
+}
+
+/// Returns an iterator over every message in the time range described by `request`.
+///
+/// Messages that appear in two consecutive batches (which happens when `inclusive` is set) are
+/// only yielded once. If a request fails, the error is yielded and iteration stops.
+pub fn walk<'a, R>(client: &'a R, token: &'a str, request: &WalkRequest<'a>) -> Messages<'a, R>
+where
+    R: SlackWebRequestSender,
+{
+    Messages {
+        client,
+        token,
+        latest: request.latest.as_ref().map(ToString::to_string),
+        request: request.clone(),
+        previous_batch: HashSet::new(),
+        pending: VecDeque::new(),
+        replies: VecDeque::new(),
+        error: None,
+        done: false,
+    }
+}
+
+/// Iterator returned by [`walk`](fn.walk.html).
+pub struct Messages<'a, R: 'a + SlackWebRequestSender> {
+    client: &'a R,
+    token: &'a str,
+    request: WalkRequest<'a>,
+    /// Upper bound of the next batch to request.
+    latest: Option<String>,
+    /// Timestamps of the previous batch, to drop messages repeated at the boundary.
+    previous_batch: HashSet<String>,
+    pending: VecDeque<Message>,
+    replies: VecDeque<Message>,
+    /// An error to yield once the message it occurred for has been yielded.
+    error: Option<HistoryError<R::Error>>,
+    done: bool,
+}
+
+impl<'a, R: SlackWebRequestSender> Messages<'a, R> {
+    /// Fetches the batch ending at `self.latest`, newest message first, and moves `latest` to the
+    /// oldest message in it.
+    fn next_batch(&mut self) -> Result<Vec<Message>, HistoryError<R::Error>> {
+        // With `inclusive` set, every batch after the first starts with the message the previous
+        // one ended at, so ask for one more to still make progress.
+        let count = match self.request.page_size {
+            Some(count) if self.request.inclusive && !self.previous_batch.is_empty() => Some(count + 1),
+            count => count,
+        };
+        let (mut messages, has_more) = history(self.client, self.token, &self.request, self.latest.as_deref(), count)?;
+        messages.sort_by(|a, b| compare_ts(b, a));
+
+        // Where the next batch ends is decided before dropping the repeated boundary message, which
+        // may be the whole batch.
+        let oldest = messages.iter().filter_map(Message::ts).next_back().map(str::to_owned);
+        if !has_more || oldest.is_none() || oldest == self.latest {
+            self.done = true;
+        }
+        let batch = messages.iter().filter_map(Message::ts).map(str::to_owned).collect();
+        let previous_batch = &self.previous_batch;
+        messages.retain(|m| match m.ts() {
+            Some(ts) => !previous_batch.contains(ts),
+            None => true,
+        });
+        self.previous_batch = batch;
+        self.latest = oldest;
+        Ok(messages)
+    }
+
+    fn fill(&mut self) -> Result<(), HistoryError<R::Error>> {
+        match self.request.direction {
+            Direction::NewestFirst => {
+                let batch = self.next_batch()?;
+                self.pending.extend(batch);
+            }
+            Direction::OldestFirst => {
+                let mut all = Vec::new();
+                while !self.done {
+                    all.extend(self.next_batch()?);
+                }
+                self.pending.extend(all.into_iter().rev());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a, R: SlackWebRequestSender> Iterator for Messages<'a, R> {
+    type Item = Result<Message, HistoryError<R::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(reply) = self.replies.pop_front() {
+                return Some(Ok(reply));
+            }
+            if let Some(error) = self.error.take() {
+                self.pending.clear();
+                self.done = true;
+                return Some(Err(error));
+            }
+            if let Some(message) = self.pending.pop_front() {
+                if self.request.expand_threads {
+                    if let Some(thread_ts) = message.thread_ts().filter(|_| message.is_thread_parent()) {
+                        match replies(self.client, self.token, self.request.kind, &self.request.channel, thread_ts) {
+                            Ok(replies) => self.replies.extend(replies),
+                            Err(e) => self.error = Some(e),
+                        }
+                    }
+                }
+                return Some(Ok(message));
+            }
+            if self.done {
+                return None;
+            }
+            if let Err(e) = self.fill() {
+                self.done = true;
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+/// Fetches up to `count` messages of history ending at `latest`, returning them and `has_more`.
+fn history<R>(
+    client: &R,
+    token: &str,
+    request: &WalkRequest,
+    latest: Option<&str>,
+    count: Option<u32>,
+) -> Result<(Vec<Message>, bool), HistoryError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let oldest = request.oldest.as_ref().map(ToString::to_string);
+
+    macro_rules! history {
+        ($module:ident, $variant:ident) => {{
+            let mut history = $module::HistoryRequest::new(&request.channel[..]);
+            history.latest = latest.map(Cow::from);
+            history.oldest = oldest.as_ref().map(|oldest| Cow::from(&oldest[..]));
+            history.inclusive = Some(request.inclusive);
+            history.count = count;
+            $module::history(client, token, &history)
+                .map(|r| (r.messages.unwrap_or_default(), r.has_more.unwrap_or(false)))
+                .map_err(HistoryError::$variant)
+        }};
+    }
+
+    match request.kind {
+        ConversationKind::Channel => history!(channels, Channels),
+        ConversationKind::Group => history!(groups, Groups),
+        ConversationKind::Im => history!(im, Im),
+        ConversationKind::Mpim => history!(mpim, Mpim),
+    }
+}
+
+/// Fetches the replies to the thread started by `thread_ts`, oldest first and without the parent.
+pub fn replies<R>(
+    client: &R,
+    token: &str,
+    kind: ConversationKind,
+    channel: &str,
+    thread_ts: &str,
+) -> Result<Vec<Message>, HistoryError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let (mut messages, _) = thread(client, token, kind, channel, thread_ts)?;
+    messages.retain(|m| m.ts() != Some(thread_ts));
+    Ok(messages)
+}
+
+/// The messages of a thread and Slack's summary of it.
+pub(crate) type ThreadMessages = (Vec<Message>, Option<ThreadInfo>);
+
+/// Fetches every message of the thread started by `thread_ts`, including the parent, oldest first.
+pub(crate) fn thread<R>(
+    client: &R,
+    token: &str,
+    kind: ConversationKind,
+    channel: &str,
+    thread_ts: &str,
+) -> Result<ThreadMessages, HistoryError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    macro_rules! replies {
+        ($module:ident, $variant:ident) => {
+            $module::replies(client, token, &$module::RepliesRequest::new(channel, thread_ts))
+                .map(|r| (r.messages.unwrap_or_default(), r.thread_info))
+                .map_err(HistoryError::$variant)
+        };
+    }
+
+    let (mut messages, info) = match kind {
+        ConversationKind::Channel => replies!(channels, ChannelsReplies),
+        ConversationKind::Group => replies!(groups, GroupsReplies),
+        ConversationKind::Im => replies!(im, ImReplies),
+        ConversationKind::Mpim => replies!(mpim, MpimReplies),
+    }?;
+    messages.sort_by(compare_ts);
+    Ok((messages, info))
+}
+
+/// Orders messages chronologically by their `ts`.
+pub(crate) fn compare_ts(a: &Message, b: &Message) -> Ordering {
+    let a = a.ts().and_then(|ts| ts.parse::<Timestamp>().ok());
+    let b = b.ts().and_then(|ts| ts.parse::<Timestamp>().ok());
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+#[derive(Debug)]
+pub enum HistoryError<E: Error> {
+    /// `channels.history` failed.
+    Channels(channels::HistoryError<E>),
+    /// `groups.history` failed.
+    Groups(groups::HistoryError<E>),
+    /// `im.history` failed.
+    Im(im::HistoryError<E>),
+    /// `mpim.history` failed.
+    Mpim(mpim::HistoryError<E>),
+    /// `channels.replies` failed.
+    ChannelsReplies(channels::RepliesError<E>),
+    /// `groups.replies` failed.
+    GroupsReplies(groups::RepliesError<E>),
+    /// `im.replies` failed.
+    ImReplies(im::RepliesError<E>),
+    /// `mpim.replies` failed.
+    MpimReplies(mpim::RepliesError<E>),
+}
+
+impl<E: Error> fmt::Display for HistoryError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for HistoryError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            HistoryError::Channels(ref e) => e.description(),
+            HistoryError::Groups(ref e) => e.description(),
+            HistoryError::Im(ref e) => e.description(),
+            HistoryError::Mpim(ref e) => e.description(),
+            HistoryError::ChannelsReplies(ref e) => e.description(),
+            HistoryError::GroupsReplies(ref e) => e.description(),
+            HistoryError::ImReplies(ref e) => e.description(),
+            HistoryError::MpimReplies(ref e) => e.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            HistoryError::Channels(ref e) => Some(e),
+            HistoryError::Groups(ref e) => Some(e),
+            HistoryError::Im(ref e) => Some(e),
+            HistoryError::Mpim(ref e) => Some(e),
+            HistoryError::ChannelsReplies(ref e) => Some(e),
+            HistoryError::GroupsReplies(ref e) => Some(e),
+            HistoryError::ImReplies(ref e) => Some(e),
+            HistoryError::MpimReplies(ref e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tests::{param, Replay};
+
+    fn texts<I: Iterator<Item = Result<Message, HistoryError<::tests::NoError>>>>(iter: I) -> Vec<String> {
+        iter.map(|m| match m.unwrap() {
+            Message::Standard(m) => m.text.unwrap(),
+            other => panic!("unexpected {:?}", other),
+        }).collect()
+    }
+
+    #[test]
+    fn test_walk_follows_has_more_and_dedups_boundary() {
+        let client = Replay::new(vec![
+            r#"{"ok": true, "has_more": true, "messages": [
+                {"type": "message", "ts": "1500000003.000000", "text": "c"},
+                {"type": "message", "ts": "1500000002.000000", "text": "b"}]}"#,
+            r#"{"ok": true, "has_more": false, "messages": [
+                {"type": "message", "ts": "1500000002.000000", "text": "b"},
+                {"type": "message", "ts": "1500000001.000000", "text": "a"}]}"#,
+        ]);
+        let request = WalkRequest::new(ConversationKind::Channel, "C1")
+            .oldest(Timestamp::new(1500000001.0))
+            .inclusive(true);
+
+        assert_eq!(vec!["c", "b", "a"], texts(walk(&client, "token", &request)));
+        let requests = client.requests.borrow();
+        assert!(requests[0].0.ends_with("channels.history"));
+        assert_eq!(None, param(&requests[0], "latest"));
+        assert_eq!(Some("1500000002.000000".to_owned()), param(&requests[1], "latest"));
+        assert_eq!(Some("1500000001".to_owned()), param(&requests[1], "oldest"));
+        assert_eq!(Some("1".to_owned()), param(&requests[1], "inclusive"));
+    }
+
+    #[test]
+    fn test_walk_inclusive_one_message_at_a_time() {
+        let client = Replay::new(vec![
+            r#"{"ok": true, "has_more": true, "messages": [
+                {"type": "message", "ts": "1500000003.000000", "text": "c"}]}"#,
+            r#"{"ok": true, "has_more": true, "messages": [
+                {"type": "message", "ts": "1500000003.000000", "text": "c"},
+                {"type": "message", "ts": "1500000002.000000", "text": "b"}]}"#,
+            r#"{"ok": true, "has_more": true, "messages": [
+                {"type": "message", "ts": "1500000002.000000", "text": "b"},
+                {"type": "message", "ts": "1500000001.000000", "text": "a"}]}"#,
+            r#"{"ok": true, "has_more": false, "messages": [
+                {"type": "message", "ts": "1500000001.000000", "text": "a"}]}"#,
+        ]);
+        let request = WalkRequest::new(ConversationKind::Im, "D1").inclusive(true).page_size(1);
+
+        assert_eq!(vec!["c", "b", "a"], texts(walk(&client, "token", &request)));
+        let requests = client.requests.borrow();
+        assert_eq!(4, requests.len());
+        assert_eq!(Some("1".to_owned()), param(&requests[0], "count"));
+        assert_eq!(Some("2".to_owned()), param(&requests[1], "count"));
+        assert_eq!(Some("1500000003.000000".to_owned()), param(&requests[1], "latest"));
+        assert_eq!(Some("1500000002.000000".to_owned()), param(&requests[2], "latest"));
+        assert_eq!(Some("1500000001.000000".to_owned()), param(&requests[3], "latest"));
+    }
+
+    #[test]
+    fn test_walk_oldest_first_with_threads() {
+        let client = Replay::new(vec![
+            r#"{"ok": true, "has_more": false, "messages": [
+                {"type": "message", "ts": "1500000002.000000", "text": "b"},
+                {"type": "message", "ts": "1500000001.000000", "thread_ts": "1500000001.000000", "text": "a"}]}"#,
+            r#"{"ok": true, "messages": [
+                {"type": "message", "ts": "1500000001.000000", "thread_ts": "1500000001.000000", "text": "a"},
+                {"type": "message", "ts": "1500000004.000000", "thread_ts": "1500000001.000000", "text": "a2"},
+                {"type": "message", "ts": "1500000003.000000", "thread_ts": "1500000001.000000", "text": "a1"}]}"#,
+        ]);
+        let request = WalkRequest::new(ConversationKind::Group, "G1")
+            .direction(Direction::OldestFirst)
+            .expand_threads(true);
+
+        assert_eq!(vec!["a", "a1", "a2", "b"], texts(walk(&client, "token", &request)));
+        let requests = client.requests.borrow();
+        assert!(requests[1].0.ends_with("groups.replies"));
+        assert_eq!(Some("1500000001.000000".to_owned()), param(&requests[1], "thread_ts"));
+    }
+}
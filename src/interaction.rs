@@ -0,0 +1,807 @@
+//! Requests sent to an app's interactivity endpoint when a user clicks a button, submits a
+//! modal or uses a shortcut.
+//!
+//! Slack POSTs a form whose `payload` field holds a JSON [`Interaction`]. Submissions of modals
+//! can be answered in the body of the response with a [`ViewResponse`], and interactions with
+//! messages by posting a `slash_command::Response` to their `response_url`:
+//!
+//! ```no_run
+//! # fn run(body: &[u8]) {
+//! use slack_api::interaction::{Interaction, ViewResponse};
+//!
+//! match Interaction::parse(body).unwrap() {
+//!     Interaction::ViewSubmission(ref submission) => {
+//!         let email = submission.view.state.as_ref().and_then(|state| state.value("email", "input"));
+//!         if !email.is_some_and(|email| email.contains('@')) {
+//!             // The body of the response to the request:
+//!             let body = ViewResponse::errors(vec![("email", "Enter an email address")]).to_json();
+//!             # let _ = body;
+//!         }
+//!     }
+//!     Interaction::BlockActions(ref actions) => {
+//!         for action in &actions.actions {
+//!             println!("{} clicked {}", actions.user.id, action.action_id);
+//!         }
+//!     }
+//!     _ => {}
+//! }
+//! # }
+//! ```
+//!
+//! The `signing` feature adds [`Receiver`], which rejects requests not signed by Slack.
+//!
+//! [`Interaction`]: enum.Interaction.html
+//! [`ViewResponse`]: enum.ViewResponse.html
+//! [`Receiver`]: type.Receiver.html
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::de::Error as DeError;
+use serde_json;
+
+use blocks::{Block, SelectOption, Text};
+use form;
+use requests::SlackJsonPoster;
+#[cfg(feature = "signing")]
+use signature::{self, SignedPayload};
+use slash_command::Response;
+use types::Message;
+
+tagged_enum! {
+    /// The payload of a request to an interactivity endpoint.
+    #[derive(Clone, Debug)]
+    pub enum Interaction {
+        /// A user used an interactive element in a message, modal or the App Home.
+        BlockActions(BlockActions) = "block_actions",
+        /// A user submitted a modal.
+        ViewSubmission(ViewSubmission) = "view_submission",
+        /// A user closed a modal which was opened with `notify_on_close`.
+        ViewClosed(ViewClosed) = "view_closed",
+        /// A user used a global shortcut.
+        Shortcut(Shortcut) = "shortcut",
+        /// A user used a message shortcut.
+        MessageAction(MessageAction) = "message_action",
+        /// A user clicked a button or chose an option in a legacy message attachment.
+        InteractiveMessage(InteractiveMessage) = "interactive_message",
+        /// A user submitted a legacy dialog.
+        DialogSubmission(DialogSubmission) = "dialog_submission",
+    }
+}
+
+impl Interaction {
+    /// Parses the form-encoded body of a request, whose `payload` field holds the JSON
+    /// interaction.
+    pub fn parse(body: &[u8]) -> Result<Self, serde_json::Error> {
+        let payload = form::parse(&String::from_utf8_lossy(body))
+            .into_iter()
+            .find(|(name, _)| name == "payload")
+            .map(|(_, payload)| payload)
+            .ok_or_else(|| serde_json::Error::missing_field("payload"))?;
+        serde_json::from_str(&payload)
+    }
+
+    /// The user who interacted.
+    pub fn user(&self) -> Option<&UserRef> {
+        match *self {
+            Interaction::BlockActions(ref i) => Some(&i.user),
+            Interaction::ViewSubmission(ref i) => Some(&i.user),
+            Interaction::ViewClosed(ref i) => Some(&i.user),
+            Interaction::Shortcut(ref i) => Some(&i.user),
+            Interaction::MessageAction(ref i) => Some(&i.user),
+            Interaction::InteractiveMessage(ref i) => Some(&i.user),
+            Interaction::DialogSubmission(ref i) => Some(&i.user),
+            Interaction::Unknown { .. } => None,
+        }
+    }
+
+    /// Opens a modal in response to the interaction, within three seconds.
+    pub fn trigger_id(&self) -> Option<&str> {
+        match *self {
+            Interaction::BlockActions(ref i) => i.trigger_id.as_deref(),
+            Interaction::ViewSubmission(ref i) => i.trigger_id.as_deref(),
+            Interaction::Shortcut(ref i) => i.trigger_id.as_deref(),
+            Interaction::MessageAction(ref i) => i.trigger_id.as_deref(),
+            Interaction::InteractiveMessage(ref i) => i.trigger_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Where replies to an interaction with a message are posted.
+    pub fn response_url(&self) -> Option<&str> {
+        match *self {
+            Interaction::BlockActions(ref i) => i.response_url.as_deref(),
+            Interaction::MessageAction(ref i) => i.response_url.as_deref(),
+            Interaction::InteractiveMessage(ref i) => i.response_url.as_deref(),
+            Interaction::DialogSubmission(ref i) => i.response_url.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Posts a reply to the interaction's `response_url`, returning `Ok(false)` if it has none.
+    pub fn respond<P: SlackJsonPoster>(&self, poster: &P, response: &Response) -> Result<bool, P::Error> {
+        match self.response_url() {
+            Some(url) => response.send_to(poster, url).map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    /// The legacy verification token included in the payload.
+    pub fn token(&self) -> Option<&str> {
+        match *self {
+            Interaction::BlockActions(ref i) => i.token.as_deref(),
+            Interaction::ViewSubmission(ref i) => i.token.as_deref(),
+            Interaction::ViewClosed(ref i) => i.token.as_deref(),
+            Interaction::Shortcut(ref i) => i.token.as_deref(),
+            Interaction::MessageAction(ref i) => i.token.as_deref(),
+            Interaction::InteractiveMessage(ref i) => i.token.as_deref(),
+            Interaction::DialogSubmission(ref i) => i.token.as_deref(),
+            Interaction::Unknown { ref raw, .. } => raw.get("token").and_then(|token| token.as_str()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserRef {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TeamRef {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChannelRef {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BlockActions {
+    pub user: UserRef,
+    /// `None` for apps installed across an Enterprise Grid organization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// Where the actions happened: a message, a view or the App Home.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<Container>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_id: Option<String>,
+    /// The channel of the message, for actions in a message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<ChannelRef>,
+    /// The message, for actions in a message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Box<Message>>,
+    /// The view, for actions in a modal or the App Home.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view: Option<Box<View>>,
+    /// The values of the inputs of a message, for actions in a message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<ViewState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_url: Option<String>,
+    pub actions: Vec<Action>,
+}
+
+/// Where an interaction happened.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Container {
+    /// `message`, `view` or `message_attachment`.
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_ephemeral: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view_id: Option<String>,
+}
+
+/// The use of an interactive element.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Action {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    /// The type of the element, such as `button` or `static_select`.
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_ts: Option<String>,
+    /// The `value` of a button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_option: Option<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_options: Option<Vec<SelectOption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_users: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_channels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_conversation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_conversations: Option<Vec<String>>,
+    /// In `YYYY-MM-DD` form.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_date: Option<String>,
+    /// In `HH:mm` form.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_time: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ViewSubmission {
+    pub user: UserRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_id: Option<String>,
+    /// The submitted view, whose `state` holds the values of its inputs.
+    pub view: Box<View>,
+    /// Where messages can be posted, for modals with a `response_url_enabled` conversation select.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_urls: Option<Vec<ResponseUrl>>,
+}
+
+impl ViewSubmission {
+    /// The values of the view's inputs.
+    pub fn state(&self) -> Option<&ViewState> {
+        self.view.state.as_ref()
+    }
+}
+
+/// A `response_url` for a conversation chosen in a modal.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResponseUrl {
+    pub block_id: String,
+    pub action_id: String,
+    pub channel_id: String,
+    pub response_url: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ViewClosed {
+    pub user: UserRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    pub view: Box<View>,
+    /// Whether the whole stack of views was closed, rather than only this one.
+    #[serde(default)]
+    pub is_cleared: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Shortcut {
+    pub callback_id: String,
+    pub user: UserRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_ts: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MessageAction {
+    pub callback_id: String,
+    pub user: UserRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<ChannelRef>,
+    /// The message the shortcut was used on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Box<Message>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_ts: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InteractiveMessage {
+    /// The `callback_id` of the attachment.
+    pub callback_id: String,
+    pub actions: Vec<AttachmentAction>,
+    pub user: UserRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<ChannelRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_ts: Option<String>,
+    /// The position of the attachment in the message, starting from 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment_id: Option<String>,
+    /// The message, unless it was ephemeral.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_message: Option<Box<Message>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_url: Option<String>,
+}
+
+/// The use of a legacy attachment button or menu.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AttachmentAction {
+    pub name: String,
+    /// `button` or `select`.
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_options: Option<Vec<AttachmentSelectedOption>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AttachmentSelectedOption {
+    pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DialogSubmission {
+    pub callback_id: String,
+    /// The values of the dialog's elements by name. Optional elements left empty are `None`.
+    pub submission: HashMap<String, Option<String>>,
+    /// The `state` the dialog was opened with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    pub user: UserRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<ChannelRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_url: Option<String>,
+}
+
+/// A modal or App Home. Views sent in payloads carry their `id` and `state`; views sent in a
+/// [`ViewResponse`] are built with [`modal`].
+///
+/// [`ViewResponse`]: enum.ViewResponse.html
+/// [`modal`]: #method.modal
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct View {
+    /// `modal` or `home`.
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Text>,
+    #[serde(default)]
+    pub blocks: Vec<Block>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    /// Up to 3000 characters the app keeps with the view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_metadata: Option<String>,
+    /// Closes every view in the stack when this one is closed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear_on_close: Option<bool>,
+    /// Sends `view_closed` when the view is closed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_close: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The values of the view's inputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<ViewState>,
+    /// Changes whenever the view is updated, so that updates based on a stale view can be rejected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root_view_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_view_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<String>,
+}
+
+impl View {
+    /// Creates a modal.
+    pub fn modal(title: impl Into<String>, blocks: Vec<Block>) -> Self {
+        View {
+            ty: "modal".to_owned(),
+            title: Some(Text::plain(title)),
+            blocks,
+            submit: None,
+            close: None,
+            callback_id: None,
+            private_metadata: None,
+            clear_on_close: None,
+            notify_on_close: None,
+            external_id: None,
+            id: None,
+            team_id: None,
+            state: None,
+            hash: None,
+            root_view_id: None,
+            previous_view_id: None,
+            app_id: None,
+            bot_id: None,
+        }
+    }
+}
+
+setters!(View {
+    submit: Text,
+    close: Text,
+    callback_id: String,
+    /// Up to 3000 characters the app keeps with the view.
+    private_metadata: String,
+    /// Closes every view in the stack when this one is closed.
+    clear_on_close: bool,
+    /// Sends `view_closed` when the view is closed.
+    notify_on_close: bool,
+    external_id: String,
+});
+
+/// The values of the inputs of a view or message, by `block_id` and then `action_id`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ViewState {
+    #[serde(default)]
+    pub values: HashMap<String, HashMap<String, StateValue>>,
+}
+
+impl ViewState {
+    /// The value of an element.
+    pub fn get(&self, block_id: &str, action_id: &str) -> Option<&StateValue> {
+        self.values.get(block_id)?.get(action_id)
+    }
+
+    /// The value of the first element with `action_id` in any block, for views whose
+    /// `block_id`s are generated.
+    pub fn find(&self, action_id: &str) -> Option<&StateValue> {
+        self.values.values().find_map(|block| block.get(action_id))
+    }
+
+    /// The single value of an element, as described by [`StateValue::value`].
+    ///
+    /// [`StateValue::value`]: struct.StateValue.html#method.value
+    pub fn value(&self, block_id: &str, action_id: &str) -> Option<&str> {
+        self.get(block_id, action_id)?.value()
+    }
+
+    /// Every value of an element, as described by [`StateValue::values`].
+    ///
+    /// [`StateValue::values`]: struct.StateValue.html#method.values
+    pub fn values(&self, block_id: &str, action_id: &str) -> Vec<&str> {
+        self.get(block_id, action_id).map(StateValue::values).unwrap_or_default()
+    }
+}
+
+/// The value of an input element. Only the fields for the element's type are set.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StateValue {
+    /// The type of the element, such as `plain_text_input` or `static_select`.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The text of a `plain_text_input`, or the value of an `email_text_input`, `url_text_input`
+    /// or `number_input`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_option: Option<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_options: Option<Vec<SelectOption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_users: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_channels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_conversation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_conversations: Option<Vec<String>>,
+    /// In `YYYY-MM-DD` form.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_date: Option<String>,
+    /// In `HH:mm` form.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_time: Option<String>,
+    /// In seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_date_time: Option<i64>,
+}
+
+impl StateValue {
+    /// The value of a single-valued element: the text entered, or the value of the option,
+    /// user, channel, conversation, date or time selected. `None` if nothing was entered.
+    pub fn value(&self) -> Option<&str> {
+        self.value
+            .as_deref()
+            .or_else(|| self.selected_option.as_ref().map(|option| option.value.as_str()))
+            .or(self.selected_user.as_deref())
+            .or(self.selected_channel.as_deref())
+            .or(self.selected_conversation.as_deref())
+            .or(self.selected_date.as_deref())
+            .or(self.selected_time.as_deref())
+    }
+
+    /// The values of a multi-select, or the single value of any other element.
+    pub fn values(&self) -> Vec<&str> {
+        if let Some(ref options) = self.selected_options {
+            return options.iter().map(|option| option.value.as_str()).collect();
+        }
+        let selected = self.selected_users.as_ref()
+            .or(self.selected_channels.as_ref())
+            .or(self.selected_conversations.as_ref());
+        match selected {
+            Some(selected) => selected.iter().map(String::as_str).collect(),
+            None => self.value().into_iter().collect(),
+        }
+    }
+}
+
+/// The body of the response to a `view_submission`, which changes the stack of views.
+/// Responding with an empty body closes the submitted view.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "response_action", rename_all = "snake_case")]
+pub enum ViewResponse {
+    /// Shows errors next to inputs, by `block_id`, and keeps the view open.
+    Errors {
+        errors: BTreeMap<String, String>,
+    },
+    /// Replaces the submitted view.
+    Update {
+        view: Box<View>,
+    },
+    /// Shows a new view on top of the stack.
+    Push {
+        view: Box<View>,
+    },
+    /// Closes every view in the stack.
+    Clear,
+}
+
+impl ViewResponse {
+    pub fn errors<I, K, V>(errors: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        ViewResponse::Errors {
+            errors: errors.into_iter().map(|(block_id, error)| (block_id.into(), error.into())).collect(),
+        }
+    }
+
+    pub fn update(view: View) -> Self {
+        ViewResponse::Update { view: Box::new(view) }
+    }
+
+    pub fn push(view: View) -> Self {
+        ViewResponse::Push { view: Box::new(view) }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("view responses always serialize to JSON")
+    }
+}
+
+/// The body of the response to a `dialog_submission` which shows errors next to elements.
+/// Responding with an empty body closes the dialog.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DialogErrors {
+    pub errors: Vec<DialogError>,
+}
+
+impl DialogErrors {
+    pub fn new() -> Self {
+        DialogErrors::default()
+    }
+
+    /// Adds an error for the element with `name`.
+    pub fn error(mut self, name: impl Into<String>, error: impl Into<String>) -> Self {
+        self.errors.push(DialogError { name: name.into(), error: error.into() });
+        self
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("dialog errors always serialize to JSON")
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DialogError {
+    pub name: String,
+    pub error: String,
+}
+
+/// Checks and parses requests to an interactivity endpoint. Requires the `signing` feature.
+#[cfg(feature = "signing")]
+pub type Receiver = signature::Receiver<Interaction>;
+
+#[cfg(feature = "signing")]
+impl SignedPayload for Interaction {
+    fn parse(body: &[u8]) -> Result<Self, serde_json::Error> {
+        Interaction::parse(body)
+    }
+
+    fn token(&self) -> Option<&str> {
+        Interaction::token(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blocks::{InputBlock, PlainTextInputElement};
+
+    const BLOCK_ACTIONS: &str = r#"{
+        "type": "block_actions",
+        "user": {"id": "U1", "username": "steve", "team_id": "T1"},
+        "team": {"id": "T1", "domain": "example"},
+        "api_app_id": "A1",
+        "token": "XXYYZZ",
+        "container": {"type": "message", "message_ts": "1.000001", "channel_id": "C1", "is_ephemeral": false},
+        "trigger_id": "1.2.3",
+        "channel": {"id": "C1", "name": "general"},
+        "message": {"type": "message", "user": "U2", "text": "Deploy?", "ts": "1.000001"},
+        "response_url": "https://hooks.slack.com/actions/T1/1/2",
+        "actions": [
+            {"action_id": "deploy", "block_id": "b1", "type": "button", "value": "main", "action_ts": "2.0",
+             "text": {"type": "plain_text", "text": "Deploy"}},
+            {"action_id": "env", "block_id": "b1", "type": "static_select", "action_ts": "2.0",
+             "selected_option": {"text": {"type": "plain_text", "text": "Prod"}, "value": "prod"}}
+        ]
+    }"#;
+
+    const VIEW_SUBMISSION: &str = r#"{
+        "type": "view_submission",
+        "user": {"id": "U1"},
+        "team": {"id": "T1"},
+        "view": {
+            "id": "V1",
+            "type": "modal",
+            "callback_id": "signup",
+            "title": {"type": "plain_text", "text": "Sign up"},
+            "blocks": [],
+            "hash": "1.abc",
+            "state": {"values": {
+                "email": {"input": {"type": "plain_text_input", "value": "a@example.com"}},
+                "teams": {"pick": {"type": "multi_static_select", "selected_options": [
+                    {"text": {"type": "plain_text", "text": "Ops"}, "value": "ops"},
+                    {"text": {"type": "plain_text", "text": "Web"}, "value": "web"}
+                ]}},
+                "b3": {"lead": {"type": "users_select", "selected_user": "U9"}},
+                "b4": {"notes": {"type": "plain_text_input", "value": null}}
+            }}
+        }
+    }"#;
+
+    #[test]
+    fn test_parse_interactions() {
+        let body = format!("payload={}", BLOCK_ACTIONS.replace('%', "%25").replace('&', "%26").replace('+', "%2B"));
+        let interaction = Interaction::parse(body.as_bytes()).unwrap();
+        assert_eq!("block_actions", interaction.ty());
+        assert_eq!(Some("U1"), interaction.user().map(|user| user.id.as_str()));
+        assert_eq!(Some("https://hooks.slack.com/actions/T1/1/2"), interaction.response_url());
+        match interaction {
+            Interaction::BlockActions(ref actions) => {
+                assert_eq!(Some("main"), actions.actions[0].value.as_deref());
+                assert_eq!("prod", actions.actions[1].selected_option.as_ref().unwrap().value);
+                assert!(actions.message.is_some());
+            }
+            ref other => panic!("unexpected {:?}", other),
+        }
+        assert!(Interaction::parse(b"token=x").is_err());
+
+        let interaction: Interaction = serde_json::from_str(VIEW_SUBMISSION).unwrap();
+        let state = match interaction {
+            Interaction::ViewSubmission(ref submission) => submission.state().unwrap().clone(),
+            ref other => panic!("unexpected {:?}", other),
+        };
+        assert_eq!(Some("a@example.com"), state.value("email", "input"));
+        assert_eq!(vec!["ops", "web"], state.values("teams", "pick"));
+        assert_eq!(Some("U9"), state.find("lead").and_then(StateValue::value));
+        assert_eq!(None, state.value("b4", "notes"));
+        assert_eq!(Vec::<&str>::new(), state.values("missing", "notes"));
+
+        let shortcut: Interaction = serde_json::from_str(
+            r#"{"type": "shortcut", "callback_id": "new", "user": {"id": "U1"}, "trigger_id": "1.2"}"#,
+        ).unwrap();
+        assert_eq!(Some("1.2"), shortcut.trigger_id());
+
+        let dialog: Interaction = serde_json::from_str(
+            r#"{"type": "dialog_submission", "callback_id": "d", "user": {"id": "U1"}, "submission": {"name": "x", "age": null}}"#,
+        ).unwrap();
+        match dialog {
+            Interaction::DialogSubmission(ref dialog) => assert_eq!(Some(&None), dialog.submission.get("age")),
+            ref other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_responses() {
+        assert_eq!(
+            r#"{"response_action":"errors","errors":{"email":"Enter an email address"}}"#,
+            ViewResponse::errors(vec![("email", "Enter an email address")]).to_json()
+        );
+        assert_eq!(r#"{"response_action":"clear"}"#, ViewResponse::Clear.to_json());
+
+        let view = View::modal("Thanks", vec![InputBlock::new(Text::plain("Notes"), PlainTextInputElement::new("notes")).into()])
+            .callback_id("thanks");
+        let response: serde_json::Value = serde_json::from_str(&ViewResponse::push(view).to_json()).unwrap();
+        assert_eq!("push", response["response_action"]);
+        assert_eq!("modal", response["view"]["type"]);
+        assert_eq!("Thanks", response["view"]["title"]["text"]);
+        assert_eq!(None, response["view"].get("id"));
+
+        assert_eq!(
+            r#"{"errors":[{"name":"email","error":"Invalid"}]}"#,
+            DialogErrors::new().error("email", "Invalid").to_json()
+        );
+    }
+}
@@ -15,10 +15,133 @@
 //! Low-level, direct interface for the [Slack Web
 //! API](https://api.slack.com/methods).
 
+#[cfg(feature = "signing")]
+extern crate hmac;
+#[cfg(feature = "markdown")]
+extern crate pulldown_cmark;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 extern crate serde_json;
+#[cfg(feature = "signing")]
+extern crate sha2;
+#[cfg(any(feature = "rtm", feature = "socket-mode"))]
+extern crate tungstenite;
+
+/// Defines a chainable setter for each optional field of a struct.
+macro_rules! setters {
+    ($name:ident { $($(#[$attr:meta])* $field:ident: $ty:ty,)* }) => {
+        impl $name {
+            $(
+                $(#[$attr])*
+                pub fn $field(mut self, $field: impl Into<$ty>) -> Self {
+                    self.$field = Some($field.into());
+                    self
+                }
+            )*
+        }
+    };
+}
+
+/// Defines an enum over structs which are distinguished by their `type` field, with an `Unknown`
+/// variant holding any other type as raw JSON.
+///
+/// Each struct converts into its variant with `From`, unless the enum is prefixed with `shared;`
+/// because some structs are used by more than one variant.
+macro_rules! tagged_enum {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_attr:meta])* $variant:ident($inner:ident) = $tag:expr,)*
+        }
+    ) => {
+        tagged_enum! {
+            shared;
+            $(#[$attr])*
+            pub enum $name {
+                $($(#[$variant_attr])* $variant($inner) = $tag,)*
+            }
+        }
+
+        $(
+            impl From<$inner> for $name {
+                fn from(inner: $inner) -> Self {
+                    $name::$variant(inner)
+                }
+            }
+        )*
+    };
+    (
+        shared;
+        $(#[$attr:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_attr:meta])* $variant:ident($inner:ident) = $tag:expr,)*
+        }
+    ) => {
+        $(#[$attr])*
+        pub enum $name {
+            $($(#[$variant_attr])* $variant($inner),)*
+            /// A type this crate does not model yet.
+            Unknown {
+                /// The unrecognised `type`.
+                ty: String,
+                /// The complete object as received.
+                raw: ::serde_json::Value,
+            },
+        }
+
+        impl $name {
+            /// The value of the `type` field this is serialized with.
+            pub fn ty(&self) -> &str {
+                match *self {
+                    $($name::$variant(_) => $tag,)*
+                    $name::Unknown { ref ty, .. } => ty,
+                }
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                use ::serde::ser::Error;
+                use ::serde_json::Value;
+
+                let value = match *self {
+                    $($name::$variant(ref inner) => ::serde_json::to_value(inner),)*
+                    $name::Unknown { ref raw, .. } => return raw.serialize(serializer),
+                };
+                let mut map = match value.map_err(S::Error::custom)? {
+                    Value::Object(map) => map,
+                    _ => return Err(S::Error::custom("expected an object")),
+                };
+                map.insert("type".to_owned(), Value::String(self.ty().to_owned()));
+                map.serialize(serializer)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                use ::serde::de::Error;
+                use ::serde_json::Value;
+
+                let value = Value::deserialize(deserializer)?;
+                let ty = match value.get("type").and_then(Value::as_str) {
+                    Some(ty) => ty.to_owned(),
+                    None => return Err(D::Error::missing_field("type")),
+                };
+                match &ty[..] {
+                    $($tag => ::serde_json::from_value(value).map($name::$variant).map_err(D::Error::custom),)*
+                    _ => Ok($name::Unknown { ty, raw: value }),
+                }
+            }
+        }
+    };
+}
 
 mod mods;
 pub use mods::*;
@@ -26,7 +149,32 @@ pub use mods::*;
 mod types;
 pub use types::*;
 
+mod message;
+pub use message::*;
+
+mod form;
+
+pub mod attachments;
+pub mod blocks;
+pub mod event;
+pub mod events_api;
+pub mod history;
+pub mod interaction;
+pub mod long_message;
+pub mod mrkdwn;
+pub mod paging;
+pub mod permalink;
+pub mod render;
 pub mod requests;
+#[cfg(feature = "rtm")]
+pub mod rtm_client;
+#[cfg(feature = "signing")]
+pub mod signature;
+pub mod slash_command;
+#[cfg(feature = "socket-mode")]
+pub mod socket_mode;
+pub mod thread;
+pub mod workspace;
 
 #[cfg(feature = "reqwest")]
 pub use requests::default_client;
@@ -80,8 +228,55 @@ fn optional_struct_or_empty_array<'de, T, D>(deserializer: D) -> Result<Option<T
 
 #[cfg(test)]
 mod tests {
+    use std::cell::RefCell;
+    use std::error::Error;
+    use std::fmt;
+
     use serde_json;
-    use super::UserProfile;
+    use super::{Message, UserProfile};
+    use super::chat;
+    use super::conversations;
+    use requests::SlackWebRequestSender;
+
+    #[derive(Debug)]
+    pub struct NoError;
+
+    impl fmt::Display for NoError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("no error")
+        }
+    }
+
+    impl Error for NoError {}
+
+    /// The method URL and params of a request sent to a `Replay`.
+    pub type Recorded = (String, Vec<(String, String)>);
+
+    /// Replays canned responses in order and records the method and params of every request.
+    pub struct Replay {
+        responses: RefCell<Vec<&'static str>>,
+        pub requests: RefCell<Vec<Recorded>>,
+    }
+
+    impl Replay {
+        pub fn new(responses: Vec<&'static str>) -> Self {
+            Replay { responses: RefCell::new(responses), requests: RefCell::new(Vec::new()) }
+        }
+    }
+
+    impl SlackWebRequestSender for Replay {
+        type Error = NoError;
+
+        fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, NoError> {
+            let params = params.iter().map(|&(k, v)| (k.to_owned(), v.to_owned())).collect();
+            self.requests.borrow_mut().push((method_url.to_owned(), params));
+            Ok(self.responses.borrow_mut().remove(0).to_owned())
+        }
+    }
+
+    pub fn param(request: &Recorded, name: &str) -> Option<String> {
+        request.1.iter().find(|p| p.0 == name).map(|p| p.1.clone())
+    }
 
     #[test]
     fn test_user_profile_fields_empty_array_deserialize() {
@@ -112,4 +307,60 @@ mod tests {
         let user_profile: UserProfile = serde_json::from_str(r#"{}"#).unwrap();
         assert!(user_profile.fields.is_none());
     }
+
+    #[test]
+    fn test_request_builder_into_owned_round_trip() {
+        let channel = String::from("C1234567890");
+        let request = chat::PostMessageRequest::new(&channel[..], "hello")
+            .thread_ts("1503435956.000247")
+            .as_user(true)
+            .into_owned();
+        drop(channel);
+
+        let json = serde_json::to_string(&request).unwrap();
+        let request: chat::PostMessageRequest<'static> = serde_json::from_str(&json).unwrap();
+        assert_eq!("C1234567890", request.channel);
+        assert_eq!("hello", request.text);
+        assert_eq!(Some("1503435956.000247"), request.thread_ts.as_ref().map(|ts| &ts[..]));
+        assert_eq!(Some(true), request.as_user);
+        assert!(request.username.is_none());
+    }
+
+    #[test]
+    fn test_conversations_list_response_cursor_deserialize() {
+        let response: conversations::ListResponse = serde_json::from_str(r#"{
+            "ok": true,
+            "channels": [{"id": "C012AB3CD", "name": "general", "is_channel": true, "is_private": false},
+                         {"id": "D0C0F7S8Y", "is_im": true, "user": "U0BS9U4SV"}],
+            "response_metadata": {"next_cursor": "dGVhbTpDMDYxRkE1UEI="}
+        }"#).unwrap();
+        let channels = response.channels.unwrap();
+        assert_eq!(2, channels.len());
+        assert_eq!(Some(true), channels[1].is_im);
+        assert_eq!(Some("dGVhbTpDMDYxRkE1UEI=".to_owned()), response.response_metadata.unwrap().next_cursor);
+    }
+
+    #[test]
+    fn test_message_new_and_unknown_subtypes_deserialize() {
+        let messages: Vec<Message> = serde_json::from_str(r#"[
+            {"type": "message", "subtype": "tombstone", "ts": "1500000001.000000", "hidden": true, "text": "This message was deleted."},
+            {"type": "message", "subtype": "channel_join", "ts": "1500000002.000000", "user": "U1", "inviter": "U2"},
+            {"type": "message", "subtype": "huddle_thread", "ts": "1500000003.000000", "text": "new"}
+        ]"#).unwrap();
+        match messages[0] {
+            Message::Tombstone(ref m) => assert_eq!(Some(true), m.hidden),
+            ref other => panic!("unexpected {:?}", other),
+        }
+        match messages[1] {
+            Message::ChannelJoin(ref m) => assert_eq!(Some("U2".to_owned()), m.inviter),
+            ref other => panic!("unexpected {:?}", other),
+        }
+        match messages[2] {
+            Message::Unknown { ref subtype, ref raw } => {
+                assert_eq!("huddle_thread", subtype);
+                assert_eq!("new", raw["text"]);
+            }
+            ref other => panic!("unexpected {:?}", other),
+        }
+    }
 }
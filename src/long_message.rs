@@ -0,0 +1,94 @@
+//! Posting text which is too long for a single message.
+//!
+//! Slack truncates or rejects messages with very long text. [`post`] splits the text with
+//! [`mrkdwn::split`] and posts each part in turn:
+//!
+//! ```no_run
+//! # fn run<R: slack_api::requests::SlackWebRequestSender>(client: &R, log: &str) {
+//! use slack_api::chat::PostMessageRequest;
+//! use slack_api::long_message::{post, Threading};
+//! use slack_api::mrkdwn::MAX_MESSAGE_CHARS;
+//!
+//! let request = PostMessageRequest::new("#ci", log);
+//! post(client, "xoxb-token", &request, MAX_MESSAGE_CHARS, Threading::UnderFirst).unwrap();
+//! # }
+//! ```
+//!
+//! [`post`]: fn.post.html
+//! [`mrkdwn::split`]: ../mrkdwn/fn.split.html
+
+use std::borrow::Cow;
+
+use chat::{self, PostMessageError, PostMessageRequest, PostMessageResponse};
+use mrkdwn;
+use requests::SlackWebRequestSender;
+
+/// Where the parts after the first are posted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Threading {
+    /// Alongside the first part, in the channel or thread the request is for.
+    None,
+    /// As replies in a thread started by the first part. If the request is already a reply, the
+    /// parts are all posted in its thread.
+    UnderFirst,
+}
+
+/// Posts `request` as one or more messages whose text is at most `max_chars` characters long.
+///
+/// Every part uses the rest of the request as it is, except that attachments and blocks are only
+/// sent with the last part so they follow the complete text. The responses are returned in order.
+/// If a part fails, the parts before it will already have been posted.
+pub fn post<R>(
+    client: &R,
+    token: &str,
+    request: &PostMessageRequest,
+    max_chars: usize,
+    threading: Threading,
+) -> Result<Vec<PostMessageResponse>, PostMessageError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let parts = mrkdwn::split(&request.text, max_chars);
+    let mut responses: Vec<PostMessageResponse> = Vec::with_capacity(parts.len());
+    for (i, part) in parts.iter().enumerate() {
+        let mut part_request = request.clone();
+        part_request.text = Cow::Borrowed(part);
+        if i + 1 < parts.len() {
+            part_request.attachments = None;
+            part_request.blocks = None;
+        }
+        if threading == Threading::UnderFirst && part_request.thread_ts.is_none() {
+            if let Some(ts) = responses.first().and_then(|first| first.ts.as_ref()) {
+                part_request.thread_ts = Some(Cow::Borrowed(ts));
+            }
+        }
+        let response = chat::post_message(client, token, &part_request)?;
+        responses.push(response);
+    }
+    Ok(responses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tests::{param, Replay};
+
+    #[test]
+    fn test_post_threads_parts_under_first() {
+        let client = Replay::new(vec![
+            r#"{"ok": true, "channel": "C1", "ts": "1.0"}"#,
+            r#"{"ok": true, "channel": "C1", "ts": "2.0"}"#,
+        ]);
+        let request = PostMessageRequest::new("C1", "first paragraph\n\nsecond paragraph").blocks("[]");
+        let responses = post(&client, "token", &request, 20, Threading::UnderFirst).unwrap();
+
+        assert_eq!(2, responses.len());
+        let requests = client.requests.borrow();
+        assert_eq!(Some("first paragraph".to_owned()), param(&requests[0], "text"));
+        assert_eq!(None, param(&requests[0], "thread_ts"));
+        assert_eq!(None, param(&requests[0], "blocks"));
+        assert_eq!(Some("second paragraph".to_owned()), param(&requests[1], "text"));
+        assert_eq!(Some("1.0".to_owned()), param(&requests[1], "thread_ts"));
+        assert_eq!(Some("[]".to_owned()), param(&requests[1], "blocks"));
+    }
+}
@@ -0,0 +1,213 @@
+//! Accessors for the fields most `Message` subtypes have in common.
+
+use blocks::Block;
+use types::{Message, MessageStandardAttachment, Reaction};
+
+/// Who last edited a message, and when.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Edited<'a> {
+    pub user: Option<&'a str>,
+    pub ts: Option<&'a str>,
+}
+
+/// Reads an optional string field from the listed variants, and from the raw JSON of an
+/// `Unknown` message.
+macro_rules! field {
+    ($message:expr, $field:ident, [$($variant:ident),*]) => {
+        match *$message {
+            $(Message::$variant(ref m) => m.$field.as_deref(),)*
+            Message::Unknown { ref raw, .. } => raw.get(stringify!($field)).and_then(|v| v.as_str()),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    };
+}
+
+impl Message {
+    /// The message's `subtype`, or `None` for a standard message.
+    pub fn subtype(&self) -> Option<&str> {
+        match *self {
+            Message::Standard(_) => None,
+            Message::Unknown { ref subtype, .. } => Some(subtype),
+            _ => field!(self, subtype, [
+                BotAdd, BotMessage, BotRemove, ChannelArchive, ChannelConvertToPrivate,
+                ChannelJoin, ChannelLeave, ChannelName, ChannelPurpose, ChannelTopic,
+                ChannelUnarchive, FileComment, FileMention, FileShare, GroupArchive, GroupJoin,
+                GroupLeave, GroupName, GroupPurpose, GroupTopic, GroupUnarchive, MeMessage,
+                MessageChanged, MessageDeleted, MessageReplied, PinnedItem, ReplyBroadcast,
+                ShRoomCreated, ThreadBroadcast, Tombstone, UnpinnedItem
+            ]),
+        }
+    }
+
+    /// The message's `ts`, which identifies it within its channel.
+    ///
+    /// For `message_changed`, `message_deleted` and `message_replied` this is the time of the
+    /// event, not of the message it refers to.
+    pub fn ts(&self) -> Option<&str> {
+        field!(self, ts, [
+            Standard, BotAdd, BotMessage, BotRemove, ChannelArchive, ChannelConvertToPrivate,
+            ChannelJoin, ChannelLeave, ChannelName, ChannelPurpose, ChannelTopic,
+            ChannelUnarchive, FileComment, FileMention, FileShare, GroupArchive, GroupJoin,
+            GroupLeave, GroupName, GroupPurpose, GroupTopic, GroupUnarchive, MeMessage,
+            MessageChanged, MessageDeleted, MessageReplied, PinnedItem, ReplyBroadcast,
+            ShRoomCreated, ThreadBroadcast, Tombstone, UnpinnedItem
+        ])
+    }
+
+    /// The id of the user who posted the message.
+    ///
+    /// For `message_changed` and `message_replied` this is the author of the message referred to.
+    pub fn user(&self) -> Option<&str> {
+        match *self {
+            Message::MessageChanged(ref m) => m.message.as_ref().and_then(|m| m.user.as_deref()),
+            Message::MessageReplied(ref m) => m.message.as_ref().and_then(|m| m.user.as_deref()),
+            _ => field!(self, user, [
+                Standard, BotAdd, BotRemove, ChannelArchive, ChannelConvertToPrivate, ChannelJoin,
+                ChannelLeave, ChannelName, ChannelPurpose, ChannelTopic, ChannelUnarchive,
+                FileMention, FileShare, GroupArchive, GroupJoin, GroupLeave, GroupName,
+                GroupPurpose, GroupTopic, GroupUnarchive, MeMessage, PinnedItem, ReplyBroadcast,
+                ShRoomCreated, ThreadBroadcast, Tombstone, UnpinnedItem
+            ]),
+        }
+    }
+
+    /// The id of the bot which posted the message, or which was added or removed.
+    pub fn bot_id(&self) -> Option<&str> {
+        match *self {
+            Message::MessageChanged(ref m) => m.message.as_ref().and_then(|m| m.bot_id.as_deref()),
+            Message::MessageReplied(ref m) => m.message.as_ref().and_then(|m| m.bot_id.as_deref()),
+            _ => field!(self, bot_id, [Standard, BotAdd, BotMessage, BotRemove]),
+        }
+    }
+
+    /// The message text.
+    ///
+    /// For `message_changed` and `message_replied` this is the text of the message referred to.
+    pub fn text(&self) -> Option<&str> {
+        match *self {
+            Message::MessageChanged(ref m) => m.message.as_ref().and_then(|m| m.text.as_deref()),
+            Message::MessageReplied(ref m) => m.message.as_ref().and_then(|m| m.text.as_deref()),
+            _ => field!(self, text, [
+                Standard, BotAdd, BotMessage, BotRemove, ChannelArchive, ChannelConvertToPrivate,
+                ChannelJoin, ChannelLeave, ChannelName, ChannelPurpose, ChannelTopic,
+                ChannelUnarchive, FileComment, FileMention, FileShare, GroupArchive, GroupJoin,
+                GroupLeave, GroupName, GroupPurpose, GroupTopic, GroupUnarchive, MeMessage,
+                PinnedItem, ShRoomCreated, ThreadBroadcast, Tombstone, UnpinnedItem
+            ]),
+        }
+    }
+
+    /// The channel the message was posted in.
+    ///
+    /// Slack only includes this where the channel is not implied, such as in RTM events.
+    pub fn channel(&self) -> Option<&str> {
+        field!(self, channel, [
+            Standard, BotAdd, BotRemove, ChannelConvertToPrivate, MeMessage, MessageChanged,
+            MessageDeleted, MessageReplied, PinnedItem, ReplyBroadcast, ShRoomCreated,
+            ThreadBroadcast, UnpinnedItem
+        ])
+    }
+
+    /// The `ts` of the parent of the thread this message belongs to, if any.
+    pub fn thread_ts(&self) -> Option<&str> {
+        match *self {
+            Message::MessageChanged(ref m) => {
+                m.message.as_ref().and_then(|m| m.thread_ts.as_deref())
+            }
+            _ => field!(self, thread_ts, [Standard, MessageReplied, ThreadBroadcast, Tombstone]),
+        }
+    }
+
+    /// Whether this message starts a thread, as opposed to being a reply or not threaded at all.
+    pub fn is_thread_parent(&self) -> bool {
+        match (self.thread_ts(), self.ts()) {
+            (Some(thread_ts), Some(ts)) => thread_ts == ts,
+            _ => false,
+        }
+    }
+
+    /// Who last edited the message and when, if it has been edited.
+    pub fn edited<'a>(&'a self) -> Option<Edited<'a>> {
+        match *self {
+            Message::Standard(ref m) => m.edited.as_ref().map(|e| Edited {
+                user: e.user.as_deref(),
+                ts: e.ts.as_deref(),
+            }),
+            Message::MessageChanged(ref m) => {
+                m.message.as_ref().and_then(|m| m.edited.as_ref()).map(|e| Edited {
+                    user: e.user.as_deref(),
+                    ts: e.ts.as_deref(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// The reactions added to the message.
+    pub fn reactions(&self) -> &[Reaction] {
+        let reactions = match *self {
+            Message::Standard(ref m) => &m.reactions,
+            Message::BotMessage(ref m) => &m.reactions,
+            Message::FileShare(ref m) => &m.reactions,
+            Message::MeMessage(ref m) => &m.reactions,
+            Message::ThreadBroadcast(ref m) => &m.reactions,
+            _ => return &[],
+        };
+        reactions.as_deref().unwrap_or(&[])
+    }
+
+    /// The message's Block Kit layout.
+    ///
+    /// For `message_changed` these are the blocks of the message after the change.
+    pub fn blocks(&self) -> &[Block] {
+        let blocks = match *self {
+            Message::Standard(ref m) => &m.blocks,
+            Message::BotMessage(ref m) => &m.blocks,
+            Message::MeMessage(ref m) => &m.blocks,
+            Message::ThreadBroadcast(ref m) => &m.blocks,
+            Message::MessageChanged(ref m) => match m.message {
+                Some(ref m) => &m.blocks,
+                None => return &[],
+            },
+            _ => return &[],
+        };
+        blocks.as_deref().unwrap_or(&[])
+    }
+
+    /// The message's attachments.
+    pub fn attachments(&self) -> &[MessageStandardAttachment] {
+        match *self {
+            Message::Standard(ref m) => m.attachments.as_deref().unwrap_or(&[]),
+            Message::BotMessage(ref m) => m.attachments.as_deref().unwrap_or(&[]),
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_accessors_across_subtypes() {
+        let messages: Vec<Message> = serde_json::from_str(r#"[
+            {"type": "message", "ts": "1.2", "thread_ts": "1.2", "user": "U1", "text": "hi",
+             "edited": {"user": "U1", "ts": "1.3"}, "reactions": [{"name": "+1", "count": 1, "users": ["U2"]}]},
+            {"type": "message", "subtype": "bot_message", "ts": "2.0", "bot_id": "B1", "text": "beep"},
+            {"type": "message", "subtype": "message_changed", "ts": "3.0", "channel": "C1",
+             "message": {"type": "message", "ts": "1.2", "user": "U1", "text": "hello", "edited": {"user": "U1", "ts": "3.0"}}},
+            {"type": "message", "subtype": "something_new", "ts": "4.0", "user": "U3", "text": "?"}
+        ]"#).unwrap();
+
+        assert!(messages[0].is_thread_parent());
+        assert_eq!(Some(Edited { user: Some("U1"), ts: Some("1.3") }), messages[0].edited());
+        assert_eq!("+1", messages[0].reactions()[0].name.as_ref().unwrap());
+        assert_eq!((Some("B1"), None, Some("beep")), (messages[1].bot_id(), messages[1].user(), messages[1].text()));
+        assert_eq!((Some("3.0"), Some("hello"), Some("C1")), (messages[2].ts(), messages[2].text(), messages[2].channel()));
+        assert_eq!(Some("3.0"), messages[2].edited().unwrap().ts);
+        assert_eq!((Some("something_new"), Some("U3")), (messages[3].subtype(), messages[3].user()));
+        assert!(messages[3].reactions().is_empty());
+    }
+}
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -20,8 +22,8 @@ where
 {
 
     let params = vec![
-        request.error.map(|error| ("error", error)),
-        request.foo.map(|foo| ("foo", foo)),
+        request.error.as_ref().map(|error| ("error", &error[..])),
+        request.foo.as_ref().map(|foo| ("foo", &foo[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("api.test");
@@ -34,17 +36,42 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct TestRequest<'a> {
     /// Error response to return
-    pub error: Option<&'a str>,
+    pub error: Option<Cow<'a, str>>,
     /// example property to return
-    pub foo: Option<&'a str>,
+    pub foo: Option<Cow<'a, str>>,
+}
+
+impl<'a> TestRequest<'a> {
+    /// Error response to return
+    pub fn error(mut self, error: impl Into<Cow<'a, str>>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
+    /// example property to return
+    pub fn foo(mut self, foo: impl Into<Cow<'a, str>>) -> Self {
+        self.foo = Some(foo.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> TestRequest<'static> {
+        TestRequest {
+            error: self.error.map(|error| Cow::Owned(error.into_owned())),
+            foo: self.foo.map(|foo| Cow::Owned(foo.into_owned())),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TestResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub args: Option<HashMap<String, bool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,9 +27,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.test.map(
-            |test| ("test", if test { "1" } else { "0" })
-        ),
+        request.test.map(|test| ("test", if test { "1" } else { "0" })),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("auth.revoke");
@@ -40,17 +40,27 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct RevokeRequest {
     /// Setting this parameter to 1 triggers a testing mode where the specified token will not actually be revoked.
     pub test: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl RevokeRequest {
+    /// Setting this parameter to 1 triggers a testing mode where the specified token will not actually be revoked.
+    pub fn test(mut self, test: bool) -> Self {
+        self.test = Some(test);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RevokeResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub revoked: Option<bool>,
 }
 
@@ -187,15 +197,21 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TestResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<String>,
 }
 
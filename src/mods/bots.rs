@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -23,7 +25,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), request.bot.map(|bot| ("bot", bot))];
+    let params = vec![
+        Some(("token", token)),
+        request.bot.as_ref().map(|bot| ("bot", &bot[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("bots.info");
     client
@@ -35,33 +40,59 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct InfoRequest<'a> {
     /// Bot user to get info on
-    pub bot: Option<&'a str>,
+    pub bot: Option<Cow<'a, str>>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Bot user to get info on
+    pub fn bot(mut self, bot: impl Into<Cow<'a, str>>) -> Self {
+        self.bot = Some(bot.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> InfoRequest<'static> {
+        InfoRequest {
+            bot: self.bot.map(|bot| Cow::Owned(bot.into_owned())),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bot: Option<InfoResponseBot>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponseBot {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icons: Option<InfoResponseBotIcons>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponseBotIcons {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_36: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_48: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_72: Option<String>,
 }
 
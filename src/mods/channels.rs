@@ -1,6 +1,8 @@
 //! Get info on your team's Slack channels, create or archive channels, invite users, set the topic and purpose, and mark a channel as read.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -24,7 +26,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.archive");
     client
@@ -38,14 +43,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ArchiveRequest<'a> {
     /// Channel to archive
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> ArchiveRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        ArchiveRequest {
+            channel: channel.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> ArchiveRequest<'static> {
+        ArchiveRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ArchiveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -212,10 +235,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("name", request.name)),
-        request.validate.map(|validate| {
-            ("validate", if validate { "1" } else { "0" })
-        }),
+        Some(("name", &request.name[..])),
+        request.validate.map(|validate| ("validate", if validate { "1" } else { "0" })),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.create");
@@ -228,17 +249,44 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CreateRequest<'a> {
     /// Name of channel to create
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
     pub validate: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> CreateRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        CreateRequest {
+            name: name.into(),
+            validate: None,
+        }
+    }
+
+    /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> CreateRequest<'static> {
+        CreateRequest {
+            name: Cow::Owned(self.name.into_owned()),
+            validate: self.validate,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<::Channel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -427,16 +475,12 @@ where
     let count = request.count.map(|count| count.to_string());
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        request.latest.map(|latest| ("latest", latest)),
-        request.oldest.map(|oldest| ("oldest", oldest)),
-        request.inclusive.map(|inclusive| {
-            ("inclusive", if inclusive { "1" } else { "0" })
-        }),
+        Some(("channel", &request.channel[..])),
+        request.latest.as_ref().map(|latest| ("latest", &latest[..])),
+        request.oldest.as_ref().map(|oldest| ("oldest", &oldest[..])),
+        request.inclusive.map(|inclusive| ("inclusive", if inclusive { "1" } else { "0" })),
         count.as_ref().map(|count| ("count", &count[..])),
-        request.unreads.map(|unreads| {
-            ("unreads", if unreads { "1" } else { "0" })
-        }),
+        request.unreads.map(|unreads| ("unreads", if unreads { "1" } else { "0" })),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.history");
@@ -451,14 +495,39 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+/// Like [`history`], but returns an iterator over every item, requesting further pages as
+/// needed.
+///
+/// [`history`]: fn.history.html
+pub fn history_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &HistoryRequest<'a>,
+) -> ::paging::Items<'a, ::Message, HistoryError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut request = request.clone();
+    ::paging::Items::new(move |next| {
+        if let Some(next) = next {
+            request.latest = next.latest().map(|latest| Cow::Owned(latest.to_owned()));
+            request.inclusive = None;
+        }
+        history(client, token, &request).map(|response| {
+            let next = ::paging::next_window(response.has_more, response.messages.as_deref());
+            ::paging::Page { items: response.messages.unwrap_or_default(), next }
+        })
+    })
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct HistoryRequest<'a> {
     /// Channel to fetch history for.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// End of time range of messages to include in results.
-    pub latest: Option<&'a str>,
+    pub latest: Option<Cow<'a, str>>,
     /// Start of time range of messages to include in results.
-    pub oldest: Option<&'a str>,
+    pub oldest: Option<Cow<'a, str>>,
     /// Include messages with latest or oldest timestamp in results.
     pub inclusive: Option<bool>,
     /// Number of messages to return, between 1 and 1000.
@@ -467,11 +536,72 @@ pub struct HistoryRequest<'a> {
     pub unreads: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> HistoryRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        HistoryRequest {
+            channel: channel.into(),
+            latest: None,
+            oldest: None,
+            inclusive: None,
+            count: None,
+            unreads: None,
+        }
+    }
+
+    /// End of time range of messages to include in results.
+    pub fn latest(mut self, latest: impl Into<Cow<'a, str>>) -> Self {
+        self.latest = Some(latest.into());
+        self
+    }
+
+    /// Start of time range of messages to include in results.
+    pub fn oldest(mut self, oldest: impl Into<Cow<'a, str>>) -> Self {
+        self.oldest = Some(oldest.into());
+        self
+    }
+
+    /// Include messages with latest or oldest timestamp in results.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    /// Number of messages to return, between 1 and 1000.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Include unread_count_display in the output?
+    pub fn unreads(mut self, unreads: bool) -> Self {
+        self.unreads = Some(unreads);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> HistoryRequest<'static> {
+        HistoryRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            latest: self.latest.map(|latest| Cow::Owned(latest.into_owned())),
+            oldest: self.oldest.map(|oldest| Cow::Owned(oldest.into_owned())),
+            inclusive: self.inclusive,
+            count: self.count,
+            unreads: self.unreads,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub has_more: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub latest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
@@ -622,7 +752,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.info");
     client
@@ -634,15 +767,34 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct InfoRequest<'a> {
     /// Channel to get info on
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        InfoRequest {
+            channel: channel.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> InfoRequest<'static> {
+        InfoRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<::Channel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -783,8 +935,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("user", request.user)),
+        Some(("channel", &request.channel[..])),
+        Some(("user", &request.user[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.invite");
@@ -797,17 +949,38 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct InviteRequest<'a> {
     /// Channel to invite user to.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// User to invite to channel.
-    pub user: &'a str,
+    pub user: Cow<'a, str>,
+}
+
+impl<'a> InviteRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, user: impl Into<Cow<'a, str>>) -> Self {
+        InviteRequest {
+            channel: channel.into(),
+            user: user.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> InviteRequest<'static> {
+        InviteRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            user: Cow::Owned(self.user.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<::Channel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -994,10 +1167,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("name", request.name)),
-        request.validate.map(|validate| {
-            ("validate", if validate { "1" } else { "0" })
-        }),
+        Some(("name", &request.name[..])),
+        request.validate.map(|validate| ("validate", if validate { "1" } else { "0" })),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.join");
@@ -1010,17 +1181,44 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct JoinRequest<'a> {
     /// Name of channel to join
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
     pub validate: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> JoinRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        JoinRequest {
+            name: name.into(),
+            validate: None,
+        }
+    }
+
+    /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> JoinRequest<'static> {
+        JoinRequest {
+            name: Cow::Owned(self.name.into_owned()),
+            validate: self.validate,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<::Channel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -1217,8 +1415,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("user", request.user)),
+        Some(("channel", &request.channel[..])),
+        Some(("user", &request.user[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.kick");
@@ -1231,16 +1429,36 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct KickRequest<'a> {
     /// Channel to remove user from.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// User to remove from channel.
-    pub user: &'a str,
+    pub user: Cow<'a, str>,
+}
+
+impl<'a> KickRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, user: impl Into<Cow<'a, str>>) -> Self {
+        KickRequest {
+            channel: channel.into(),
+            user: user.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> KickRequest<'static> {
+        KickRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            user: Cow::Owned(self.user.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct KickResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -1415,7 +1633,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.leave");
     client
@@ -1427,14 +1648,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LeaveRequest<'a> {
     /// Channel to leave
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> LeaveRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        LeaveRequest {
+            channel: channel.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> LeaveRequest<'static> {
+        LeaveRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LeaveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -1592,7 +1831,7 @@ pub fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let limit = request.limit.map(|limit| limit.to_string());
     let params = vec![
         Some(("token", token)),
         request.exclude_archived.map(|exclude_archived| {
@@ -1601,6 +1840,8 @@ where
         request.exclude_members.map(|exclude_members| {
             ("exclude_members", if exclude_members { "1" } else { "0" })
         }),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.list");
@@ -1613,20 +1854,89 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
-pub struct ListRequest {
+/// Like [`list`], but returns an iterator over every item, requesting further pages as
+/// needed.
+///
+/// [`list`]: fn.list.html
+pub fn list_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &ListRequest<'a>,
+) -> ::paging::Items<'a, ::Channel, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut request = request.clone();
+    ::paging::Items::new(move |next| {
+        if let Some(next) = next {
+            request.cursor = next.cursor().map(|cursor| Cow::Owned(cursor.to_owned()));
+        }
+        list(client, token, &request).map(|response| {
+            let next = response.response_metadata.as_ref().and_then(::paging::next_cursor);
+            ::paging::Page { items: response.channels.unwrap_or_default(), next }
+        })
+    })
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct ListRequest<'a> {
     /// Exclude archived channels from the list
     pub exclude_archived: Option<bool>,
     /// Exclude the members collection from each channel
     pub exclude_members: Option<bool>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection. See pagination for more detail.
+    pub cursor: Option<Cow<'a, str>>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> ListRequest<'a> {
+    /// Exclude archived channels from the list
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.exclude_archived = Some(exclude_archived);
+        self
+    }
+
+    /// Exclude the members collection from each channel
+    pub fn exclude_members(mut self, exclude_members: bool) -> Self {
+        self.exclude_members = Some(exclude_members);
+        self
+    }
+
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection. See pagination for more detail.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> ListRequest<'static> {
+        ListRequest {
+            exclude_archived: self.exclude_archived,
+            exclude_members: self.exclude_members,
+            cursor: self.cursor.map(|cursor| Cow::Owned(cursor.into_owned())),
+            limit: self.limit,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channels: Option<Vec<::Channel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_metadata: Option<::ResponseMetadata>,
 }
 
 
@@ -1663,6 +1973,8 @@ pub enum ListError<E: Error> {
     TeamAddedToOrg,
     /// The method was called via a POST request, but the POST data was either missing or truncated.
     RequestTimeout,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
     /// The response was not parseable as the expected object
     MalformedResponse(serde_json::error::Error),
     /// The response returned an error that was unknown to the library
@@ -1685,6 +1997,7 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
             "missing_post_type" => ListError::MissingPostType,
             "team_added_to_org" => ListError::TeamAddedToOrg,
             "request_timeout" => ListError::RequestTimeout,
+            "invalid_cursor" => ListError::InvalidCursor,
             _ => ListError::Unknown(s.to_owned()),
         }
     }
@@ -1728,6 +2041,9 @@ impl<E: Error> Error for ListError<E> {
             ListError::RequestTimeout => {
                 "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
             }
+            ListError::InvalidCursor => {
+                "invalid_cursor: Value passed for cursor was not valid or is no longer valid."
+            }
             ListError::MalformedResponse(ref e) => e.description(),
             ListError::Unknown(ref s) => s,
             ListError::Client(ref inner) => inner.description(),
@@ -1758,8 +2074,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("ts", request.ts)),
+        Some(("channel", &request.channel[..])),
+        Some(("ts", &request.ts[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.mark");
@@ -1772,16 +2088,36 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MarkRequest<'a> {
     /// Channel to set reading cursor in.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Timestamp of the most recently seen message.
-    pub ts: &'a str,
+    pub ts: Cow<'a, str>,
+}
+
+impl<'a> MarkRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, ts: impl Into<Cow<'a, str>>) -> Self {
+        MarkRequest {
+            channel: channel.into(),
+            ts: ts.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> MarkRequest<'static> {
+        MarkRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            ts: Cow::Owned(self.ts.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MarkResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -1932,11 +2268,9 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("name", request.name)),
-        request.validate.map(|validate| {
-            ("validate", if validate { "1" } else { "0" })
-        }),
+        Some(("channel", &request.channel[..])),
+        Some(("name", &request.name[..])),
+        request.validate.map(|validate| ("validate", if validate { "1" } else { "0" })),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.rename");
@@ -1949,29 +2283,62 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RenameRequest<'a> {
     /// Channel to rename
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// New name for channel.
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
     pub validate: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> RenameRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, name: impl Into<Cow<'a, str>>) -> Self {
+        RenameRequest {
+            channel: channel.into(),
+            name: name.into(),
+            validate: None,
+        }
+    }
+
+    /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> RenameRequest<'static> {
+        RenameRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            name: Cow::Owned(self.name.into_owned()),
+            validate: self.validate,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<RenameResponseChannel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameResponseChannel {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_channel: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
@@ -2160,8 +2527,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("thread_ts", request.thread_ts)),
+        Some(("channel", &request.channel[..])),
+        Some(("thread_ts", &request.thread_ts[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.replies");
@@ -2176,20 +2543,42 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RepliesRequest<'a> {
     /// Channel to fetch thread from
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Unique identifier of a thread's parent message
-    pub thread_ts: &'a str,
+    pub thread_ts: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> RepliesRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, thread_ts: impl Into<Cow<'a, str>>) -> Self {
+        RepliesRequest {
+            channel: channel.into(),
+            thread_ts: thread_ts.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> RepliesRequest<'static> {
+        RepliesRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            thread_ts: Cow::Owned(self.thread_ts.into_owned()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RepliesResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thread_info: Option<::ThreadInfo>,
 }
 
@@ -2334,8 +2723,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("purpose", request.purpose)),
+        Some(("channel", &request.channel[..])),
+        Some(("purpose", &request.purpose[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.setPurpose");
@@ -2349,19 +2738,40 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SetPurposeRequest<'a> {
     /// Channel to set the purpose of
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// The new purpose
-    pub purpose: &'a str,
+    pub purpose: Cow<'a, str>,
+}
+
+impl<'a> SetPurposeRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, purpose: impl Into<Cow<'a, str>>) -> Self {
+        SetPurposeRequest {
+            channel: channel.into(),
+            purpose: purpose.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> SetPurposeRequest<'static> {
+        SetPurposeRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            purpose: Cow::Owned(self.purpose.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub purpose: Option<String>,
 }
 
@@ -2520,8 +2930,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("topic", request.topic)),
+        Some(("channel", &request.channel[..])),
+        Some(("topic", &request.topic[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.setTopic");
@@ -2536,19 +2946,40 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SetTopicRequest<'a> {
     /// Channel to set the topic of
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// The new topic
-    pub topic: &'a str,
+    pub topic: Cow<'a, str>,
+}
+
+impl<'a> SetTopicRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, topic: impl Into<Cow<'a, str>>) -> Self {
+        SetTopicRequest {
+            channel: channel.into(),
+            topic: topic.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> SetTopicRequest<'static> {
+        SetTopicRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            topic: Cow::Owned(self.topic.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<String>,
 }
 
@@ -2705,7 +3136,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.unarchive");
     client
@@ -2719,14 +3153,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct UnarchiveRequest<'a> {
     /// Channel to unarchive
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> UnarchiveRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        UnarchiveRequest {
+            channel: channel.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> UnarchiveRequest<'static> {
+        UnarchiveRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UnarchiveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -1,6 +1,8 @@
 //! Post chat messages to Slack.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -26,11 +28,9 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("ts", request.ts)),
-        Some(("channel", request.channel)),
-        request.as_user.map(|as_user| {
-            ("as_user", if as_user { "1" } else { "0" })
-        }),
+        Some(("ts", &request.ts[..])),
+        Some(("channel", &request.channel[..])),
+        request.as_user.map(|as_user| ("as_user", if as_user { "1" } else { "0" })),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("chat.delete");
@@ -43,22 +43,52 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DeleteRequest<'a> {
     /// Timestamp of the message to be deleted.
-    pub ts: &'a str,
+    pub ts: Cow<'a, str>,
     /// Channel containing the message to be deleted.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Pass true to delete the message as the authed user. Bot users in this context are considered authed users.
     pub as_user: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> DeleteRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(ts: impl Into<Cow<'a, str>>, channel: impl Into<Cow<'a, str>>) -> Self {
+        DeleteRequest {
+            ts: ts.into(),
+            channel: channel.into(),
+            as_user: None,
+        }
+    }
+
+    /// Pass true to delete the message as the authed user. Bot users in this context are considered authed users.
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.as_user = Some(as_user);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> DeleteRequest<'static> {
+        DeleteRequest {
+            ts: Cow::Owned(self.ts.into_owned()),
+            channel: Cow::Owned(self.channel.into_owned()),
+            as_user: self.as_user,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
 }
 
@@ -215,8 +245,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("text", request.text)),
+        Some(("channel", &request.channel[..])),
+        Some(("text", &request.text[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("chat.meMessage");
@@ -231,20 +261,42 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MeMessageRequest<'a> {
     /// Channel to send message to. Can be a public channel, private group or IM channel. Can be an encoded ID, or a name.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Text of the message to send.
-    pub text: &'a str,
+    pub text: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> MeMessageRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, text: impl Into<Cow<'a, str>>) -> Self {
+        MeMessageRequest {
+            channel: channel.into(),
+            text: text.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> MeMessageRequest<'static> {
+        MeMessageRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            text: Cow::Owned(self.text.into_owned()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MeMessageResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
 }
 
@@ -407,33 +459,26 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("text", request.text)),
-        request.parse.map(|parse| ("parse", parse)),
-        request.link_names.map(|link_names| {
-            ("link_names", if link_names { "1" } else { "0" })
-        }),
-        request.attachments.map(|attachments| {
-            ("attachments", attachments)
-        }),
+        Some(("channel", &request.channel[..])),
+        Some(("text", &request.text[..])),
+        request.parse.as_ref().map(|parse| ("parse", &parse[..])),
+        request.link_names.map(|link_names| ("link_names", if link_names { "1" } else { "0" })),
+        request.attachments.as_ref().map(|attachments| ("attachments", &attachments[..])),
         request.unfurl_links.map(|unfurl_links| {
             ("unfurl_links", if unfurl_links { "1" } else { "0" })
         }),
         request.unfurl_media.map(|unfurl_media| {
             ("unfurl_media", if unfurl_media { "1" } else { "0" })
         }),
-        request.username.map(|username| ("username", username)),
-        request.as_user.map(|as_user| {
-            ("as_user", if as_user { "1" } else { "0" })
-        }),
-        request.icon_url.map(|icon_url| ("icon_url", icon_url)),
-        request.icon_emoji.map(
-            |icon_emoji| ("icon_emoji", icon_emoji)
-        ),
-        request.thread_ts.map(|thread_ts| ("thread_ts", thread_ts)),
+        request.username.as_ref().map(|username| ("username", &username[..])),
+        request.as_user.map(|as_user| ("as_user", if as_user { "1" } else { "0" })),
+        request.icon_url.as_ref().map(|icon_url| ("icon_url", &icon_url[..])),
+        request.icon_emoji.as_ref().map(|icon_emoji| ("icon_emoji", &icon_emoji[..])),
+        request.thread_ts.as_ref().map(|thread_ts| ("thread_ts", &thread_ts[..])),
         request.reply_broadcast.map(|reply_broadcast| {
             ("reply_broadcast", if reply_broadcast { "1" } else { "0" })
         }),
+        request.blocks.as_ref().map(|blocks| ("blocks", &blocks[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("chat.postMessage");
@@ -447,43 +492,164 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PostMessageRequest<'a> {
     /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name. See below for more details.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Text of the message to send. See below for an explanation of formatting. This field is usually required, unless you're providing only attachments instead.
-    pub text: &'a str,
+    pub text: Cow<'a, str>,
     /// Change how messages are treated. Defaults to none. See below.
-    pub parse: Option<&'a str>,
+    pub parse: Option<Cow<'a, str>>,
     /// Find and link channel names and usernames.
     pub link_names: Option<bool>,
     /// Structured message attachments.
-    pub attachments: Option<&'a str>,
+    pub attachments: Option<Cow<'a, str>>,
     /// Pass true to enable unfurling of primarily text-based content.
     pub unfurl_links: Option<bool>,
     /// Pass false to disable unfurling of media content.
     pub unfurl_media: Option<bool>,
     /// Set your bot's user name. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
-    pub username: Option<&'a str>,
+    pub username: Option<Cow<'a, str>>,
     /// Pass true to post the message as the authed user, instead of as a bot. Defaults to false. See authorship below.
     pub as_user: Option<bool>,
     /// URL to an image to use as the icon for this message. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
-    pub icon_url: Option<&'a str>,
+    pub icon_url: Option<Cow<'a, str>>,
     /// Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
-    pub icon_emoji: Option<&'a str>,
+    pub icon_emoji: Option<Cow<'a, str>>,
     /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
-    pub thread_ts: Option<&'a str>,
+    pub thread_ts: Option<Cow<'a, str>>,
     /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
     pub reply_broadcast: Option<bool>,
+    /// A JSON-based array of structured blocks, presented as a URL-encoded string.
+    pub blocks: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> PostMessageRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, text: impl Into<Cow<'a, str>>) -> Self {
+        PostMessageRequest {
+            channel: channel.into(),
+            text: text.into(),
+            parse: None,
+            link_names: None,
+            attachments: None,
+            unfurl_links: None,
+            unfurl_media: None,
+            username: None,
+            as_user: None,
+            icon_url: None,
+            icon_emoji: None,
+            thread_ts: None,
+            reply_broadcast: None,
+            blocks: None,
+        }
+    }
+
+    /// Change how messages are treated. Defaults to none. See below.
+    pub fn parse(mut self, parse: impl Into<Cow<'a, str>>) -> Self {
+        self.parse = Some(parse.into());
+        self
+    }
+
+    /// Find and link channel names and usernames.
+    pub fn link_names(mut self, link_names: bool) -> Self {
+        self.link_names = Some(link_names);
+        self
+    }
+
+    /// Structured message attachments.
+    pub fn attachments(mut self, attachments: impl Into<Cow<'a, str>>) -> Self {
+        self.attachments = Some(attachments.into());
+        self
+    }
+
+    /// Pass true to enable unfurling of primarily text-based content.
+    pub fn unfurl_links(mut self, unfurl_links: bool) -> Self {
+        self.unfurl_links = Some(unfurl_links);
+        self
+    }
+
+    /// Pass false to disable unfurling of media content.
+    pub fn unfurl_media(mut self, unfurl_media: bool) -> Self {
+        self.unfurl_media = Some(unfurl_media);
+        self
+    }
+
+    /// Set your bot's user name. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub fn username(mut self, username: impl Into<Cow<'a, str>>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Pass true to post the message as the authed user, instead of as a bot. Defaults to false. See authorship below.
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.as_user = Some(as_user);
+        self
+    }
+
+    /// URL to an image to use as the icon for this message. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub fn icon_url(mut self, icon_url: impl Into<Cow<'a, str>>) -> Self {
+        self.icon_url = Some(icon_url.into());
+        self
+    }
+
+    /// Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub fn icon_emoji(mut self, icon_emoji: impl Into<Cow<'a, str>>) -> Self {
+        self.icon_emoji = Some(icon_emoji.into());
+        self
+    }
+
+    /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
+    pub fn thread_ts(mut self, thread_ts: impl Into<Cow<'a, str>>) -> Self {
+        self.thread_ts = Some(thread_ts.into());
+        self
+    }
+
+    /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
+    pub fn reply_broadcast(mut self, reply_broadcast: bool) -> Self {
+        self.reply_broadcast = Some(reply_broadcast);
+        self
+    }
+
+    /// A JSON-based array of structured blocks, presented as a URL-encoded string.
+    pub fn blocks(mut self, blocks: impl Into<Cow<'a, str>>) -> Self {
+        self.blocks = Some(blocks.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> PostMessageRequest<'static> {
+        PostMessageRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            text: Cow::Owned(self.text.into_owned()),
+            parse: self.parse.map(|parse| Cow::Owned(parse.into_owned())),
+            link_names: self.link_names,
+            attachments: self.attachments.map(|attachments| Cow::Owned(attachments.into_owned())),
+            unfurl_links: self.unfurl_links,
+            unfurl_media: self.unfurl_media,
+            username: self.username.map(|username| Cow::Owned(username.into_owned())),
+            as_user: self.as_user,
+            icon_url: self.icon_url.map(|icon_url| Cow::Owned(icon_url.into_owned())),
+            icon_emoji: self.icon_emoji.map(|icon_emoji| Cow::Owned(icon_emoji.into_owned())),
+            thread_ts: self.thread_ts.map(|thread_ts| Cow::Owned(thread_ts.into_owned())),
+            reply_broadcast: self.reply_broadcast,
+            blocks: self.blocks.map(|blocks| Cow::Owned(blocks.into_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<::Message>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
 }
 
@@ -535,6 +701,10 @@ pub enum PostMessageError<E: Error> {
     TeamAddedToOrg,
     /// The method was called via a POST request, but the POST data was either missing or truncated.
     RequestTimeout,
+    /// Blocks submitted with this message are not valid.
+    InvalidBlocks,
+    /// The blocks is not a valid JSON object or doesn't match the Block Kit syntax.
+    InvalidBlocksFormat,
     /// The response was not parseable as the expected object
     MalformedResponse(serde_json::error::Error),
     /// The response returned an error that was unknown to the library
@@ -564,6 +734,8 @@ impl<'a, E: Error> From<&'a str> for PostMessageError<E> {
             "missing_post_type" => PostMessageError::MissingPostType,
             "team_added_to_org" => PostMessageError::TeamAddedToOrg,
             "request_timeout" => PostMessageError::RequestTimeout,
+            "invalid_blocks" => PostMessageError::InvalidBlocks,
+            "invalid_blocks_format" => PostMessageError::InvalidBlocksFormat,
             _ => PostMessageError::Unknown(s.to_owned()),
         }
     }
@@ -622,6 +794,12 @@ impl<E: Error> Error for PostMessageError<E> {
             PostMessageError::RequestTimeout => {
                 "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
             }
+            PostMessageError::InvalidBlocks => {
+                "invalid_blocks: Blocks submitted with this message are not valid."
+            }
+            PostMessageError::InvalidBlocksFormat => {
+                "invalid_blocks_format: The blocks is not a valid JSON object or doesn't match the Block Kit syntax."
+            }
             PostMessageError::MalformedResponse(ref e) => e.description(),
             PostMessageError::Unknown(ref s) => s,
             PostMessageError::Client(ref inner) => inner.description(),
@@ -652,14 +830,11 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("ts", request.ts)),
-        Some(("unfurls", request.unfurls)),
+        Some(("channel", &request.channel[..])),
+        Some(("ts", &request.ts[..])),
+        Some(("unfurls", &request.unfurls[..])),
         request.user_auth_required.map(|user_auth_required| {
-            (
-                "user_auth_required",
-                if user_auth_required { "1" } else { "0" },
-            )
+            ("user_auth_required", if user_auth_required { "1" } else { "0" },)
         }),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
@@ -673,20 +848,57 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct UnfurlRequest<'a> {
     /// Channel ID of the message
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Timestamp of the message to add unfurl behavior to
-    pub ts: &'a str,
+    pub ts: Cow<'a, str>,
     /// JSON mapping a set of URLs from the message to their unfurl attachments
-    pub unfurls: &'a str,
+    pub unfurls: Cow<'a, str>,
     /// Set to true or 1 to indicate the user must install your Slack app to trigger unfurls for this domain
     pub user_auth_required: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> UnfurlRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(
+        channel: impl Into<Cow<'a,
+        str>>,
+        ts: impl Into<Cow<'a,
+        str>>,
+        unfurls: impl Into<Cow<'a,
+        str>>,
+    ) -> Self {
+        UnfurlRequest {
+            channel: channel.into(),
+            ts: ts.into(),
+            unfurls: unfurls.into(),
+            user_auth_required: None,
+        }
+    }
+
+    /// Set to true or 1 to indicate the user must install your Slack app to trigger unfurls for this domain
+    pub fn user_auth_required(mut self, user_auth_required: bool) -> Self {
+        self.user_auth_required = Some(user_auth_required);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> UnfurlRequest<'static> {
+        UnfurlRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            ts: Cow::Owned(self.ts.into_owned()),
+            unfurls: Cow::Owned(self.unfurls.into_owned()),
+            user_auth_required: self.user_auth_required,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UnfurlResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -825,19 +1037,14 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("ts", request.ts)),
-        Some(("channel", request.channel)),
-        Some(("text", request.text)),
-        request.attachments.map(|attachments| {
-            ("attachments", attachments)
-        }),
-        request.parse.map(|parse| ("parse", parse)),
-        request.link_names.map(|link_names| {
-            ("link_names", if link_names { "1" } else { "0" })
-        }),
-        request.as_user.map(|as_user| {
-            ("as_user", if as_user { "1" } else { "0" })
-        }),
+        Some(("ts", &request.ts[..])),
+        Some(("channel", &request.channel[..])),
+        Some(("text", &request.text[..])),
+        request.attachments.as_ref().map(|attachments| ("attachments", &attachments[..])),
+        request.parse.as_ref().map(|parse| ("parse", &parse[..])),
+        request.link_names.map(|link_names| ("link_names", if link_names { "1" } else { "0" })),
+        request.as_user.map(|as_user| ("as_user", if as_user { "1" } else { "0" })),
+        request.blocks.as_ref().map(|blocks| ("blocks", &blocks[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("chat.update");
@@ -850,31 +1057,105 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct UpdateRequest<'a> {
     /// Timestamp of the message to be updated.
-    pub ts: &'a str,
+    pub ts: Cow<'a, str>,
     /// Channel containing the message to be updated.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// New text for the message, using the default formatting rules.
-    pub text: &'a str,
+    pub text: Cow<'a, str>,
     /// Structured message attachments.
-    pub attachments: Option<&'a str>,
+    pub attachments: Option<Cow<'a, str>>,
     /// Change how messages are treated. Defaults to client, unlike chat.postMessage. See below.
-    pub parse: Option<&'a str>,
+    pub parse: Option<Cow<'a, str>>,
     /// Find and link channel names and usernames. Defaults to none. This parameter should be used in conjunction with parse. To set link_names to 1, specify a parse mode of full.
     pub link_names: Option<bool>,
     /// Pass true to update the message as the authed user. Bot users in this context are considered authed users.
     pub as_user: Option<bool>,
+    /// A JSON-based array of structured blocks, presented as a URL-encoded string.
+    pub blocks: Option<Cow<'a, str>>,
+}
+
+impl<'a> UpdateRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(
+        ts: impl Into<Cow<'a,
+        str>>,
+        channel: impl Into<Cow<'a,
+        str>>,
+        text: impl Into<Cow<'a,
+        str>>,
+    ) -> Self {
+        UpdateRequest {
+            ts: ts.into(),
+            channel: channel.into(),
+            text: text.into(),
+            attachments: None,
+            parse: None,
+            link_names: None,
+            as_user: None,
+            blocks: None,
+        }
+    }
+
+    /// Structured message attachments.
+    pub fn attachments(mut self, attachments: impl Into<Cow<'a, str>>) -> Self {
+        self.attachments = Some(attachments.into());
+        self
+    }
+
+    /// Change how messages are treated. Defaults to client, unlike chat.postMessage. See below.
+    pub fn parse(mut self, parse: impl Into<Cow<'a, str>>) -> Self {
+        self.parse = Some(parse.into());
+        self
+    }
+
+    /// Find and link channel names and usernames. Defaults to none. This parameter should be used in conjunction with parse. To set link_names to 1, specify a parse mode of full.
+    pub fn link_names(mut self, link_names: bool) -> Self {
+        self.link_names = Some(link_names);
+        self
+    }
+
+    /// Pass true to update the message as the authed user. Bot users in this context are considered authed users.
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.as_user = Some(as_user);
+        self
+    }
+
+    /// A JSON-based array of structured blocks, presented as a URL-encoded string.
+    pub fn blocks(mut self, blocks: impl Into<Cow<'a, str>>) -> Self {
+        self.blocks = Some(blocks.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> UpdateRequest<'static> {
+        UpdateRequest {
+            ts: Cow::Owned(self.ts.into_owned()),
+            channel: Cow::Owned(self.channel.into_owned()),
+            text: Cow::Owned(self.text.into_owned()),
+            attachments: self.attachments.map(|attachments| Cow::Owned(attachments.into_owned())),
+            parse: self.parse.map(|parse| Cow::Owned(parse.into_owned())),
+            link_names: self.link_names,
+            as_user: self.as_user,
+            blocks: self.blocks.map(|blocks| Cow::Owned(blocks.into_owned())),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
 }
 
@@ -926,6 +1207,10 @@ pub enum UpdateError<E: Error> {
     TeamAddedToOrg,
     /// The method was called via a POST request, but the POST data was either missing or truncated.
     RequestTimeout,
+    /// Blocks submitted with this message are not valid.
+    InvalidBlocks,
+    /// The blocks is not a valid JSON object or doesn't match the Block Kit syntax.
+    InvalidBlocksFormat,
     /// The response was not parseable as the expected object
     MalformedResponse(serde_json::error::Error),
     /// The response returned an error that was unknown to the library
@@ -955,6 +1240,8 @@ impl<'a, E: Error> From<&'a str> for UpdateError<E> {
             "missing_post_type" => UpdateError::MissingPostType,
             "team_added_to_org" => UpdateError::TeamAddedToOrg,
             "request_timeout" => UpdateError::RequestTimeout,
+            "invalid_blocks" => UpdateError::InvalidBlocks,
+            "invalid_blocks_format" => UpdateError::InvalidBlocksFormat,
             _ => UpdateError::Unknown(s.to_owned()),
         }
     }
@@ -1015,6 +1302,12 @@ impl<E: Error> Error for UpdateError<E> {
             UpdateError::RequestTimeout => {
                 "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
             }
+            UpdateError::InvalidBlocks => {
+                "invalid_blocks: Blocks submitted with this message are not valid."
+            }
+            UpdateError::InvalidBlocksFormat => {
+                "invalid_blocks_format: The blocks is not a valid JSON object or doesn't match the Block Kit syntax."
+            }
             UpdateError::MalformedResponse(ref e) => e.description(),
             UpdateError::Unknown(ref s) => s,
             UpdateError::Client(ref inner) => inner.description(),
@@ -1029,3 +1322,197 @@ impl<E: Error> Error for UpdateError<E> {
         }
     }
 }
+
+/// Retrieve a permalink URL for a specific extant message
+///
+/// Wraps https://api.slack.com/methods/chat.getPermalink
+
+pub fn get_permalink<R>(
+    client: &R,
+    token: &str,
+    request: &GetPermalinkRequest,
+) -> Result<GetPermalinkResponse, GetPermalinkError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        Some(("message_ts", &request.message_ts[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("chat.getPermalink");
+    client
+        .send(&url, &params[..])
+        .map_err(GetPermalinkError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<GetPermalinkResponse>(&result).map_err(GetPermalinkError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GetPermalinkRequest<'a> {
+    /// The ID of the conversation or channel containing the message
+    pub channel: Cow<'a, str>,
+    /// A message's ts value, uniquely identifying it within a channel
+    pub message_ts: Cow<'a, str>,
+}
+
+impl<'a> GetPermalinkRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, message_ts: impl Into<Cow<'a, str>>) -> Self {
+        GetPermalinkRequest {
+            channel: channel.into(),
+            message_ts: message_ts.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> GetPermalinkRequest<'static> {
+        GetPermalinkRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            message_ts: Cow::Owned(self.message_ts.into_owned()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GetPermalinkResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permalink: Option<String>,
+}
+
+
+impl<E: Error> Into<Result<GetPermalinkResponse, GetPermalinkError<E>>> for GetPermalinkResponse {
+    fn into(self) -> Result<GetPermalinkResponse, GetPermalinkError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum GetPermalinkError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// No message exists with the requested timestamp.
+    MessageNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for GetPermalinkError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => GetPermalinkError::ChannelNotFound,
+            "message_not_found" => GetPermalinkError::MessageNotFound,
+            "not_authed" => GetPermalinkError::NotAuthed,
+            "invalid_auth" => GetPermalinkError::InvalidAuth,
+            "account_inactive" => GetPermalinkError::AccountInactive,
+            "invalid_arg_name" => GetPermalinkError::InvalidArgName,
+            "invalid_array_arg" => GetPermalinkError::InvalidArrayArg,
+            "invalid_charset" => GetPermalinkError::InvalidCharset,
+            "invalid_form_data" => GetPermalinkError::InvalidFormData,
+            "invalid_post_type" => GetPermalinkError::InvalidPostType,
+            "missing_post_type" => GetPermalinkError::MissingPostType,
+            "team_added_to_org" => GetPermalinkError::TeamAddedToOrg,
+            "request_timeout" => GetPermalinkError::RequestTimeout,
+            _ => GetPermalinkError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for GetPermalinkError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for GetPermalinkError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            GetPermalinkError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            GetPermalinkError::MessageNotFound => {
+                "message_not_found: No message exists with the requested timestamp."
+            }
+            GetPermalinkError::NotAuthed => "not_authed: No authentication token provided.",
+            GetPermalinkError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            GetPermalinkError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            GetPermalinkError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            GetPermalinkError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            GetPermalinkError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            GetPermalinkError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            GetPermalinkError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            GetPermalinkError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            GetPermalinkError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            GetPermalinkError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            GetPermalinkError::MalformedResponse(ref e) => e.description(),
+            GetPermalinkError::Unknown(ref s) => s,
+            GetPermalinkError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            GetPermalinkError::MalformedResponse(ref e) => Some(e),
+            GetPermalinkError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
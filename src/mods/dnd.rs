@@ -1,6 +1,8 @@
 //! Adjust and view Do Not Disturb settings for team members.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -30,8 +32,9 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EndDndResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -182,14 +185,19 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EndSnoozeResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dnd_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub next_dnd_end_ts: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub next_dnd_start_ts: Option<f32>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_enabled: Option<bool>,
 }
 
@@ -338,7 +346,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.user.map(|user| ("user", user)),
+        request.user.as_ref().map(|user| ("user", &user[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("dnd.info");
@@ -351,22 +359,45 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct InfoRequest<'a> {
     /// User to fetch status for (defaults to current user)
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// User to fetch status for (defaults to current user)
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> InfoRequest<'static> {
+        InfoRequest {
+            user: self.user.map(|user| Cow::Owned(user.into_owned())),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dnd_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub next_dnd_end_ts: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub next_dnd_start_ts: Option<f32>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_endtime: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_remaining: Option<f32>,
 }
 
@@ -518,19 +549,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SetSnoozeRequest {
     /// Number of minutes, from now, to snooze until.
     pub num_minutes: u32,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl SetSnoozeRequest {
+    /// Creates a request with all required parameters set.
+    pub fn new(num_minutes: u32) -> Self {
+        SetSnoozeRequest {
+            num_minutes,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetSnoozeResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_endtime: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_remaining: Option<f32>,
 }
 
@@ -679,7 +723,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.users.map(|users| ("users", users)),
+        request.users.as_ref().map(|users| ("users", &users[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("dnd.teamInfo");
@@ -694,17 +738,35 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct TeamInfoRequest<'a> {
     /// Comma-separated list of users to fetch Do Not Disturb status for
-    pub users: Option<&'a str>,
+    pub users: Option<Cow<'a, str>>,
+}
+
+impl<'a> TeamInfoRequest<'a> {
+    /// Comma-separated list of users to fetch Do Not Disturb status for
+    pub fn users(mut self, users: impl Into<Cow<'a, str>>) -> Self {
+        self.users = Some(users.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> TeamInfoRequest<'static> {
+        TeamInfoRequest {
+            users: self.users.map(|users| Cow::Owned(users.into_owned())),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TeamInfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub users: Option<HashMap<String, bool>>,
 }
 
@@ -1,6 +1,8 @@
 //! Get info on files uploaded to Slack, upload new files to Slack.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -24,7 +26,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("file", &request.file[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("files.delete");
     client
@@ -36,14 +41,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DeleteRequest<'a> {
     /// ID of file to delete.
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
+}
+
+impl<'a> DeleteRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(file: impl Into<Cow<'a, str>>) -> Self {
+        DeleteRequest {
+            file: file.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> DeleteRequest<'static> {
+        DeleteRequest {
+            file: Cow::Owned(self.file.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -195,7 +218,7 @@ where
     let page = request.page.map(|page| page.to_string());
     let params = vec![
         Some(("token", token)),
-        Some(("file", request.file)),
+        Some(("file", &request.file[..])),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
@@ -210,23 +233,60 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct InfoRequest<'a> {
     /// Specify a file by providing its ID.
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
     /// Number of items to return per page.
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> InfoRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(file: impl Into<Cow<'a, str>>) -> Self {
+        InfoRequest {
+            file: file.into(),
+            count: None,
+            page: None,
+        }
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> InfoRequest<'static> {
+        InfoRequest {
+            file: Cow::Owned(self.file.into_owned()),
+            count: self.count,
+            page: self.page,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub comments: Option<Vec<::FileComment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file: Option<::File>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub paging: Option<::Paging>,
 }
 
@@ -370,11 +430,11 @@ where
     let page = request.page.map(|page| page.to_string());
     let params = vec![
         Some(("token", token)),
-        request.user.map(|user| ("user", user)),
-        request.channel.map(|channel| ("channel", channel)),
+        request.user.as_ref().map(|user| ("user", &user[..])),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
         ts_from.as_ref().map(|ts_from| ("ts_from", &ts_from[..])),
         ts_to.as_ref().map(|ts_to| ("ts_to", &ts_to[..])),
-        request.types.map(|types| ("types", types)),
+        request.types.as_ref().map(|types| ("types", &types[..])),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
@@ -389,12 +449,36 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+/// Like [`list`], but returns an iterator over every item, requesting further pages as
+/// needed.
+///
+/// [`list`]: fn.list.html
+pub fn list_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &ListRequest<'a>,
+) -> ::paging::Items<'a, ::File, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut request = request.clone();
+    ::paging::Items::new(move |next| {
+        if let Some(next) = next {
+            request.page = next.page();
+        }
+        list(client, token, &request).map(|response| {
+            let next = response.paging.as_ref().and_then(::paging::next_page);
+            ::paging::Page { items: response.files.unwrap_or_default(), next }
+        })
+    })
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct ListRequest<'a> {
     /// Filter files created by a single user.
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
     /// Filter files appearing in a specific channel, indicated by its ID.
-    pub channel: Option<&'a str>,
+    pub channel: Option<Cow<'a, str>>,
     /// Filter files created after this timestamp (inclusive).
     pub ts_from: Option<u32>,
     /// Filter files created before this timestamp (inclusive).
@@ -412,19 +496,92 @@ pub struct ListRequest<'a> {
     ///
     ///
     /// You can pass multiple values in the types argument, like types=spaces,snippets.The default value is all, which does not filter the list.
-    pub types: Option<&'a str>,
+    pub types: Option<Cow<'a, str>>,
     /// Number of items to return per page.
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> ListRequest<'a> {
+    /// Filter files created by a single user.
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Filter files appearing in a specific channel, indicated by its ID.
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.channel = Some(channel.into());
+        self
+    }
+
+    /// Filter files created after this timestamp (inclusive).
+    pub fn ts_from(mut self, ts_from: u32) -> Self {
+        self.ts_from = Some(ts_from);
+        self
+    }
+
+    /// Filter files created before this timestamp (inclusive).
+    pub fn ts_to(mut self, ts_to: u32) -> Self {
+        self.ts_to = Some(ts_to);
+        self
+    }
+
+    /// Filter files by type:
+    ///
+    ///
+    /// all - All files
+    /// spaces - Posts
+    /// snippets - Snippets
+    /// images - Image files
+    /// gdocs - Google docs
+    /// zips - Zip files
+    /// pdfs - PDF files
+    ///
+    ///
+    /// You can pass multiple values in the types argument, like types=spaces,snippets.The default value is all, which does not filter the list.
+    pub fn types(mut self, types: impl Into<Cow<'a, str>>) -> Self {
+        self.types = Some(types.into());
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> ListRequest<'static> {
+        ListRequest {
+            user: self.user.map(|user| Cow::Owned(user.into_owned())),
+            channel: self.channel.map(|channel| Cow::Owned(channel.into_owned())),
+            ts_from: self.ts_from,
+            ts_to: self.ts_to,
+            types: self.types.map(|types| Cow::Owned(types.into_owned())),
+            count: self.count,
+            page: self.page,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub files: Option<Vec<::File>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub paging: Option<::Paging>,
 }
 
@@ -567,7 +724,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("file", &request.file[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("files.revokePublicURL");
     client
@@ -580,15 +740,34 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RevokePublicURLRequest<'a> {
     /// File to revoke
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
+}
+
+impl<'a> RevokePublicURLRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(file: impl Into<Cow<'a, str>>) -> Self {
+        RevokePublicURLRequest {
+            file: file.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> RevokePublicURLRequest<'static> {
+        RevokePublicURLRequest {
+            file: Cow::Owned(self.file.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RevokePublicURLResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file: Option<::File>,
     #[serde(default)]
     ok: bool,
@@ -740,7 +919,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("file", &request.file[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("files.sharedPublicURL");
     client
@@ -753,15 +935,34 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SharedPublicURLRequest<'a> {
     /// File to share
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
+}
+
+impl<'a> SharedPublicURLRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(file: impl Into<Cow<'a, str>>) -> Self {
+        SharedPublicURLRequest {
+            file: file.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> SharedPublicURLRequest<'static> {
+        SharedPublicURLRequest {
+            file: Cow::Owned(self.file.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SharedPublicURLResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file: Option<::File>,
     #[serde(default)]
     ok: bool,
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,8 +27,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("file", request.file)),
-        Some(("comment", request.comment)),
+        Some(("file", &request.file[..])),
+        Some(("comment", &request.comment[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("files.comments.add");
@@ -39,17 +41,38 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AddRequest<'a> {
     /// File to add a comment to.
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
     /// Text of the comment to add.
-    pub comment: &'a str,
+    pub comment: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> AddRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(file: impl Into<Cow<'a, str>>, comment: impl Into<Cow<'a, str>>) -> Self {
+        AddRequest {
+            file: file.into(),
+            comment: comment.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> AddRequest<'static> {
+        AddRequest {
+            file: Cow::Owned(self.file.into_owned()),
+            comment: Cow::Owned(self.comment.into_owned()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<::FileComment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -196,8 +219,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("file", request.file)),
-        Some(("id", request.id)),
+        Some(("file", &request.file[..])),
+        Some(("id", &request.id[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("files.comments.delete");
@@ -210,16 +233,36 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DeleteRequest<'a> {
     /// File to delete a comment from.
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
     /// The comment to delete.
-    pub id: &'a str,
+    pub id: Cow<'a, str>,
+}
+
+impl<'a> DeleteRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(file: impl Into<Cow<'a, str>>, id: impl Into<Cow<'a, str>>) -> Self {
+        DeleteRequest {
+            file: file.into(),
+            id: id.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> DeleteRequest<'static> {
+        DeleteRequest {
+            file: Cow::Owned(self.file.into_owned()),
+            id: Cow::Owned(self.id.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -366,9 +409,9 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("file", request.file)),
-        Some(("id", request.id)),
-        Some(("comment", request.comment)),
+        Some(("file", &request.file[..])),
+        Some(("id", &request.id[..])),
+        Some(("comment", &request.comment[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("files.comments.edit");
@@ -381,19 +424,49 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EditRequest<'a> {
     /// File containing the comment to edit.
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
     /// The comment to edit.
-    pub id: &'a str,
+    pub id: Cow<'a, str>,
     /// Text of the comment to edit.
-    pub comment: &'a str,
+    pub comment: Cow<'a, str>,
+}
+
+impl<'a> EditRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(
+        file: impl Into<Cow<'a,
+        str>>,
+        id: impl Into<Cow<'a,
+        str>>,
+        comment: impl Into<Cow<'a,
+        str>>,
+    ) -> Self {
+        EditRequest {
+            file: file.into(),
+            id: id.into(),
+            comment: comment.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> EditRequest<'static> {
+        EditRequest {
+            file: Cow::Owned(self.file.into_owned()),
+            id: Cow::Owned(self.id.into_owned()),
+            comment: Cow::Owned(self.comment.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EditResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<::FileComment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
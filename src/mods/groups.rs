@@ -1,6 +1,8 @@
 //! Get info on your team's private channels.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -24,7 +26,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.archive");
     client
@@ -38,14 +43,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ArchiveRequest<'a> {
     /// Private channel to archive
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> ArchiveRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        ArchiveRequest {
+            channel: channel.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> ArchiveRequest<'static> {
+        ArchiveRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ArchiveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -210,7 +233,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.close");
     client
@@ -222,14 +248,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CloseRequest<'a> {
     /// Private channel to close.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> CloseRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        CloseRequest {
+            channel: channel.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> CloseRequest<'static> {
+        CloseRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CloseResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -370,10 +414,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("name", request.name)),
-        request.validate.map(|validate| {
-            ("validate", if validate { "1" } else { "0" })
-        }),
+        Some(("name", &request.name[..])),
+        request.validate.map(|validate| ("validate", if validate { "1" } else { "0" })),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.create");
@@ -386,17 +428,44 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CreateRequest<'a> {
     /// Name of private channel to create
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
     pub validate: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> CreateRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        CreateRequest {
+            name: name.into(),
+            validate: None,
+        }
+    }
+
+    /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> CreateRequest<'static> {
+        CreateRequest {
+            name: Cow::Owned(self.name.into_owned()),
+            validate: self.validate,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub group: Option<::Group>,
     #[serde(default)]
     ok: bool,
@@ -581,7 +650,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.createChild");
     client
@@ -594,15 +666,34 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CreateChildRequest<'a> {
     /// Private channel to clone and archive.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> CreateChildRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        CreateChildRequest {
+            channel: channel.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> CreateChildRequest<'static> {
+        CreateChildRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateChildResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub group: Option<::Group>,
     #[serde(default)]
     ok: bool,
@@ -767,16 +858,12 @@ where
     let count = request.count.map(|count| count.to_string());
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        request.latest.map(|latest| ("latest", latest)),
-        request.oldest.map(|oldest| ("oldest", oldest)),
-        request.inclusive.map(|inclusive| {
-            ("inclusive", if inclusive { "1" } else { "0" })
-        }),
+        Some(("channel", &request.channel[..])),
+        request.latest.as_ref().map(|latest| ("latest", &latest[..])),
+        request.oldest.as_ref().map(|oldest| ("oldest", &oldest[..])),
+        request.inclusive.map(|inclusive| ("inclusive", if inclusive { "1" } else { "0" })),
         count.as_ref().map(|count| ("count", &count[..])),
-        request.unreads.map(|unreads| {
-            ("unreads", if unreads { "1" } else { "0" })
-        }),
+        request.unreads.map(|unreads| ("unreads", if unreads { "1" } else { "0" })),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.history");
@@ -791,14 +878,39 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+/// Like [`history`], but returns an iterator over every item, requesting further pages as
+/// needed.
+///
+/// [`history`]: fn.history.html
+pub fn history_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &HistoryRequest<'a>,
+) -> ::paging::Items<'a, ::Message, HistoryError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut request = request.clone();
+    ::paging::Items::new(move |next| {
+        if let Some(next) = next {
+            request.latest = next.latest().map(|latest| Cow::Owned(latest.to_owned()));
+            request.inclusive = None;
+        }
+        history(client, token, &request).map(|response| {
+            let next = ::paging::next_window(response.has_more, response.messages.as_deref());
+            ::paging::Page { items: response.messages.unwrap_or_default(), next }
+        })
+    })
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct HistoryRequest<'a> {
     /// Private channel to fetch history for.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// End of time range of messages to include in results.
-    pub latest: Option<&'a str>,
+    pub latest: Option<Cow<'a, str>>,
     /// Start of time range of messages to include in results.
-    pub oldest: Option<&'a str>,
+    pub oldest: Option<Cow<'a, str>>,
     /// Include messages with latest or oldest timestamp in results.
     pub inclusive: Option<bool>,
     /// Number of messages to return, between 1 and 1000.
@@ -807,11 +919,72 @@ pub struct HistoryRequest<'a> {
     pub unreads: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> HistoryRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        HistoryRequest {
+            channel: channel.into(),
+            latest: None,
+            oldest: None,
+            inclusive: None,
+            count: None,
+            unreads: None,
+        }
+    }
+
+    /// End of time range of messages to include in results.
+    pub fn latest(mut self, latest: impl Into<Cow<'a, str>>) -> Self {
+        self.latest = Some(latest.into());
+        self
+    }
+
+    /// Start of time range of messages to include in results.
+    pub fn oldest(mut self, oldest: impl Into<Cow<'a, str>>) -> Self {
+        self.oldest = Some(oldest.into());
+        self
+    }
+
+    /// Include messages with latest or oldest timestamp in results.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    /// Number of messages to return, between 1 and 1000.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Include unread_count_display in the output?
+    pub fn unreads(mut self, unreads: bool) -> Self {
+        self.unreads = Some(unreads);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> HistoryRequest<'static> {
+        HistoryRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            latest: self.latest.map(|latest| Cow::Owned(latest.into_owned())),
+            oldest: self.oldest.map(|oldest| Cow::Owned(oldest.into_owned())),
+            inclusive: self.inclusive,
+            count: self.count,
+            unreads: self.unreads,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub has_more: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub latest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
@@ -962,7 +1135,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.info");
     client
@@ -974,15 +1150,34 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct InfoRequest<'a> {
     /// Private channel to get info on
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        InfoRequest {
+            channel: channel.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> InfoRequest<'static> {
+        InfoRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub group: Option<::Group>,
     #[serde(default)]
     ok: bool,
@@ -1123,8 +1318,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("user", request.user)),
+        Some(("channel", &request.channel[..])),
+        Some(("user", &request.user[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.invite");
@@ -1137,17 +1332,38 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct InviteRequest<'a> {
     /// Private channel to invite user to.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// User to invite.
-    pub user: &'a str,
+    pub user: Cow<'a, str>,
+}
+
+impl<'a> InviteRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, user: impl Into<Cow<'a, str>>) -> Self {
+        InviteRequest {
+            channel: channel.into(),
+            user: user.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> InviteRequest<'static> {
+        InviteRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            user: Cow::Owned(self.user.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub group: Option<::Group>,
     #[serde(default)]
     ok: bool,
@@ -1322,8 +1538,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("user", request.user)),
+        Some(("channel", &request.channel[..])),
+        Some(("user", &request.user[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.kick");
@@ -1336,16 +1552,36 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct KickRequest<'a> {
     /// Private channel to remove user from.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// User to remove from private channel.
-    pub user: &'a str,
+    pub user: Cow<'a, str>,
+}
+
+impl<'a> KickRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, user: impl Into<Cow<'a, str>>) -> Self {
+        KickRequest {
+            channel: channel.into(),
+            user: user.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> KickRequest<'static> {
+        KickRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            user: Cow::Owned(self.user.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct KickResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -1512,7 +1748,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.leave");
     client
@@ -1524,14 +1763,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LeaveRequest<'a> {
     /// Private channel to leave
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> LeaveRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        LeaveRequest {
+            channel: channel.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> LeaveRequest<'static> {
+        LeaveRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LeaveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -1701,15 +1958,25 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct ListRequest {
     /// Don't return archived private channels.
     pub exclude_archived: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl ListRequest {
+    /// Don't return archived private channels.
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.exclude_archived = Some(exclude_archived);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub groups: Option<Vec<::Group>>,
     #[serde(default)]
     ok: bool,
@@ -1844,8 +2111,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("ts", request.ts)),
+        Some(("channel", &request.channel[..])),
+        Some(("ts", &request.ts[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.mark");
@@ -1858,16 +2125,36 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MarkRequest<'a> {
     /// Private channel to set reading cursor in.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Timestamp of the most recently seen message.
-    pub ts: &'a str,
+    pub ts: Cow<'a, str>,
+}
+
+impl<'a> MarkRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, ts: impl Into<Cow<'a, str>>) -> Self {
+        MarkRequest {
+            channel: channel.into(),
+            ts: ts.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> MarkRequest<'static> {
+        MarkRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            ts: Cow::Owned(self.ts.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MarkResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -2012,7 +2299,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.open");
     client
@@ -2024,14 +2314,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OpenRequest<'a> {
     /// Private channel to open.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> OpenRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        OpenRequest {
+            channel: channel.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> OpenRequest<'static> {
+        OpenRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -2172,11 +2480,9 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("name", request.name)),
-        request.validate.map(|validate| {
-            ("validate", if validate { "1" } else { "0" })
-        }),
+        Some(("channel", &request.channel[..])),
+        Some(("name", &request.name[..])),
+        request.validate.map(|validate| ("validate", if validate { "1" } else { "0" })),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.rename");
@@ -2189,29 +2495,62 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RenameRequest<'a> {
     /// Private channel to rename
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// New name for private channel.
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
     pub validate: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> RenameRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, name: impl Into<Cow<'a, str>>) -> Self {
+        RenameRequest {
+            channel: channel.into(),
+            name: name.into(),
+            validate: None,
+        }
+    }
+
+    /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> RenameRequest<'static> {
+        RenameRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            name: Cow::Owned(self.name.into_owned()),
+            validate: self.validate,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<RenameResponseChannel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameResponseChannel {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_group: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
@@ -2392,8 +2731,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("thread_ts", request.thread_ts)),
+        Some(("channel", &request.channel[..])),
+        Some(("thread_ts", &request.thread_ts[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.replies");
@@ -2408,20 +2747,42 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RepliesRequest<'a> {
     /// Private channel to fetch thread from
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Unique identifier of a thread's parent message
-    pub thread_ts: &'a str,
+    pub thread_ts: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> RepliesRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, thread_ts: impl Into<Cow<'a, str>>) -> Self {
+        RepliesRequest {
+            channel: channel.into(),
+            thread_ts: thread_ts.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> RepliesRequest<'static> {
+        RepliesRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            thread_ts: Cow::Owned(self.thread_ts.into_owned()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RepliesResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thread_info: Option<::ThreadInfo>,
 }
 
@@ -2570,8 +2931,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("purpose", request.purpose)),
+        Some(("channel", &request.channel[..])),
+        Some(("purpose", &request.purpose[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.setPurpose");
@@ -2585,19 +2946,40 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SetPurposeRequest<'a> {
     /// Private channel to set the purpose of
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// The new purpose
-    pub purpose: &'a str,
+    pub purpose: Cow<'a, str>,
+}
+
+impl<'a> SetPurposeRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, purpose: impl Into<Cow<'a, str>>) -> Self {
+        SetPurposeRequest {
+            channel: channel.into(),
+            purpose: purpose.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> SetPurposeRequest<'static> {
+        SetPurposeRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            purpose: Cow::Owned(self.purpose.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub purpose: Option<String>,
 }
 
@@ -2750,8 +3132,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("topic", request.topic)),
+        Some(("channel", &request.channel[..])),
+        Some(("topic", &request.topic[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.setTopic");
@@ -2766,19 +3148,40 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SetTopicRequest<'a> {
     /// Private channel to set the topic of
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// The new topic
-    pub topic: &'a str,
+    pub topic: Cow<'a, str>,
+}
+
+impl<'a> SetTopicRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, topic: impl Into<Cow<'a, str>>) -> Self {
+        SetTopicRequest {
+            channel: channel.into(),
+            topic: topic.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> SetTopicRequest<'static> {
+        SetTopicRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            topic: Cow::Owned(self.topic.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<String>,
 }
 
@@ -2929,7 +3332,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("groups.unarchive");
     client
@@ -2943,14 +3349,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct UnarchiveRequest<'a> {
     /// Private channel to unarchive
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> UnarchiveRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        UnarchiveRequest {
+            channel: channel.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> UnarchiveRequest<'static> {
+        UnarchiveRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UnarchiveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -1,6 +1,8 @@
 //! Get info on your direct messages.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -24,7 +26,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("im.close");
     client
@@ -36,14 +41,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CloseRequest<'a> {
     /// Direct message channel to close.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> CloseRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        CloseRequest {
+            channel: channel.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> CloseRequest<'static> {
+        CloseRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CloseResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -190,16 +213,12 @@ where
     let count = request.count.map(|count| count.to_string());
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        request.latest.map(|latest| ("latest", latest)),
-        request.oldest.map(|oldest| ("oldest", oldest)),
-        request.inclusive.map(|inclusive| {
-            ("inclusive", if inclusive { "1" } else { "0" })
-        }),
+        Some(("channel", &request.channel[..])),
+        request.latest.as_ref().map(|latest| ("latest", &latest[..])),
+        request.oldest.as_ref().map(|oldest| ("oldest", &oldest[..])),
+        request.inclusive.map(|inclusive| ("inclusive", if inclusive { "1" } else { "0" })),
         count.as_ref().map(|count| ("count", &count[..])),
-        request.unreads.map(|unreads| {
-            ("unreads", if unreads { "1" } else { "0" })
-        }),
+        request.unreads.map(|unreads| ("unreads", if unreads { "1" } else { "0" })),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("im.history");
@@ -214,14 +233,39 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+/// Like [`history`], but returns an iterator over every item, requesting further pages as
+/// needed.
+///
+/// [`history`]: fn.history.html
+pub fn history_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &HistoryRequest<'a>,
+) -> ::paging::Items<'a, ::Message, HistoryError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut request = request.clone();
+    ::paging::Items::new(move |next| {
+        if let Some(next) = next {
+            request.latest = next.latest().map(|latest| Cow::Owned(latest.to_owned()));
+            request.inclusive = None;
+        }
+        history(client, token, &request).map(|response| {
+            let next = ::paging::next_window(response.has_more, response.messages.as_deref());
+            ::paging::Page { items: response.messages.unwrap_or_default(), next }
+        })
+    })
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct HistoryRequest<'a> {
     /// Direct message channel to fetch history for.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// End of time range of messages to include in results.
-    pub latest: Option<&'a str>,
+    pub latest: Option<Cow<'a, str>>,
     /// Start of time range of messages to include in results.
-    pub oldest: Option<&'a str>,
+    pub oldest: Option<Cow<'a, str>>,
     /// Include messages with latest or oldest timestamp in results.
     pub inclusive: Option<bool>,
     /// Number of messages to return, between 1 and 1000.
@@ -230,11 +274,72 @@ pub struct HistoryRequest<'a> {
     pub unreads: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> HistoryRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        HistoryRequest {
+            channel: channel.into(),
+            latest: None,
+            oldest: None,
+            inclusive: None,
+            count: None,
+            unreads: None,
+        }
+    }
+
+    /// End of time range of messages to include in results.
+    pub fn latest(mut self, latest: impl Into<Cow<'a, str>>) -> Self {
+        self.latest = Some(latest.into());
+        self
+    }
+
+    /// Start of time range of messages to include in results.
+    pub fn oldest(mut self, oldest: impl Into<Cow<'a, str>>) -> Self {
+        self.oldest = Some(oldest.into());
+        self
+    }
+
+    /// Include messages with latest or oldest timestamp in results.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    /// Number of messages to return, between 1 and 1000.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Include unread_count_display in the output?
+    pub fn unreads(mut self, unreads: bool) -> Self {
+        self.unreads = Some(unreads);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> HistoryRequest<'static> {
+        HistoryRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            latest: self.latest.map(|latest| Cow::Owned(latest.into_owned())),
+            oldest: self.oldest.map(|oldest| Cow::Owned(oldest.into_owned())),
+            inclusive: self.inclusive,
+            count: self.count,
+            unreads: self.unreads,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub has_more: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub latest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
@@ -387,7 +492,7 @@ where
     let limit = request.limit.map(|limit| limit.to_string());
     let params = vec![
         Some(("token", token)),
-        request.cursor.map(|cursor| ("cursor", cursor)),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
         limit.as_ref().map(|limit| ("limit", &limit[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
@@ -401,17 +506,42 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct ListRequest<'a> {
     /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See pagination for more detail.
-    pub cursor: Option<&'a str>,
+    pub cursor: Option<Cow<'a, str>>,
     /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
     pub limit: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> ListRequest<'a> {
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See pagination for more detail.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> ListRequest<'static> {
+        ListRequest {
+            cursor: self.cursor.map(|cursor| Cow::Owned(cursor.into_owned())),
+            limit: self.limit,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ims: Option<Vec<::Im>>,
     #[serde(default)]
     ok: bool,
@@ -546,8 +676,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("ts", request.ts)),
+        Some(("channel", &request.channel[..])),
+        Some(("ts", &request.ts[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("im.mark");
@@ -560,16 +690,36 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MarkRequest<'a> {
     /// Direct message channel to set reading cursor in.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Timestamp of the most recently seen message.
-    pub ts: &'a str,
+    pub ts: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> MarkRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, ts: impl Into<Cow<'a, str>>) -> Self {
+        MarkRequest {
+            channel: channel.into(),
+            ts: ts.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> MarkRequest<'static> {
+        MarkRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            ts: Cow::Owned(self.ts.into_owned()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MarkResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -720,10 +870,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("user", request.user)),
-        request.return_im.map(|return_im| {
-            ("return_im", if return_im { "1" } else { "0" })
-        }),
+        Some(("user", &request.user[..])),
+        request.return_im.map(|return_im| ("return_im", if return_im { "1" } else { "0" })),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("im.open");
@@ -736,17 +884,44 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OpenRequest<'a> {
     /// User to open a direct message channel with.
-    pub user: &'a str,
+    pub user: Cow<'a, str>,
     /// Boolean, indicates you want the full IM channel definition in the response.
     pub return_im: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> OpenRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(user: impl Into<Cow<'a, str>>) -> Self {
+        OpenRequest {
+            user: user.into(),
+            return_im: None,
+        }
+    }
+
+    /// Boolean, indicates you want the full IM channel definition in the response.
+    pub fn return_im(mut self, return_im: bool) -> Self {
+        self.return_im = Some(return_im);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> OpenRequest<'static> {
+        OpenRequest {
+            user: Cow::Owned(self.user.into_owned()),
+            return_im: self.return_im,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<::Im>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -895,8 +1070,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("thread_ts", request.thread_ts)),
+        Some(("channel", &request.channel[..])),
+        Some(("thread_ts", &request.thread_ts[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("im.replies");
@@ -911,20 +1086,42 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RepliesRequest<'a> {
     /// Direct message channel to fetch thread from
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Unique identifier of a thread's parent message
-    pub thread_ts: &'a str,
+    pub thread_ts: Cow<'a, str>,
+}
+
+impl<'a> RepliesRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>, thread_ts: impl Into<Cow<'a, str>>) -> Self {
+        RepliesRequest {
+            channel: channel.into(),
+            thread_ts: thread_ts.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> RepliesRequest<'static> {
+        RepliesRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            thread_ts: Cow::Owned(self.thread_ts.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RepliesResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thread_info: Option<::ThreadInfo>,
 }
 
@@ -1,8 +1,10 @@
 pub mod api;
+pub mod apps_connections;
 pub mod auth;
 pub mod bots;
 pub mod channels;
 pub mod chat;
+pub mod conversations;
 pub mod dnd;
 pub mod emoji;
 pub mod files_comments;
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -23,12 +25,10 @@ where
 {
 
     let params = vec![
-        Some(("client_id", request.client_id)),
-        Some(("client_secret", request.client_secret)),
-        Some(("code", request.code)),
-        request.redirect_uri.map(|redirect_uri| {
-            ("redirect_uri", redirect_uri)
-        }),
+        Some(("client_id", &request.client_id[..])),
+        Some(("client_secret", &request.client_secret[..])),
+        Some(("code", &request.code[..])),
+        request.redirect_uri.as_ref().map(|redirect_uri| ("redirect_uri", &redirect_uri[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("oauth.access");
@@ -40,21 +40,61 @@ where
         })
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccessRequest<'a> {
     /// Issued when you created your application.
-    pub client_id: &'a str,
+    pub client_id: Cow<'a, str>,
     /// Issued when you created your application.
-    pub client_secret: &'a str,
+    pub client_secret: Cow<'a, str>,
     /// The code param returned via the OAuth callback.
-    pub code: &'a str,
+    pub code: Cow<'a, str>,
     /// This must match the originally submitted URI (if one was sent).
-    pub redirect_uri: Option<&'a str>,
+    pub redirect_uri: Option<Cow<'a, str>>,
+}
+
+impl<'a> AccessRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(
+        client_id: impl Into<Cow<'a,
+        str>>,
+        client_secret: impl Into<Cow<'a,
+        str>>,
+        code: impl Into<Cow<'a,
+        str>>,
+    ) -> Self {
+        AccessRequest {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            code: code.into(),
+            redirect_uri: None,
+        }
+    }
+
+    /// This must match the originally submitted URI (if one was sent).
+    pub fn redirect_uri(mut self, redirect_uri: impl Into<Cow<'a, str>>) -> Self {
+        self.redirect_uri = Some(redirect_uri.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> AccessRequest<'static> {
+        AccessRequest {
+            client_id: Cow::Owned(self.client_id.into_owned()),
+            client_secret: Cow::Owned(self.client_secret.into_owned()),
+            code: Cow::Owned(self.code.into_owned()),
+            redirect_uri: self.redirect_uri.map(|redirect_uri| {
+                Cow::Owned(redirect_uri.into_owned())
+            }),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AccessResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub access_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<String>,
 }
 
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,12 +27,10 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        Some(("channel", &request.channel[..])),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("pins.add");
@@ -43,20 +43,64 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AddRequest<'a> {
     /// Channel to pin the item in.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// File to pin.
-    pub file: Option<&'a str>,
+    pub file: Option<Cow<'a, str>>,
     /// File comment to pin.
-    pub file_comment: Option<&'a str>,
+    pub file_comment: Option<Cow<'a, str>>,
     /// Timestamp of the message to pin.
-    pub timestamp: Option<&'a str>,
+    pub timestamp: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> AddRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        AddRequest {
+            channel: channel.into(),
+            file: None,
+            file_comment: None,
+            timestamp: None,
+        }
+    }
+
+    /// File to pin.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// File comment to pin.
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.file_comment = Some(file_comment.into());
+        self
+    }
+
+    /// Timestamp of the message to pin.
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> AddRequest<'static> {
+        AddRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            file: self.file.map(|file| Cow::Owned(file.into_owned())),
+            file_comment: self.file_comment.map(|file_comment| {
+                Cow::Owned(file_comment.into_owned())
+            }),
+            timestamp: self.timestamp.map(|timestamp| Cow::Owned(timestamp.into_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -239,7 +283,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("pins.list");
     client
@@ -251,15 +298,34 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ListRequest<'a> {
     /// Channel to get pinned items for.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> ListRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        ListRequest {
+            channel: channel.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> ListRequest<'static> {
+        ListRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Vec<ListResponseItem>>,
     #[serde(default)]
     ok: bool,
@@ -314,9 +380,24 @@ impl<'de> ::serde::Deserialize<'de> for ListResponseItem {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl ::serde::Serialize for ListResponseItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        match *self {
+            ListResponseItem::Message(ref inner) => inner.serialize(serializer),
+            ListResponseItem::File(ref inner) => inner.serialize(serializer),
+            ListResponseItem::FileComment(ref inner) => inner.serialize(serializer),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseItemFile {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by: Option<String>,
     pub file: ::File,
     #[serde(rename = "type")]
@@ -324,10 +405,12 @@ pub struct ListResponseItemFile {
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseItemFileComment {
     pub comment: ::FileComment,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by: Option<String>,
     pub file: ::File,
     #[serde(rename = "type")]
@@ -335,10 +418,12 @@ pub struct ListResponseItemFileComment {
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseItemMessage {
     pub channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by: Option<String>,
     pub message: ::Message,
     #[serde(rename = "type")]
@@ -480,12 +565,10 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        Some(("channel", &request.channel[..])),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("pins.remove");
@@ -498,20 +581,64 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RemoveRequest<'a> {
     /// Channel where the item is pinned to.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// File to un-pin.
-    pub file: Option<&'a str>,
+    pub file: Option<Cow<'a, str>>,
     /// File comment to un-pin.
-    pub file_comment: Option<&'a str>,
+    pub file_comment: Option<Cow<'a, str>>,
     /// Timestamp of the message to un-pin.
-    pub timestamp: Option<&'a str>,
+    pub timestamp: Option<Cow<'a, str>>,
+}
+
+impl<'a> RemoveRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(channel: impl Into<Cow<'a, str>>) -> Self {
+        RemoveRequest {
+            channel: channel.into(),
+            file: None,
+            file_comment: None,
+            timestamp: None,
+        }
+    }
+
+    /// File to un-pin.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// File comment to un-pin.
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.file_comment = Some(file_comment.into());
+        self
+    }
+
+    /// Timestamp of the message to un-pin.
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> RemoveRequest<'static> {
+        RemoveRequest {
+            channel: Cow::Owned(self.channel.into_owned()),
+            file: self.file.map(|file| Cow::Owned(file.into_owned())),
+            file_comment: self.file_comment.map(|file_comment| {
+                Cow::Owned(file_comment.into_owned())
+            }),
+            timestamp: self.timestamp.map(|timestamp| Cow::Owned(timestamp.into_owned())),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RemoveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
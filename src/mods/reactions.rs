@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,13 +27,11 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("name", request.name)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.channel.map(|channel| ("channel", channel)),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        Some(("name", &request.name[..])),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reactions.add");
@@ -44,22 +44,74 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AddRequest<'a> {
     /// Reaction (emoji) name.
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
+    /// File to add reaction to.
+    pub file: Option<Cow<'a, str>>,
+    /// File comment to add reaction to.
+    pub file_comment: Option<Cow<'a, str>>,
+    /// Channel where the message to add reaction to was posted.
+    pub channel: Option<Cow<'a, str>>,
+    /// Timestamp of the message to add reaction to.
+    pub timestamp: Option<Cow<'a, str>>,
+}
+
+impl<'a> AddRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        AddRequest {
+            name: name.into(),
+            file: None,
+            file_comment: None,
+            channel: None,
+            timestamp: None,
+        }
+    }
+
     /// File to add reaction to.
-    pub file: Option<&'a str>,
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
     /// File comment to add reaction to.
-    pub file_comment: Option<&'a str>,
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.file_comment = Some(file_comment.into());
+        self
+    }
+
     /// Channel where the message to add reaction to was posted.
-    pub channel: Option<&'a str>,
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.channel = Some(channel.into());
+        self
+    }
+
     /// Timestamp of the message to add reaction to.
-    pub timestamp: Option<&'a str>,
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> AddRequest<'static> {
+        AddRequest {
+            name: Cow::Owned(self.name.into_owned()),
+            file: self.file.map(|file| Cow::Owned(file.into_owned())),
+            file_comment: self.file_comment.map(|file_comment| {
+                Cow::Owned(file_comment.into_owned())
+            }),
+            channel: self.channel.map(|channel| Cow::Owned(channel.into_owned())),
+            timestamp: self.timestamp.map(|timestamp| Cow::Owned(timestamp.into_owned())),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -242,15 +294,11 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.channel.map(|channel| ("channel", channel)),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
-        request.full.map(
-            |full| ("full", if full { "1" } else { "0" })
-        ),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
+        request.full.map(|full| ("full", if full { "1" } else { "0" })),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reactions.get");
@@ -263,20 +311,66 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct GetRequest<'a> {
     /// File to get reactions for.
-    pub file: Option<&'a str>,
+    pub file: Option<Cow<'a, str>>,
     /// File comment to get reactions for.
-    pub file_comment: Option<&'a str>,
+    pub file_comment: Option<Cow<'a, str>>,
     /// Channel where the message to get reactions for was posted.
-    pub channel: Option<&'a str>,
+    pub channel: Option<Cow<'a, str>>,
     /// Timestamp of the message to get reactions for.
-    pub timestamp: Option<&'a str>,
+    pub timestamp: Option<Cow<'a, str>>,
     /// If true always return the complete reaction list.
     pub full: Option<bool>,
 }
 
+impl<'a> GetRequest<'a> {
+    /// File to get reactions for.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// File comment to get reactions for.
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.file_comment = Some(file_comment.into());
+        self
+    }
+
+    /// Channel where the message to get reactions for was posted.
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.channel = Some(channel.into());
+        self
+    }
+
+    /// Timestamp of the message to get reactions for.
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// If true always return the complete reaction list.
+    pub fn full(mut self, full: bool) -> Self {
+        self.full = Some(full);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> GetRequest<'static> {
+        GetRequest {
+            file: self.file.map(|file| Cow::Owned(file.into_owned())),
+            file_comment: self.file_comment.map(|file_comment| {
+                Cow::Owned(file_comment.into_owned())
+            }),
+            channel: self.channel.map(|channel| Cow::Owned(channel.into_owned())),
+            timestamp: self.timestamp.map(|timestamp| Cow::Owned(timestamp.into_owned())),
+            full: self.full,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum GetResponse {
     Message(GetResponseMessage),
@@ -326,8 +420,22 @@ impl<'de> ::serde::Deserialize<'de> for GetResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl ::serde::Serialize for GetResponse {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        match *self {
+            GetResponse::Message(ref inner) => inner.serialize(serializer),
+            GetResponse::File(ref inner) => inner.serialize(serializer),
+            GetResponse::FileComment(ref inner) => inner.serialize(serializer),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponseFile {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     pub file: ::File,
     #[serde(default)]
@@ -337,9 +445,10 @@ pub struct GetResponseFile {
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponseFileComment {
     pub comment: ::FileComment,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     pub file: ::File,
     #[serde(default)]
@@ -349,9 +458,10 @@ pub struct GetResponseFileComment {
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponseMessage {
     pub channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     pub message: ::Message,
     #[serde(default)]
@@ -553,10 +663,8 @@ where
     let page = request.page.map(|page| page.to_string());
     let params = vec![
         Some(("token", token)),
-        request.user.map(|user| ("user", user)),
-        request.full.map(
-            |full| ("full", if full { "1" } else { "0" })
-        ),
+        request.user.as_ref().map(|user| ("user", &user[..])),
+        request.full.map(|full| ("full", if full { "1" } else { "0" })),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
@@ -571,10 +679,34 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+/// Like [`list`], but returns an iterator over every item, requesting further pages as
+/// needed.
+///
+/// [`list`]: fn.list.html
+pub fn list_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &ListRequest<'a>,
+) -> ::paging::Items<'a, ListResponseItem, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut request = request.clone();
+    ::paging::Items::new(move |next| {
+        if let Some(next) = next {
+            request.page = next.page();
+        }
+        list(client, token, &request).map(|response| {
+            let next = response.paging.as_ref().and_then(::paging::next_page);
+            ::paging::Page { items: response.items.unwrap_or_default(), next }
+        })
+    })
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct ListRequest<'a> {
     /// Show reactions made by this user. Defaults to the authed user.
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
     /// If true always return the complete reaction list.
     pub full: Option<bool>,
     /// Number of items to return per page.
@@ -583,12 +715,52 @@ pub struct ListRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> ListRequest<'a> {
+    /// Show reactions made by this user. Defaults to the authed user.
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// If true always return the complete reaction list.
+    pub fn full(mut self, full: bool) -> Self {
+        self.full = Some(full);
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> ListRequest<'static> {
+        ListRequest {
+            user: self.user.map(|user| Cow::Owned(user.into_owned())),
+            full: self.full,
+            count: self.count,
+            page: self.page,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Vec<ListResponseItem>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub paging: Option<::Paging>,
 }
 
@@ -641,7 +813,20 @@ impl<'de> ::serde::Deserialize<'de> for ListResponseItem {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl ::serde::Serialize for ListResponseItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        match *self {
+            ListResponseItem::Message(ref inner) => inner.serialize(serializer),
+            ListResponseItem::File(ref inner) => inner.serialize(serializer),
+            ListResponseItem::FileComment(ref inner) => inner.serialize(serializer),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseItemFile {
     pub file: ::File,
     #[serde(rename = "type")]
@@ -649,7 +834,7 @@ pub struct ListResponseItemFile {
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseItemFileComment {
     pub comment: ::FileComment,
     pub file: ::File,
@@ -658,7 +843,7 @@ pub struct ListResponseItemFileComment {
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseItemMessage {
     pub channel: String,
     pub message: ::Message,
@@ -799,13 +984,11 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("name", request.name)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.channel.map(|channel| ("channel", channel)),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        Some(("name", &request.name[..])),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reactions.remove");
@@ -818,22 +1001,74 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RemoveRequest<'a> {
     /// Reaction (emoji) name.
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
+    /// File to remove reaction from.
+    pub file: Option<Cow<'a, str>>,
+    /// File comment to remove reaction from.
+    pub file_comment: Option<Cow<'a, str>>,
+    /// Channel where the message to remove reaction from was posted.
+    pub channel: Option<Cow<'a, str>>,
+    /// Timestamp of the message to remove reaction from.
+    pub timestamp: Option<Cow<'a, str>>,
+}
+
+impl<'a> RemoveRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        RemoveRequest {
+            name: name.into(),
+            file: None,
+            file_comment: None,
+            channel: None,
+            timestamp: None,
+        }
+    }
+
     /// File to remove reaction from.
-    pub file: Option<&'a str>,
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
     /// File comment to remove reaction from.
-    pub file_comment: Option<&'a str>,
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.file_comment = Some(file_comment.into());
+        self
+    }
+
     /// Channel where the message to remove reaction from was posted.
-    pub channel: Option<&'a str>,
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.channel = Some(channel.into());
+        self
+    }
+
     /// Timestamp of the message to remove reaction from.
-    pub timestamp: Option<&'a str>,
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> RemoveRequest<'static> {
+        RemoveRequest {
+            name: Cow::Owned(self.name.into_owned()),
+            file: self.file.map(|file| Cow::Owned(file.into_owned())),
+            file_comment: self.file_comment.map(|file_comment| {
+                Cow::Owned(file_comment.into_owned())
+            }),
+            channel: self.channel.map(|channel| Cow::Owned(channel.into_owned())),
+            timestamp: self.timestamp.map(|timestamp| Cow::Owned(timestamp.into_owned())),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RemoveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
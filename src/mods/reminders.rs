@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,9 +27,9 @@ where
     let time = request.time.to_string();
     let params = vec![
         Some(("token", token)),
-        Some(("text", request.text)),
+        Some(("text", &request.text[..])),
         Some(("time", &time[..])),
-        request.user.map(|user| ("user", user)),
+        request.user.as_ref().map(|user| ("user", &user[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reminders.add");
@@ -40,21 +42,50 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AddRequest<'a> {
     /// The content of the reminder
-    pub text: &'a str,
+    pub text: Cow<'a, str>,
     /// When this reminder should happen: the Unix timestamp (up to five years from now), the number of seconds until the reminder (if within 24 hours), or a natural language description (Ex. "in 15 minutes," or "every Thursday")
     pub time: u32,
     /// The user who will receive the reminder. If no user is specified, the reminder will go to user who created it.
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
+}
+
+impl<'a> AddRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(text: impl Into<Cow<'a, str>>, time: u32) -> Self {
+        AddRequest {
+            text: text.into(),
+            time,
+            user: None,
+        }
+    }
+
+    /// The user who will receive the reminder. If no user is specified, the reminder will go to user who created it.
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> AddRequest<'static> {
+        AddRequest {
+            text: Cow::Owned(self.text.into_owned()),
+            time: self.time,
+            user: self.user.map(|user| Cow::Owned(user.into_owned())),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reminder: Option<::Reminder>,
 }
 
@@ -221,7 +252,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("reminder", &request.reminder[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reminders.complete");
     client
@@ -235,14 +269,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CompleteRequest<'a> {
     /// The ID of the reminder to be marked as complete
-    pub reminder: &'a str,
+    pub reminder: Cow<'a, str>,
+}
+
+impl<'a> CompleteRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(reminder: impl Into<Cow<'a, str>>) -> Self {
+        CompleteRequest {
+            reminder: reminder.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> CompleteRequest<'static> {
+        CompleteRequest {
+            reminder: Cow::Owned(self.reminder.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CompleteResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -395,7 +447,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("reminder", &request.reminder[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reminders.delete");
     client
@@ -407,14 +462,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DeleteRequest<'a> {
     /// The ID of the reminder
-    pub reminder: &'a str,
+    pub reminder: Cow<'a, str>,
+}
+
+impl<'a> DeleteRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(reminder: impl Into<Cow<'a, str>>) -> Self {
+        DeleteRequest {
+            reminder: reminder.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> DeleteRequest<'static> {
+        DeleteRequest {
+            reminder: Cow::Owned(self.reminder.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -555,7 +628,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("reminder", &request.reminder[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reminders.info");
     client
@@ -567,17 +643,36 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct InfoRequest<'a> {
     /// The ID of the reminder
-    pub reminder: &'a str,
+    pub reminder: Cow<'a, str>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(reminder: impl Into<Cow<'a, str>>) -> Self {
+        InfoRequest {
+            reminder: reminder.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> InfoRequest<'static> {
+        InfoRequest {
+            reminder: Cow::Owned(self.reminder.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reminder: Option<::Reminder>,
 }
 
@@ -722,11 +817,13 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reminders: Option<Vec<::Reminder>>,
 }
 
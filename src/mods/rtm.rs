@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -31,30 +33,41 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConnectResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub slf: Option<ConnectResponseSelf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<ConnectResponseTeam>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConnectResponseSelf {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConnectResponseTeam {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub enterprise_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub enterprise_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
@@ -187,20 +200,11 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.no_unreads.map(|no_unreads| {
-            ("no_unreads", if no_unreads { "1" } else { "0" })
-        }),
-        request.mpim_aware.map(|mpim_aware| {
-            ("mpim_aware", if mpim_aware { "1" } else { "0" })
-        }),
-        request.no_latest.map(|no_latest| {
-            ("no_latest", if no_latest { "1" } else { "0" })
-        }),
+        request.no_unreads.map(|no_unreads| ("no_unreads", if no_unreads { "1" } else { "0" })),
+        request.mpim_aware.map(|mpim_aware| ("mpim_aware", if mpim_aware { "1" } else { "0" })),
+        request.no_latest.map(|no_latest| ("no_latest", if no_latest { "1" } else { "0" })),
         request.batch_presence_aware.map(|batch_presence_aware| {
-            (
-                "batch_presence_aware",
-                if batch_presence_aware { "1" } else { "0" },
-            )
+            ("batch_presence_aware", if batch_presence_aware { "1" } else { "0" },)
         }),
         request.include_locale.map(|include_locale| {
             ("include_locale", if include_locale { "1" } else { "0" })
@@ -217,7 +221,7 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct StartRequest {
     /// Skip unread counts for each channel (improves performance).
     pub no_unreads: Option<bool>,
@@ -231,20 +235,62 @@ pub struct StartRequest {
     pub include_locale: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl StartRequest {
+    /// Skip unread counts for each channel (improves performance).
+    pub fn no_unreads(mut self, no_unreads: bool) -> Self {
+        self.no_unreads = Some(no_unreads);
+        self
+    }
+
+    /// Returns MPIMs to the client in the API response.
+    pub fn mpim_aware(mut self, mpim_aware: bool) -> Self {
+        self.mpim_aware = Some(mpim_aware);
+        self
+    }
+
+    /// Exclude latest timestamps for channels, groups, mpims, and ims. Automatically sets no_unreads to 1
+    pub fn no_latest(mut self, no_latest: bool) -> Self {
+        self.no_latest = Some(no_latest);
+        self
+    }
+
+    /// Only deliver presence events when requested by subscription. See [presence subscriptions](/docs/presence-and-status#subscriptions).
+    pub fn batch_presence_aware(mut self, batch_presence_aware: bool) -> Self {
+        self.batch_presence_aware = Some(batch_presence_aware);
+        self
+    }
+
+    /// Set this to `true` to receive the locale for users and channels. Defaults to `false`
+    pub fn include_locale(mut self, include_locale: bool) -> Self {
+        self.include_locale = Some(include_locale);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct StartResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bots: Option<Vec<::Bot>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channels: Option<Vec<::Channel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub groups: Option<Vec<::Group>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ims: Option<Vec<::Im>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mpims: Option<Vec<::Mpim>>,
     #[serde(default)]
     ok: bool,
     #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub slf: Option<::User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<::Team>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub users: Option<Vec<::User>>,
 }
 
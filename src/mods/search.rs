@@ -1,6 +1,8 @@
 //! Search your team's files and messages.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -27,12 +29,10 @@ where
     let page = request.page.map(|page| page.to_string());
     let params = vec![
         Some(("token", token)),
-        Some(("query", request.query)),
-        request.sort.map(|sort| ("sort", sort)),
-        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
-        request.highlight.map(|highlight| {
-            ("highlight", if highlight { "1" } else { "0" })
-        }),
+        Some(("query", &request.query[..])),
+        request.sort.as_ref().map(|sort| ("sort", &sort[..])),
+        request.sort_dir.as_ref().map(|sort_dir| ("sort_dir", &sort_dir[..])),
+        request.highlight.map(|highlight| ("highlight", if highlight { "1" } else { "0" })),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
@@ -47,14 +47,14 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AllRequest<'a> {
     /// Search query. May contains booleans, etc.
-    pub query: &'a str,
+    pub query: Cow<'a, str>,
     /// Return matches sorted by either score or timestamp.
-    pub sort: Option<&'a str>,
+    pub sort: Option<Cow<'a, str>>,
     /// Change sort direction to ascending (asc) or descending (desc).
-    pub sort_dir: Option<&'a str>,
+    pub sort_dir: Option<Cow<'a, str>>,
     /// Pass a value of true to enable query highlight markers (see below).
     pub highlight: Option<bool>,
     /// Number of items to return per page.
@@ -63,24 +63,85 @@ pub struct AllRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> AllRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(query: impl Into<Cow<'a, str>>) -> Self {
+        AllRequest {
+            query: query.into(),
+            sort: None,
+            sort_dir: None,
+            highlight: None,
+            count: None,
+            page: None,
+        }
+    }
+
+    /// Return matches sorted by either score or timestamp.
+    pub fn sort(mut self, sort: impl Into<Cow<'a, str>>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// Change sort direction to ascending (asc) or descending (desc).
+    pub fn sort_dir(mut self, sort_dir: impl Into<Cow<'a, str>>) -> Self {
+        self.sort_dir = Some(sort_dir.into());
+        self
+    }
+
+    /// Pass a value of true to enable query highlight markers (see below).
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> AllRequest<'static> {
+        AllRequest {
+            query: Cow::Owned(self.query.into_owned()),
+            sort: self.sort.map(|sort| Cow::Owned(sort.into_owned())),
+            sort_dir: self.sort_dir.map(|sort_dir| Cow::Owned(sort_dir.into_owned())),
+            highlight: self.highlight,
+            count: self.count,
+            page: self.page,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AllResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub files: Option<AllResponseFiles>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<AllResponseMessages>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub query: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AllResponseFiles {
     pub matches: Vec<::File>,
     pub paging: ::Paging,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AllResponseMessages {
     pub matches: Vec<::Message>,
     pub paging: ::Paging,
@@ -220,12 +281,10 @@ where
     let page = request.page.map(|page| page.to_string());
     let params = vec![
         Some(("token", token)),
-        Some(("query", request.query)),
-        request.sort.map(|sort| ("sort", sort)),
-        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
-        request.highlight.map(|highlight| {
-            ("highlight", if highlight { "1" } else { "0" })
-        }),
+        Some(("query", &request.query[..])),
+        request.sort.as_ref().map(|sort| ("sort", &sort[..])),
+        request.sort_dir.as_ref().map(|sort_dir| ("sort_dir", &sort_dir[..])),
+        request.highlight.map(|highlight| ("highlight", if highlight { "1" } else { "0" })),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
@@ -240,14 +299,14 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FilesRequest<'a> {
     /// Search query. May contain booleans, etc.
-    pub query: &'a str,
+    pub query: Cow<'a, str>,
     /// Return matches sorted by either score or timestamp.
-    pub sort: Option<&'a str>,
+    pub sort: Option<Cow<'a, str>>,
     /// Change sort direction to ascending (asc) or descending (desc).
-    pub sort_dir: Option<&'a str>,
+    pub sort_dir: Option<Cow<'a, str>>,
     /// Pass a value of true to enable query highlight markers (see below).
     pub highlight: Option<bool>,
     /// Number of items to return per page.
@@ -256,19 +315,82 @@ pub struct FilesRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> FilesRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(query: impl Into<Cow<'a, str>>) -> Self {
+        FilesRequest {
+            query: query.into(),
+            sort: None,
+            sort_dir: None,
+            highlight: None,
+            count: None,
+            page: None,
+        }
+    }
+
+    /// Return matches sorted by either score or timestamp.
+    pub fn sort(mut self, sort: impl Into<Cow<'a, str>>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// Change sort direction to ascending (asc) or descending (desc).
+    pub fn sort_dir(mut self, sort_dir: impl Into<Cow<'a, str>>) -> Self {
+        self.sort_dir = Some(sort_dir.into());
+        self
+    }
+
+    /// Pass a value of true to enable query highlight markers (see below).
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> FilesRequest<'static> {
+        FilesRequest {
+            query: Cow::Owned(self.query.into_owned()),
+            sort: self.sort.map(|sort| Cow::Owned(sort.into_owned())),
+            sort_dir: self.sort_dir.map(|sort_dir| Cow::Owned(sort_dir.into_owned())),
+            highlight: self.highlight,
+            count: self.count,
+            page: self.page,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FilesResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub files: Option<FilesResponseFiles>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub query: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FilesResponseFiles {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub matches: Option<Vec<::File>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub paging: Option<::Paging>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub total: Option<i32>,
 }
 
@@ -406,12 +528,10 @@ where
     let page = request.page.map(|page| page.to_string());
     let params = vec![
         Some(("token", token)),
-        Some(("query", request.query)),
-        request.sort.map(|sort| ("sort", sort)),
-        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
-        request.highlight.map(|highlight| {
-            ("highlight", if highlight { "1" } else { "0" })
-        }),
+        Some(("query", &request.query[..])),
+        request.sort.as_ref().map(|sort| ("sort", &sort[..])),
+        request.sort_dir.as_ref().map(|sort_dir| ("sort_dir", &sort_dir[..])),
+        request.highlight.map(|highlight| ("highlight", if highlight { "1" } else { "0" })),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
@@ -428,14 +548,45 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+/// Like [`messages`], but returns an iterator over every item, requesting further pages as
+/// needed.
+///
+/// [`messages`]: fn.messages.html
+pub fn messages_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &MessagesRequest<'a>,
+) -> ::paging::Items<'a, ::Message, MessagesError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut request = request.clone();
+    ::paging::Items::new(move |next| {
+        if let Some(next) = next {
+            request.page = next.page();
+        }
+        messages(client, token, &request).map(|response| {
+            let next = response
+                .messages
+                .as_ref()
+                .and_then(|m| m.paging.as_ref())
+                .and_then(::paging::next_page);
+            ::paging::Page {
+                items: response.messages.and_then(|m| m.matches).unwrap_or_default(),
+                next,
+            }
+        })
+    })
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MessagesRequest<'a> {
     /// Search query. May contains booleans, etc.
-    pub query: &'a str,
+    pub query: Cow<'a, str>,
     /// Return matches sorted by either score or timestamp.
-    pub sort: Option<&'a str>,
+    pub sort: Option<Cow<'a, str>>,
     /// Change sort direction to ascending (asc) or descending (desc).
-    pub sort_dir: Option<&'a str>,
+    pub sort_dir: Option<Cow<'a, str>>,
     /// Pass a value of true to enable query highlight markers (see below).
     pub highlight: Option<bool>,
     /// Number of items to return per page.
@@ -444,19 +595,82 @@ pub struct MessagesRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> MessagesRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(query: impl Into<Cow<'a, str>>) -> Self {
+        MessagesRequest {
+            query: query.into(),
+            sort: None,
+            sort_dir: None,
+            highlight: None,
+            count: None,
+            page: None,
+        }
+    }
+
+    /// Return matches sorted by either score or timestamp.
+    pub fn sort(mut self, sort: impl Into<Cow<'a, str>>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// Change sort direction to ascending (asc) or descending (desc).
+    pub fn sort_dir(mut self, sort_dir: impl Into<Cow<'a, str>>) -> Self {
+        self.sort_dir = Some(sort_dir.into());
+        self
+    }
+
+    /// Pass a value of true to enable query highlight markers (see below).
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> MessagesRequest<'static> {
+        MessagesRequest {
+            query: Cow::Owned(self.query.into_owned()),
+            sort: self.sort.map(|sort| Cow::Owned(sort.into_owned())),
+            sort_dir: self.sort_dir.map(|sort_dir| Cow::Owned(sort_dir.into_owned())),
+            highlight: self.highlight,
+            count: self.count,
+            page: self.page,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessagesResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<MessagesResponseMessages>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub query: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessagesResponseMessages {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub matches: Option<Vec<::Message>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub paging: Option<::Paging>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub total: Option<i32>,
 }
 
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,12 +27,10 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.channel.map(|channel| ("channel", channel)),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("stars.add");
@@ -43,20 +43,60 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct AddRequest<'a> {
     /// File to add star to.
-    pub file: Option<&'a str>,
+    pub file: Option<Cow<'a, str>>,
+    /// File comment to add star to.
+    pub file_comment: Option<Cow<'a, str>>,
+    /// Channel to add star to, or channel where the message to add star to was posted (used with timestamp).
+    pub channel: Option<Cow<'a, str>>,
+    /// Timestamp of the message to add star to.
+    pub timestamp: Option<Cow<'a, str>>,
+}
+
+impl<'a> AddRequest<'a> {
+    /// File to add star to.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
     /// File comment to add star to.
-    pub file_comment: Option<&'a str>,
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.file_comment = Some(file_comment.into());
+        self
+    }
+
     /// Channel to add star to, or channel where the message to add star to was posted (used with timestamp).
-    pub channel: Option<&'a str>,
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.channel = Some(channel.into());
+        self
+    }
+
     /// Timestamp of the message to add star to.
-    pub timestamp: Option<&'a str>,
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> AddRequest<'static> {
+        AddRequest {
+            file: self.file.map(|file| Cow::Owned(file.into_owned())),
+            file_comment: self.file_comment.map(|file_comment| {
+                Cow::Owned(file_comment.into_owned())
+            }),
+            channel: self.channel.map(|channel| Cow::Owned(channel.into_owned())),
+            timestamp: self.timestamp.map(|timestamp| Cow::Owned(timestamp.into_owned())),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -244,7 +284,31 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+/// Like [`list`], but returns an iterator over every item, requesting further pages as
+/// needed.
+///
+/// [`list`]: fn.list.html
+pub fn list_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &ListRequest,
+) -> ::paging::Items<'a, ListResponseItem, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut request = request.clone();
+    ::paging::Items::new(move |next| {
+        if let Some(next) = next {
+            request.page = next.page();
+        }
+        list(client, token, &request).map(|response| {
+            let next = response.paging.as_ref().and_then(::paging::next_page);
+            ::paging::Page { items: response.items.unwrap_or_default(), next }
+        })
+    })
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct ListRequest {
     /// Number of items to return per page.
     pub count: Option<u32>,
@@ -252,12 +316,29 @@ pub struct ListRequest {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl ListRequest {
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Vec<ListResponseItem>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub paging: Option<::Paging>,
 }
 
@@ -329,7 +410,23 @@ impl<'de> ::serde::Deserialize<'de> for ListResponseItem {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl ::serde::Serialize for ListResponseItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        match *self {
+            ListResponseItem::Message(ref inner) => inner.serialize(serializer),
+            ListResponseItem::File(ref inner) => inner.serialize(serializer),
+            ListResponseItem::FileComment(ref inner) => inner.serialize(serializer),
+            ListResponseItem::Channel(ref inner) => inner.serialize(serializer),
+            ListResponseItem::Im(ref inner) => inner.serialize(serializer),
+            ListResponseItem::Group(ref inner) => inner.serialize(serializer),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseItemChannel {
     pub channel: String,
     #[serde(rename = "type")]
@@ -337,7 +434,7 @@ pub struct ListResponseItemChannel {
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseItemFile {
     pub file: ::File,
     #[serde(rename = "type")]
@@ -345,7 +442,7 @@ pub struct ListResponseItemFile {
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseItemFileComment {
     pub comment: ::FileComment,
     pub file: ::File,
@@ -354,7 +451,7 @@ pub struct ListResponseItemFileComment {
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseItemGroup {
     pub group: String,
     #[serde(rename = "type")]
@@ -362,7 +459,7 @@ pub struct ListResponseItemGroup {
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseItemIm {
     pub channel: String,
     #[serde(rename = "type")]
@@ -370,7 +467,7 @@ pub struct ListResponseItemIm {
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseItemMessage {
     pub channel: String,
     pub message: ::Message,
@@ -521,12 +618,10 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.channel.map(|channel| ("channel", channel)),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("stars.remove");
@@ -539,20 +634,60 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct RemoveRequest<'a> {
     /// File to remove star from.
-    pub file: Option<&'a str>,
+    pub file: Option<Cow<'a, str>>,
+    /// File comment to remove star from.
+    pub file_comment: Option<Cow<'a, str>>,
+    /// Channel to remove star from, or channel where the message to remove star from was posted (used with timestamp).
+    pub channel: Option<Cow<'a, str>>,
+    /// Timestamp of the message to remove star from.
+    pub timestamp: Option<Cow<'a, str>>,
+}
+
+impl<'a> RemoveRequest<'a> {
+    /// File to remove star from.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
     /// File comment to remove star from.
-    pub file_comment: Option<&'a str>,
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.file_comment = Some(file_comment.into());
+        self
+    }
+
     /// Channel to remove star from, or channel where the message to remove star from was posted (used with timestamp).
-    pub channel: Option<&'a str>,
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.channel = Some(channel.into());
+        self
+    }
+
     /// Timestamp of the message to remove star from.
-    pub timestamp: Option<&'a str>,
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> RemoveRequest<'static> {
+        RemoveRequest {
+            file: self.file.map(|file| Cow::Owned(file.into_owned())),
+            file_comment: self.file_comment.map(|file_comment| {
+                Cow::Owned(file_comment.into_owned())
+            }),
+            channel: self.channel.map(|channel| Cow::Owned(channel.into_owned())),
+            timestamp: self.timestamp.map(|timestamp| Cow::Owned(timestamp.into_owned())),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RemoveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -43,7 +45,31 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+/// Like [`access_logs`], but returns an iterator over every item, requesting further pages as
+/// needed.
+///
+/// [`access_logs`]: fn.access_logs.html
+pub fn access_logs_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &AccessLogsRequest,
+) -> ::paging::Items<'a, AccessLogsResponseLogin, AccessLogsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut request = request.clone();
+    ::paging::Items::new(move |next| {
+        if let Some(next) = next {
+            request.page = next.page();
+        }
+        access_logs(client, token, &request).map(|response| {
+            let next = response.paging.as_ref().and_then(::paging::next_page);
+            ::paging::Page { items: response.logins.unwrap_or_default(), next }
+        })
+    })
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct AccessLogsRequest {
     /// Number of items to return per page.
     pub count: Option<u32>,
@@ -53,26 +79,59 @@ pub struct AccessLogsRequest {
     pub before: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl AccessLogsRequest {
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// End of time range of logs to include in results (inclusive).
+    pub fn before(mut self, before: u32) -> Self {
+        self.before = Some(before);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AccessLogsResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub logins: Option<Vec<AccessLogsResponseLogin>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub paging: Option<::Paging>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AccessLogsResponseLogin {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date_first: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date_last: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub isp: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user_agent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 }
 
@@ -221,7 +280,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.user.map(|user| ("user", user)),
+        request.user.as_ref().map(|user| ("user", &user[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("team.billableInfo");
@@ -235,15 +294,33 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct BillableInfoRequest<'a> {
     /// A user to retrieve the billable information for. Defaults to all users.
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> BillableInfoRequest<'a> {
+    /// A user to retrieve the billable information for. Defaults to all users.
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> BillableInfoRequest<'static> {
+        BillableInfoRequest {
+            user: self.user.map(|user| Cow::Owned(user.into_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BillableInfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub billable_info: Option<HashMap<String, bool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -392,11 +469,13 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<::Team>,
 }
 
@@ -530,14 +609,10 @@ where
     let page = request.page.map(|page| page.to_string());
     let params = vec![
         Some(("token", token)),
-        request.service_id.map(
-            |service_id| ("service_id", service_id)
-        ),
-        request.app_id.map(|app_id| ("app_id", app_id)),
-        request.user.map(|user| ("user", user)),
-        request.change_type.map(|change_type| {
-            ("change_type", change_type)
-        }),
+        request.service_id.as_ref().map(|service_id| ("service_id", &service_id[..])),
+        request.app_id.as_ref().map(|app_id| ("app_id", &app_id[..])),
+        request.user.as_ref().map(|user| ("user", &user[..])),
+        request.change_type.as_ref().map(|change_type| ("change_type", &change_type[..])),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
@@ -553,43 +628,108 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct IntegrationLogsRequest<'a> {
     /// Filter logs to this service. Defaults to all logs.
-    pub service_id: Option<&'a str>,
+    pub service_id: Option<Cow<'a, str>>,
     /// Filter logs to this Slack app. Defaults to all logs.
-    pub app_id: Option<&'a str>,
+    pub app_id: Option<Cow<'a, str>>,
     /// Filter logs generated by this user’s actions. Defaults to all logs.
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
     /// Filter logs with this change type. Defaults to all logs.
-    pub change_type: Option<&'a str>,
+    pub change_type: Option<Cow<'a, str>>,
     /// Number of items to return per page.
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> IntegrationLogsRequest<'a> {
+    /// Filter logs to this service. Defaults to all logs.
+    pub fn service_id(mut self, service_id: impl Into<Cow<'a, str>>) -> Self {
+        self.service_id = Some(service_id.into());
+        self
+    }
+
+    /// Filter logs to this Slack app. Defaults to all logs.
+    pub fn app_id(mut self, app_id: impl Into<Cow<'a, str>>) -> Self {
+        self.app_id = Some(app_id.into());
+        self
+    }
+
+    /// Filter logs generated by this user’s actions. Defaults to all logs.
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Filter logs with this change type. Defaults to all logs.
+    pub fn change_type(mut self, change_type: impl Into<Cow<'a, str>>) -> Self {
+        self.change_type = Some(change_type.into());
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> IntegrationLogsRequest<'static> {
+        IntegrationLogsRequest {
+            service_id: self.service_id.map(|service_id| Cow::Owned(service_id.into_owned())),
+            app_id: self.app_id.map(|app_id| Cow::Owned(app_id.into_owned())),
+            user: self.user.map(|user| Cow::Owned(user.into_owned())),
+            change_type: self.change_type.map(|change_type| Cow::Owned(change_type.into_owned())),
+            count: self.count,
+            page: self.page,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IntegrationLogsResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub logs: Option<Vec<IntegrationLogsResponseLog>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub paging: Option<::Paging>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IntegrationLogsResponseLog {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub app_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub change_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub service_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub service_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user_name: Option<String>,
 }
 
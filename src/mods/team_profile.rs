@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,9 +27,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.visibility.map(
-            |visibility| ("visibility", visibility)
-        ),
+        request.visibility.as_ref().map(|visibility| ("visibility", &visibility[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("team.profile.get");
@@ -40,35 +40,62 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct GetRequest<'a> {
     /// Filter by visibility.
-    pub visibility: Option<&'a str>,
+    pub visibility: Option<Cow<'a, str>>,
+}
+
+impl<'a> GetRequest<'a> {
+    /// Filter by visibility.
+    pub fn visibility(mut self, visibility: impl Into<Cow<'a, str>>) -> Self {
+        self.visibility = Some(visibility.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> GetRequest<'static> {
+        GetRequest {
+            visibility: self.visibility.map(|visibility| Cow::Owned(visibility.into_owned())),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub profile: Option<GetResponseProfile>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponseProfile {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<Vec<GetResponseProfileField>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponseProfileField {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ordering: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub possible_values: Option<Vec<String>>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
 }
 
@@ -1,6 +1,8 @@
 //! Get info on your team's User Groups.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -26,12 +28,10 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("name", request.name)),
-        request.handle.map(|handle| ("handle", handle)),
-        request.description.map(|description| {
-            ("description", description)
-        }),
-        request.channels.map(|channels| ("channels", channels)),
+        Some(("name", &request.name[..])),
+        request.handle.as_ref().map(|handle| ("handle", &handle[..])),
+        request.description.as_ref().map(|description| ("description", &description[..])),
+        request.channels.as_ref().map(|channels| ("channels", &channels[..])),
         request.include_count.map(|include_count| {
             ("include_count", if include_count { "1" } else { "0" })
         }),
@@ -47,25 +47,76 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CreateRequest<'a> {
     /// A name for the User Group. Must be unique among User Groups.
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// A mention handle. Must be unique among channels, users and User Groups.
-    pub handle: Option<&'a str>,
+    pub handle: Option<Cow<'a, str>>,
     /// A short description of the User Group.
-    pub description: Option<&'a str>,
+    pub description: Option<Cow<'a, str>>,
     /// A comma separated string of encoded channel IDs for which the User Group uses as a default.
-    pub channels: Option<&'a str>,
+    pub channels: Option<Cow<'a, str>>,
     /// Include the number of users in each User Group.
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> CreateRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        CreateRequest {
+            name: name.into(),
+            handle: None,
+            description: None,
+            channels: None,
+            include_count: None,
+        }
+    }
+
+    /// A mention handle. Must be unique among channels, users and User Groups.
+    pub fn handle(mut self, handle: impl Into<Cow<'a, str>>) -> Self {
+        self.handle = Some(handle.into());
+        self
+    }
+
+    /// A short description of the User Group.
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// A comma separated string of encoded channel IDs for which the User Group uses as a default.
+    pub fn channels(mut self, channels: impl Into<Cow<'a, str>>) -> Self {
+        self.channels = Some(channels.into());
+        self
+    }
+
+    /// Include the number of users in each User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> CreateRequest<'static> {
+        CreateRequest {
+            name: Cow::Owned(self.name.into_owned()),
+            handle: self.handle.map(|handle| Cow::Owned(handle.into_owned())),
+            description: self.description.map(|description| Cow::Owned(description.into_owned())),
+            channels: self.channels.map(|channels| Cow::Owned(channels.into_owned())),
+            include_count: self.include_count,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub usergroup: Option<::Usergroup>,
 }
 
@@ -208,7 +259,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("usergroup", request.usergroup)),
+        Some(("usergroup", &request.usergroup[..])),
         request.include_count.map(|include_count| {
             ("include_count", if include_count { "1" } else { "0" })
         }),
@@ -226,19 +277,46 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DisableRequest<'a> {
     /// The encoded ID of the User Group to disable.
-    pub usergroup: &'a str,
+    pub usergroup: Cow<'a, str>,
     /// Include the number of users in the User Group.
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> DisableRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(usergroup: impl Into<Cow<'a, str>>) -> Self {
+        DisableRequest {
+            usergroup: usergroup.into(),
+            include_count: None,
+        }
+    }
+
+    /// Include the number of users in the User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> DisableRequest<'static> {
+        DisableRequest {
+            usergroup: Cow::Owned(self.usergroup.into_owned()),
+            include_count: self.include_count,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DisableResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub usergroup: Option<::Usergroup>,
 }
 
@@ -381,7 +459,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("usergroup", request.usergroup)),
+        Some(("usergroup", &request.usergroup[..])),
         request.include_count.map(|include_count| {
             ("include_count", if include_count { "1" } else { "0" })
         }),
@@ -397,19 +475,46 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EnableRequest<'a> {
     /// The encoded ID of the User Group to enable.
-    pub usergroup: &'a str,
+    pub usergroup: Cow<'a, str>,
     /// Include the number of users in the User Group.
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> EnableRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(usergroup: impl Into<Cow<'a, str>>) -> Self {
+        EnableRequest {
+            usergroup: usergroup.into(),
+            include_count: None,
+        }
+    }
+
+    /// Include the number of users in the User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> EnableRequest<'static> {
+        EnableRequest {
+            usergroup: Cow::Owned(self.usergroup.into_owned()),
+            include_count: self.include_count,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EnableResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub usergroup: Option<::Usergroup>,
 }
 
@@ -573,7 +678,7 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct ListRequest {
     /// Include disabled User Groups.
     pub include_disabled: Option<bool>,
@@ -583,11 +688,33 @@ pub struct ListRequest {
     pub include_users: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl ListRequest {
+    /// Include disabled User Groups.
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.include_disabled = Some(include_disabled);
+        self
+    }
+
+    /// Include the number of users in each User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    /// Include the list of users for each User Group.
+    pub fn include_users(mut self, include_users: bool) -> Self {
+        self.include_users = Some(include_users);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub usergroups: Option<Vec<::Usergroup>>,
 }
 
@@ -730,13 +857,11 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("usergroup", request.usergroup)),
-        request.name.map(|name| ("name", name)),
-        request.handle.map(|handle| ("handle", handle)),
-        request.description.map(|description| {
-            ("description", description)
-        }),
-        request.channels.map(|channels| ("channels", channels)),
+        Some(("usergroup", &request.usergroup[..])),
+        request.name.as_ref().map(|name| ("name", &name[..])),
+        request.handle.as_ref().map(|handle| ("handle", &handle[..])),
+        request.description.as_ref().map(|description| ("description", &description[..])),
+        request.channels.as_ref().map(|channels| ("channels", &channels[..])),
         request.include_count.map(|include_count| {
             ("include_count", if include_count { "1" } else { "0" })
         }),
@@ -752,27 +877,86 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct UpdateRequest<'a> {
     /// The encoded ID of the User Group to update.
-    pub usergroup: &'a str,
+    pub usergroup: Cow<'a, str>,
     /// A name for the User Group. Must be unique among User Groups.
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
     /// A mention handle. Must be unique among channels, users and User Groups.
-    pub handle: Option<&'a str>,
+    pub handle: Option<Cow<'a, str>>,
     /// A short description of the User Group.
-    pub description: Option<&'a str>,
+    pub description: Option<Cow<'a, str>>,
     /// A comma separated string of encoded channel IDs for which the User Group uses as a default.
-    pub channels: Option<&'a str>,
+    pub channels: Option<Cow<'a, str>>,
     /// Include the number of users in the User Group.
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> UpdateRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(usergroup: impl Into<Cow<'a, str>>) -> Self {
+        UpdateRequest {
+            usergroup: usergroup.into(),
+            name: None,
+            handle: None,
+            description: None,
+            channels: None,
+            include_count: None,
+        }
+    }
+
+    /// A name for the User Group. Must be unique among User Groups.
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// A mention handle. Must be unique among channels, users and User Groups.
+    pub fn handle(mut self, handle: impl Into<Cow<'a, str>>) -> Self {
+        self.handle = Some(handle.into());
+        self
+    }
+
+    /// A short description of the User Group.
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// A comma separated string of encoded channel IDs for which the User Group uses as a default.
+    pub fn channels(mut self, channels: impl Into<Cow<'a, str>>) -> Self {
+        self.channels = Some(channels.into());
+        self
+    }
+
+    /// Include the number of users in the User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> UpdateRequest<'static> {
+        UpdateRequest {
+            usergroup: Cow::Owned(self.usergroup.into_owned()),
+            name: self.name.map(|name| Cow::Owned(name.into_owned())),
+            handle: self.handle.map(|handle| Cow::Owned(handle.into_owned())),
+            description: self.description.map(|description| Cow::Owned(description.into_owned())),
+            channels: self.channels.map(|channels| Cow::Owned(channels.into_owned())),
+            include_count: self.include_count,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub usergroup: Option<::Usergroup>,
 }
 
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,7 +27,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("usergroup", request.usergroup)),
+        Some(("usergroup", &request.usergroup[..])),
         request.include_disabled.map(|include_disabled| {
             ("include_disabled", if include_disabled { "1" } else { "0" })
         }),
@@ -41,19 +43,46 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ListRequest<'a> {
     /// The encoded ID of the User Group to update.
-    pub usergroup: &'a str,
+    pub usergroup: Cow<'a, str>,
     /// Allow results that involve disabled User Groups.
     pub include_disabled: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> ListRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(usergroup: impl Into<Cow<'a, str>>) -> Self {
+        ListRequest {
+            usergroup: usergroup.into(),
+            include_disabled: None,
+        }
+    }
+
+    /// Allow results that involve disabled User Groups.
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.include_disabled = Some(include_disabled);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> ListRequest<'static> {
+        ListRequest {
+            usergroup: Cow::Owned(self.usergroup.into_owned()),
+            include_disabled: self.include_disabled,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub users: Option<Vec<String>>,
 }
 
@@ -196,8 +225,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("usergroup", request.usergroup)),
-        Some(("users", request.users)),
+        Some(("usergroup", &request.usergroup[..])),
+        Some(("users", &request.users[..])),
         request.include_count.map(|include_count| {
             ("include_count", if include_count { "1" } else { "0" })
         }),
@@ -213,21 +242,50 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct UpdateRequest<'a> {
     /// The encoded ID of the User Group to update.
-    pub usergroup: &'a str,
+    pub usergroup: Cow<'a, str>,
     /// A comma separated string of encoded user IDs that represent the entire list of users for the User Group.
-    pub users: &'a str,
+    pub users: Cow<'a, str>,
     /// Include the number of users in the User Group.
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> UpdateRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(usergroup: impl Into<Cow<'a, str>>, users: impl Into<Cow<'a, str>>) -> Self {
+        UpdateRequest {
+            usergroup: usergroup.into(),
+            users: users.into(),
+            include_count: None,
+        }
+    }
+
+    /// Include the number of users in the User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> UpdateRequest<'static> {
+        UpdateRequest {
+            usergroup: Cow::Owned(self.usergroup.into_owned()),
+            users: Cow::Owned(self.users.into_owned()),
+            include_count: self.include_count,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub usergroup: Option<::Usergroup>,
 }
 
@@ -1,6 +1,8 @@
 //! Get info on members of your Slack team.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -34,8 +36,9 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeletePhotoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -174,7 +177,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("user", request.user))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("user", &request.user[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("users.getPresence");
     client
@@ -187,17 +193,36 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GetPresenceRequest<'a> {
     /// User to get presence info on. Defaults to the authed user.
-    pub user: &'a str,
+    pub user: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> GetPresenceRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(user: impl Into<Cow<'a, str>>) -> Self {
+        GetPresenceRequest {
+            user: user.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> GetPresenceRequest<'static> {
+        GetPresenceRequest {
+            user: Cow::Owned(self.user.into_owned()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetPresenceResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub presence: Option<String>,
 }
 
@@ -336,12 +361,15 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IdentityResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<::Team>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<::User>,
 }
 
@@ -476,7 +504,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("user", request.user))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("user", &request.user[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("users.info");
     client
@@ -488,17 +519,36 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct InfoRequest<'a> {
     /// User to get info on
-    pub user: &'a str,
+    pub user: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> InfoRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(user: impl Into<Cow<'a, str>>) -> Self {
+        InfoRequest {
+            user: user.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> InfoRequest<'static> {
+        InfoRequest {
+            user: Cow::Owned(self.user.into_owned()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<::User>,
 }
 
@@ -638,12 +688,12 @@ pub fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let limit = request.limit.map(|limit| limit.to_string());
     let params = vec![
         Some(("token", token)),
-        request.presence.map(|presence| {
-            ("presence", if presence { "1" } else { "0" })
-        }),
+        request.presence.map(|presence| ("presence", if presence { "1" } else { "0" })),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("users.list");
@@ -656,18 +706,80 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
-pub struct ListRequest {
+/// Like [`list`], but returns an iterator over every item, requesting further pages as
+/// needed.
+///
+/// [`list`]: fn.list.html
+pub fn list_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &ListRequest<'a>,
+) -> ::paging::Items<'a, ::User, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut request = request.clone();
+    ::paging::Items::new(move |next| {
+        if let Some(next) = next {
+            request.cursor = next.cursor().map(|cursor| Cow::Owned(cursor.to_owned()));
+        }
+        list(client, token, &request).map(|response| {
+            let next = response.response_metadata.as_ref().and_then(::paging::next_cursor);
+            ::paging::Page { items: response.members.unwrap_or_default(), next }
+        })
+    })
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct ListRequest<'a> {
     /// Whether to include presence data in the output
     pub presence: Option<bool>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection. See pagination for more detail.
+    pub cursor: Option<Cow<'a, str>>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
+}
+
+impl<'a> ListRequest<'a> {
+    /// Whether to include presence data in the output
+    pub fn presence(mut self, presence: bool) -> Self {
+        self.presence = Some(presence);
+        self
+    }
+
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection. See pagination for more detail.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> ListRequest<'static> {
+        ListRequest {
+            presence: self.presence,
+            cursor: self.cursor.map(|cursor| Cow::Owned(cursor.into_owned())),
+            limit: self.limit,
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub members: Option<Vec<::User>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_metadata: Option<::ResponseMetadata>,
 }
 
 
@@ -704,6 +816,8 @@ pub enum ListError<E: Error> {
     TeamAddedToOrg,
     /// The method was called via a POST request, but the POST data was either missing or truncated.
     RequestTimeout,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
     /// The response was not parseable as the expected object
     MalformedResponse(serde_json::error::Error),
     /// The response returned an error that was unknown to the library
@@ -726,6 +840,7 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
             "missing_post_type" => ListError::MissingPostType,
             "team_added_to_org" => ListError::TeamAddedToOrg,
             "request_timeout" => ListError::RequestTimeout,
+            "invalid_cursor" => ListError::InvalidCursor,
             _ => ListError::Unknown(s.to_owned()),
         }
     }
@@ -769,6 +884,9 @@ impl<E: Error> Error for ListError<E> {
             ListError::RequestTimeout => {
                 "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
             }
+            ListError::InvalidCursor => {
+                "invalid_cursor: Value passed for cursor was not valid or is no longer valid."
+            }
             ListError::MalformedResponse(ref e) => e.description(),
             ListError::Unknown(ref s) => s,
             ListError::Client(ref inner) => inner.description(),
@@ -805,8 +923,9 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetActiveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -940,7 +1059,10 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("presence", request.presence))];
+    let params = vec![
+        Some(("token", token)),
+        Some(("presence", &request.presence[..])),
+    ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("users.setPresence");
     client
@@ -953,14 +1075,32 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SetPresenceRequest<'a> {
     /// Either auto or away
-    pub presence: &'a str,
+    pub presence: Cow<'a, str>,
+}
+
+impl<'a> SetPresenceRequest<'a> {
+    /// Creates a request with all required parameters set.
+    pub fn new(presence: impl Into<Cow<'a, str>>) -> Self {
+        SetPresenceRequest {
+            presence: presence.into(),
+        }
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> SetPresenceRequest<'static> {
+        SetPresenceRequest {
+            presence: Cow::Owned(self.presence.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPresenceResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,7 +27,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.user.map(|user| ("user", user)),
+        request.user.as_ref().map(|user| ("user", &user[..])),
         request.include_labels.map(|include_labels| {
             ("include_labels", if include_labels { "1" } else { "0" })
         }),
@@ -41,19 +43,44 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct GetRequest<'a> {
     /// User to retrieve profile info for
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
     /// Include labels for each ID in custom profile fields
     pub include_labels: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> GetRequest<'a> {
+    /// User to retrieve profile info for
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Include labels for each ID in custom profile fields
+    pub fn include_labels(mut self, include_labels: bool) -> Self {
+        self.include_labels = Some(include_labels);
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> GetRequest<'static> {
+        GetRequest {
+            user: self.user.map(|user| Cow::Owned(user.into_owned())),
+            include_labels: self.include_labels,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub profile: Option<::UserProfile>,
 }
 
@@ -194,10 +221,10 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.user.map(|user| ("user", user)),
-        request.profile.map(|profile| ("profile", profile)),
-        request.name.map(|name| ("name", name)),
-        request.value.map(|value| ("value", value)),
+        request.user.as_ref().map(|user| ("user", &user[..])),
+        request.profile.as_ref().map(|profile| ("profile", &profile[..])),
+        request.name.as_ref().map(|name| ("name", &name[..])),
+        request.value.as_ref().map(|value| ("value", &value[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("users.profile.set");
@@ -210,23 +237,62 @@ where
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct SetRequest<'a> {
     /// ID of user to change. This argument may only be specified by team admins on paid teams.
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
     /// Collection of key:value pairs presented as a URL-encoded JSON hash.
-    pub profile: Option<&'a str>,
+    pub profile: Option<Cow<'a, str>>,
     /// Name of a single key to set. Usable only if profile is not passed.
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
     /// Value to set a single key to. Usable only if profile is not passed.
-    pub value: Option<&'a str>,
+    pub value: Option<Cow<'a, str>>,
+}
+
+impl<'a> SetRequest<'a> {
+    /// ID of user to change. This argument may only be specified by team admins on paid teams.
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Collection of key:value pairs presented as a URL-encoded JSON hash.
+    pub fn profile(mut self, profile: impl Into<Cow<'a, str>>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    /// Name of a single key to set. Usable only if profile is not passed.
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Value to set a single key to. Usable only if profile is not passed.
+    pub fn value(mut self, value: impl Into<Cow<'a, str>>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Converts this request into one that owns all of its data, so it can be stored or sent
+    /// across threads.
+    pub fn into_owned(self) -> SetRequest<'static> {
+        SetRequest {
+            user: self.user.map(|user| Cow::Owned(user.into_owned())),
+            profile: self.profile.map(|profile| Cow::Owned(profile.into_owned())),
+            name: self.name.map(|name| Cow::Owned(name.into_owned())),
+            value: self.value.map(|value| Cow::Owned(value.into_owned())),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub profile: Option<::UserProfile>,
 }
 
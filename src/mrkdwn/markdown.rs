@@ -0,0 +1,376 @@
+//! Converting CommonMark to mrkdwn.
+
+use pulldown_cmark::{Alignment, Event, Options, Parser, Tag};
+
+use blocks::{Block, DividerBlock, HeaderBlock, SectionBlock, Text};
+use super::{escape, escape_label, escape_url, split};
+
+/// The most characters Slack shows in the text of a section block.
+const SECTION_TEXT_LIMIT: usize = 3000;
+/// The most characters Slack shows in a header block.
+const HEADER_TEXT_LIMIT: usize = 150;
+
+/// Converts CommonMark (with GitHub's tables, strikethrough and task lists) to mrkdwn.
+///
+/// Headings become bold lines, lists are indented with `•` or their numbers, and tables are laid
+/// out in a code block, since mrkdwn has none of these.
+pub fn from_markdown(markdown: &str) -> String {
+    join(&convert(markdown))
+}
+
+/// Converts CommonMark to mrkdwn, as with [`from_markdown`](fn.from_markdown.html), split into
+/// messages of at most `max_chars` characters.
+///
+/// The mrkdwn is divided with [`split`](fn.split.html), so messages end between paragraphs,
+/// lists and other blocks where possible, code blocks are closed and reopened around a split, and
+/// links are never cut.
+pub fn from_markdown_split(markdown: &str, max_chars: usize) -> Vec<String> {
+    split(&from_markdown(markdown), max_chars)
+}
+
+/// Converts CommonMark to Block Kit blocks.
+///
+/// Top-level headings become header blocks and thematic breaks become dividers. Everything else is
+/// converted to mrkdwn sections.
+pub fn blocks_from_markdown(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut pending = Vec::new();
+    for part in convert(markdown) {
+        match part.kind {
+            Kind::Heading(ref text) => {
+                flush_sections(&mut pending, &mut blocks);
+                blocks.push(HeaderBlock::new(truncate(text, HEADER_TEXT_LIMIT)).into());
+            }
+            Kind::Rule => {
+                flush_sections(&mut pending, &mut blocks);
+                blocks.push(DividerBlock::new().into());
+            }
+            _ => pending.push(part),
+        }
+    }
+    flush_sections(&mut pending, &mut blocks);
+    blocks
+}
+
+fn flush_sections(parts: &mut Vec<Part>, blocks: &mut Vec<Block>) {
+    if !parts.is_empty() {
+        for text in split(&join(parts), SECTION_TEXT_LIMIT) {
+            blocks.push(SectionBlock::new(Text::markdown(text)).into());
+        }
+    }
+    parts.clear();
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_owned();
+    }
+    let mut truncated = text.chars().take(max_chars - 1).collect::<String>();
+    truncated.push('…');
+    truncated
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Kind {
+    /// A heading, with its plain text.
+    Heading(String),
+    Rule,
+    Other,
+}
+
+/// A converted top-level block.
+#[derive(Clone, Debug)]
+struct Part {
+    kind: Kind,
+    text: String,
+}
+
+fn join(parts: &[Part]) -> String {
+    parts.iter().map(|p| &p.text[..]).collect::<Vec<_>>().join("\n\n")
+}
+
+/// Walks the CommonMark events, writing mrkdwn and cutting it into top-level parts.
+fn convert(markdown: &str) -> Vec<Part> {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut converter = Converter::default();
+    for event in Parser::new_ext(markdown, options) {
+        converter.event(event);
+    }
+    converter.parts
+}
+
+#[derive(Default)]
+struct Converter {
+    parts: Vec<Part>,
+    out: String,
+    /// Open lists, with the number of the next item for ordered lists.
+    lists: Vec<Option<u64>>,
+    /// How many lists, quotes and tables are open.
+    depth: usize,
+    /// Where the text of each open heading, link, quote or table cell starts in `out`.
+    marks: Vec<usize>,
+    /// The destinations of open links and images.
+    links: Vec<String>,
+    in_code_block: bool,
+    table: Option<Table>,
+}
+
+#[derive(Default)]
+struct Table {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Converter {
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                let text = escape(&text).into_owned();
+                self.out.push_str(&text);
+            }
+            Event::Code(code) => {
+                let code = escape(&code).into_owned();
+                self.out.push('`');
+                self.out.push_str(&code);
+                self.out.push('`');
+            }
+            Event::Html(html) => {
+                let html = escape(&html).into_owned();
+                self.out.push_str(&html);
+            }
+            Event::FootnoteReference(name) => {
+                self.out.push_str(&format!("[{}]", escape(&name)));
+            }
+            Event::SoftBreak => self.out.push(' '),
+            Event::HardBreak => self.out.push('\n'),
+            Event::Rule => {
+                self.out.push_str("———");
+                self.finish(Kind::Rule);
+            }
+            Event::TaskListMarker(checked) => self.out.push_str(if checked { "☑ " } else { "☐ " }),
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => {
+                if !self.lists.is_empty() && !self.at_line_start() && !self.out.ends_with(' ') {
+                    self.out.push('\n');
+                }
+            }
+            Tag::Heading(..) => self.marks.push(self.out.len()),
+            Tag::BlockQuote => {
+                self.depth += 1;
+                self.marks.push(self.out.len());
+            }
+            Tag::CodeBlock(_) => {
+                self.newline();
+                self.out.push_str("```\n");
+                self.in_code_block = true;
+            }
+            Tag::List(start) => {
+                self.depth += 1;
+                self.newline();
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.newline();
+                let indent = "    ".repeat(self.lists.len().saturating_sub(1));
+                self.out.push_str(&indent);
+                match self.lists.last_mut() {
+                    Some(&mut Some(ref mut number)) => {
+                        self.out.push_str(&format!("{}. ", number));
+                        *number += 1;
+                    }
+                    _ => self.out.push_str("• "),
+                }
+            }
+            Tag::FootnoteDefinition(name) => {
+                self.out.push_str(&format!("[{}]: ", escape(&name)));
+            }
+            Tag::Table(alignments) => {
+                self.depth += 1;
+                self.table = Some(Table { alignments, rows: Vec::new() });
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(ref mut table) = self.table {
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::TableCell => self.marks.push(self.out.len()),
+            Tag::Emphasis => self.out.push('_'),
+            Tag::Strong => self.out.push('*'),
+            Tag::Strikethrough => self.out.push('~'),
+            Tag::Link(_, dest, _) | Tag::Image(_, dest, _) => {
+                self.links.push(dest.into_string());
+                self.marks.push(self.out.len());
+            }
+        }
+    }
+
+    fn end(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph | Tag::FootnoteDefinition(_) => self.finish(Kind::Other),
+            Tag::Heading(..) => {
+                let start = self.marks.pop().unwrap_or(0);
+                let text = self.out.split_off(start);
+                self.out.push('*');
+                self.out.push_str(text.trim());
+                self.out.push('*');
+                self.finish(Kind::Heading(super::unescape(text.trim()).into_owned()));
+            }
+            Tag::BlockQuote => {
+                self.depth -= 1;
+                let start = self.marks.pop().unwrap_or(0);
+                let quoted = self.out.split_off(start);
+                let quoted = quoted.trim_end_matches('\n').lines().map(|line| format!("> {}", line));
+                self.out.push_str(&quoted.collect::<Vec<_>>().join("\n"));
+                self.finish(Kind::Other);
+            }
+            Tag::CodeBlock(_) => {
+                self.newline();
+                self.out.push_str("```");
+                self.in_code_block = false;
+                self.finish(Kind::Other);
+            }
+            Tag::List(_) => {
+                self.depth -= 1;
+                self.lists.pop();
+                self.finish(Kind::Other);
+            }
+            Tag::Item => {}
+            Tag::Table(_) => {
+                self.depth -= 1;
+                if let Some(table) = self.table.take() {
+                    self.out.push_str(&table.render());
+                }
+                self.finish(Kind::Other);
+            }
+            Tag::TableHead | Tag::TableRow => {}
+            Tag::TableCell => {
+                let start = self.marks.pop().unwrap_or(0);
+                let cell = self.out.split_off(start);
+                if let Some(row) = self.table.as_mut().and_then(|table| table.rows.last_mut()) {
+                    row.push(cell.trim().to_owned());
+                }
+            }
+            Tag::Emphasis => self.out.push('_'),
+            Tag::Strong => self.out.push('*'),
+            Tag::Strikethrough => self.out.push('~'),
+            Tag::Link(..) | Tag::Image(..) => {
+                let start = self.marks.pop().unwrap_or(0);
+                let label = self.out.split_off(start);
+                let dest = self.links.pop().unwrap_or_default();
+                let url = escape_url(&dest);
+                if label.is_empty() || label == url {
+                    self.out.push_str(&format!("<{}>", url));
+                } else {
+                    let label = escape_label(&super::unescape(&label));
+                    self.out.push_str(&format!("<{}|{}>", url, label));
+                }
+            }
+        }
+    }
+
+    fn at_line_start(&self) -> bool {
+        self.out.is_empty() || self.out.ends_with('\n')
+    }
+
+    fn newline(&mut self) {
+        if !self.at_line_start() {
+            self.out.push('\n');
+        }
+    }
+
+    /// Ends the current top-level part, unless a container is still open.
+    fn finish(&mut self, kind: Kind) {
+        if self.depth > 0 || self.in_code_block {
+            return;
+        }
+        let text = self.out.trim_end_matches('\n').to_owned();
+        self.out.clear();
+        if !text.is_empty() {
+            self.parts.push(Part { kind, text });
+        }
+    }
+}
+
+impl Table {
+    /// Lays the table out in a code block, with columns padded to line up.
+    fn render(&self) -> String {
+        let columns = self.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let widths = (0..columns)
+            .map(|c| {
+                self.rows.iter().filter_map(|row| row.get(c)).map(|cell| cell.chars().count()).max().unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+
+        let mut lines = Vec::new();
+        for (i, row) in self.rows.iter().enumerate() {
+            let cells = widths.iter().enumerate().map(|(c, &width)| {
+                let cell = row.get(c).map_or("", |cell| &cell[..]);
+                let padding = width - cell.chars().count();
+                match self.alignments.get(c) {
+                    Some(&Alignment::Right) => format!("{}{}", " ".repeat(padding), cell),
+                    Some(&Alignment::Center) => {
+                        format!("{}{}{}", " ".repeat(padding / 2), cell, " ".repeat(padding - padding / 2))
+                    }
+                    _ => format!("{}{}", cell, " ".repeat(padding)),
+                }
+            });
+            lines.push(cells.collect::<Vec<_>>().join(" | ").trim_end().to_owned());
+            if i == 0 {
+                lines.push(widths.iter().map(|&w| "-".repeat(w)).collect::<Vec<_>>().join("-+-"));
+            }
+        }
+        format!("```\n{}\n```", lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_markdown() {
+        let markdown = "# Release 1.2\n\nSome **bold**, _em_ and ~~gone~~ text with [a link](https://x.test/?a=1&b=2) \
+                        and `code <T>`.\n\n- one\n- two\n  1. nested\n  2. more\n- [x] done\n\n> quoted\n> text\n\n\
+                        ```rust\nfn main() {}\n```\n\n| Name | Count |\n|------|------:|\n| a | 1 |\n| long | 10 |\n\n---";
+
+        assert_eq!(
+            "*Release 1.2*\n\n\
+             Some *bold*, _em_ and ~gone~ text with <https://x.test/?a=1&amp;b=2|a link> and `code &lt;T&gt;`.\n\n\
+             • one\n• two\n    1. nested\n    2. more\n• ☑ done\n\n\
+             > quoted text\n\n\
+             ```\nfn main() {}\n```\n\n\
+             ```\nName | Count\n-----+------\na    |     1\nlong |    10\n```\n\n\
+             ———",
+            from_markdown(markdown)
+        );
+    }
+
+    #[test]
+    fn test_from_markdown_split() {
+        let markdown = "First paragraph.\n\nSecond paragraph.\n\n```\nline 1\nline 2\nline 3\n```";
+        assert_eq!(
+            vec!["First paragraph.", "Second paragraph.", "```\nline 1\nline 2\n```", "```\nline 3\n```"],
+            from_markdown_split(markdown, 22)
+        );
+
+        let links = from_markdown_split("Words [one two three four five six](https://x.test/a)", 50);
+        assert_eq!(vec!["Words", "<https://x.test/a|one two three four five six>"], links);
+    }
+
+    #[test]
+    fn test_blocks_from_markdown() {
+        let blocks = blocks_from_markdown("# Title\n\nBody *text*\n\n***\n\nMore");
+        let types = blocks.iter().map(Block::ty).collect::<Vec<_>>();
+        assert_eq!(vec!["header", "section", "divider", "section"], types);
+        match blocks[1] {
+            Block::Section(ref section) => assert_eq!("Body _text_", section.text.as_ref().unwrap().text()),
+            ref other => panic!("unexpected {:?}", other),
+        }
+    }
+}
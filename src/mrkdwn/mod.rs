@@ -0,0 +1,317 @@
+//! Composing message text in Slack's [`mrkdwn`](https://api.slack.com/reference/surfaces/formatting)
+//! markup.
+//!
+//! Slack treats `&`, `<` and `>` as control characters, so any text which didn't come from Slack
+//! must be escaped before it is sent. The functions in this module produce mentions and links with
+//! their arguments escaped, and [`Builder`] joins them with escaped plain text:
+//!
+//! ```
+//! use slack_api::mrkdwn::Builder;
+//!
+//! let text = Builder::new()
+//!     .user("U024BE7LH")
+//!     .text(" deployed <b>v2</b> & ")
+//!     .link_with_label("https://example.com/changes", "the changes")
+//!     .build();
+//! assert_eq!(
+//!     "<@U024BE7LH> deployed &lt;b&gt;v2&lt;/b&gt; &amp; <https://example.com/changes|the changes>",
+//!     text
+//! );
+//! ```
+//!
+//! Text received from Slack can be turned back into a tree of mentions, links and formatting
+//! with [`parse`], and text too long for one message can be divided with [`split`]. With the
+//! `markdown` feature enabled, CommonMark can be converted to mrkdwn with `from_markdown`.
+//!
+//! [`Builder`]: struct.Builder.html
+//! [`parse`]: fn.parse.html
+//! [`split`]: fn.split.html
+
+use std::borrow::Cow;
+
+mod parse;
+mod split;
+pub use self::parse::{parse, unescape, walk, Node, NodeKind, SpecialMention};
+pub use self::split::{split, MAX_MESSAGE_CHARS};
+#[cfg(feature = "markdown")]
+mod markdown;
+#[cfg(feature = "markdown")]
+pub use self::markdown::{blocks_from_markdown, from_markdown, from_markdown_split};
+
+/// Escapes `&`, `<` and `>` so `text` is shown as written.
+pub fn escape<'a>(text: &'a str) -> Cow<'a, str> {
+    if !text.contains(&['&', '<', '>'][..]) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut escaped = String::with_capacity(text.len() + 8);
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// Escapes the label of a `<…|label>` token, which additionally can't contain `|`.
+fn escape_label(label: &str) -> String {
+    escape(label).replace('|', "\u{2758}")
+}
+
+/// Escapes a URL so it can be placed in a `<…>` token.
+fn escape_url(url: &str) -> String {
+    escape(url).replace('|', "%7C")
+}
+
+/// Mentions a user, such as `<@U024BE7LH>`.
+pub fn user(id: &str) -> String {
+    format!("<@{}>", escape(id))
+}
+
+/// Links to a channel, such as `<#C024BE7LR>`.
+pub fn channel(id: &str) -> String {
+    format!("<#{}>", escape(id))
+}
+
+/// Links to a channel and gives the name to show if it can't be resolved, such as
+/// `<#C024BE7LR|general>`.
+pub fn channel_with_name(id: &str, name: &str) -> String {
+    format!("<#{}|{}>", escape(id), escape_label(name))
+}
+
+/// Mentions a user group, such as `<!subteam^SAZ94GDB8>`.
+pub fn usergroup(id: &str) -> String {
+    format!("<!subteam^{}>", escape(id))
+}
+
+/// Mentions a user group and gives the handle to show if it can't be resolved, such as
+/// `<!subteam^SAZ94GDB8|@ops>`.
+pub fn usergroup_with_handle(id: &str, handle: &str) -> String {
+    format!("<!subteam^{}|{}>", escape(id), escape_label(handle))
+}
+
+/// `<!here>`, which notifies the active members of a channel.
+pub fn here() -> &'static str {
+    "<!here>"
+}
+
+/// `<!channel>`, which notifies every member of a channel.
+pub fn channel_everyone() -> &'static str {
+    "<!channel>"
+}
+
+/// `<!everyone>`, which notifies every member of the workspace in `#general`.
+pub fn everyone() -> &'static str {
+    "<!everyone>"
+}
+
+/// A date shown in the reader's time zone, such as
+/// `<!date^1392734382^{date_short} at {time}|Feb 18, 2014>`.
+///
+/// `format` may contain tokens such as `{date_num}`, `{date_short_pretty}` and `{time}`, and
+/// `fallback` is shown by clients which can't format dates.
+pub fn date(timestamp: i64, format: &str, fallback: &str) -> String {
+    format!("<!date^{}^{}|{}>", timestamp, escape_label(format), escape_label(fallback))
+}
+
+/// A date, as with [`date`](fn.date.html), which also links to `url`.
+pub fn date_with_link(timestamp: i64, format: &str, url: &str, fallback: &str) -> String {
+    format!(
+        "<!date^{}^{}^{}|{}>",
+        timestamp,
+        escape_label(format),
+        escape_url(url).replace('^', "%5E"),
+        escape_label(fallback)
+    )
+}
+
+/// A link showing the URL itself, such as `<https://example.com>`.
+pub fn link(url: &str) -> String {
+    format!("<{}>", escape_url(url))
+}
+
+/// A link with a label, such as `<https://example.com|Example>`.
+pub fn link_with_label(url: &str, label: &str) -> String {
+    format!("<{}|{}>", escape_url(url), escape_label(label))
+}
+
+/// A link which opens a new email to `address`.
+pub fn email(address: &str) -> String {
+    format!("<mailto:{}|{}>", escape_url(address), escape_label(address))
+}
+
+/// `*text*`, escaped.
+pub fn bold(text: &str) -> String {
+    format!("*{}*", escape(text))
+}
+
+/// `_text_`, escaped.
+pub fn italic(text: &str) -> String {
+    format!("_{}_", escape(text))
+}
+
+/// `~text~`, escaped.
+pub fn strike(text: &str) -> String {
+    format!("~{}~", escape(text))
+}
+
+/// `` `text` ``, escaped.
+pub fn code(text: &str) -> String {
+    format!("`{}`", escape(text))
+}
+
+/// A preformatted block, escaped.
+pub fn code_block(text: &str) -> String {
+    format!("```\n{}\n```", escape(text))
+}
+
+/// Quotes every line of `text`, escaped. The `>` marking the quote is left unescaped, since Slack
+/// only treats a literal `>` at the start of a line as a quote.
+pub fn quote(text: &str) -> String {
+    escape(text).lines().map(|line| format!("> {}", line)).collect::<Vec<_>>().join("\n")
+}
+
+/// Joins escaped text, mentions and links into a message.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Builder {
+    buf: String,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Builder::default()
+    }
+
+    /// Appends `text`, escaped.
+    pub fn text(self, text: &str) -> Self {
+        let text = escape(text).into_owned();
+        self.raw(&text)
+    }
+
+    /// Appends `markup` as it is, without escaping.
+    pub fn raw(mut self, markup: &str) -> Self {
+        self.buf.push_str(markup);
+        self
+    }
+
+    /// Appends a line break.
+    pub fn newline(self) -> Self {
+        self.raw("\n")
+    }
+
+    pub fn user(self, id: &str) -> Self {
+        self.raw(&user(id))
+    }
+
+    pub fn channel(self, id: &str) -> Self {
+        self.raw(&channel(id))
+    }
+
+    pub fn channel_with_name(self, id: &str, name: &str) -> Self {
+        self.raw(&channel_with_name(id, name))
+    }
+
+    pub fn usergroup(self, id: &str) -> Self {
+        self.raw(&usergroup(id))
+    }
+
+    pub fn usergroup_with_handle(self, id: &str, handle: &str) -> Self {
+        self.raw(&usergroup_with_handle(id, handle))
+    }
+
+    pub fn here(self) -> Self {
+        self.raw(here())
+    }
+
+    pub fn channel_everyone(self) -> Self {
+        self.raw(channel_everyone())
+    }
+
+    pub fn everyone(self) -> Self {
+        self.raw(everyone())
+    }
+
+    pub fn date(self, timestamp: i64, format: &str, fallback: &str) -> Self {
+        self.raw(&date(timestamp, format, fallback))
+    }
+
+    pub fn date_with_link(self, timestamp: i64, format: &str, url: &str, fallback: &str) -> Self {
+        self.raw(&date_with_link(timestamp, format, url, fallback))
+    }
+
+    pub fn link(self, url: &str) -> Self {
+        self.raw(&link(url))
+    }
+
+    pub fn link_with_label(self, url: &str, label: &str) -> Self {
+        self.raw(&link_with_label(url, label))
+    }
+
+    pub fn email(self, address: &str) -> Self {
+        self.raw(&email(address))
+    }
+
+    pub fn bold(self, text: &str) -> Self {
+        self.raw(&bold(text))
+    }
+
+    pub fn italic(self, text: &str) -> Self {
+        self.raw(&italic(text))
+    }
+
+    pub fn strike(self, text: &str) -> Self {
+        self.raw(&strike(text))
+    }
+
+    pub fn code(self, text: &str) -> Self {
+        self.raw(&code(text))
+    }
+
+    pub fn code_block(self, text: &str) -> Self {
+        self.raw(&code_block(text))
+    }
+
+    pub fn quote(self, text: &str) -> Self {
+        self.raw(&quote(text))
+    }
+
+    /// Returns the message text.
+    pub fn build(self) -> String {
+        self.buf
+    }
+}
+
+impl From<Builder> for String {
+    fn from(builder: Builder) -> String {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!("a &lt;b&gt; &amp;&amp; c", escape("a <b> && c"));
+        assert!(match escape("plain") {
+            Cow::Borrowed(_) => true,
+            Cow::Owned(_) => false,
+        });
+    }
+
+    #[test]
+    fn test_tokens_escape_their_arguments() {
+        assert_eq!("<#C1|a\u{2758}b &lt;c&gt;>", channel_with_name("C1", "a|b <c>"));
+        assert_eq!("<!subteam^S1|@ops>", usergroup_with_handle("S1", "@ops"));
+        assert_eq!("<https://x.test/?a=1&amp;b=%7C|x>", link_with_label("https://x.test/?a=1&b=|", "x"));
+        assert_eq!(
+            "<!date^1392734382^{date_short} at {time}^https://x.test|Feb 18>",
+            date_with_link(1392734382, "{date_short} at {time}", "https://x.test", "Feb 18")
+        );
+        assert_eq!("> a\n> b &amp; c", quote("a\nb & c"));
+    }
+}
@@ -0,0 +1,404 @@
+//! Parsing the markup in message text received from Slack.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// A piece of parsed message text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node {
+    pub kind: NodeKind,
+    /// The byte range of the source text this node was parsed from, including any delimiters.
+    pub span: Range<usize>,
+}
+
+impl Node {
+    /// The nodes nested inside this one, for formatting and quotes.
+    pub fn children(&self) -> &[Node] {
+        match self.kind {
+            NodeKind::Bold(ref nodes)
+            | NodeKind::Italic(ref nodes)
+            | NodeKind::Strike(ref nodes)
+            | NodeKind::Quote(ref nodes) => nodes,
+            _ => &[],
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum NodeKind {
+    /// Text without markup, unescaped.
+    Text(String),
+    /// `<@U024BE7LH>`, optionally with the name to show (`<@U024BE7LH|bob>`).
+    User { id: String, label: Option<String> },
+    /// `<#C024BE7LR|general>`.
+    Channel { id: String, name: Option<String> },
+    /// `<!subteam^SAZ94GDB8|@ops>`.
+    Usergroup { id: String, handle: Option<String> },
+    /// `<!here>`, `<!channel>` or `<!everyone>`.
+    Special(SpecialMention),
+    /// `<!date^1392734382^{date_short}^https://example.com|Feb 18>`.
+    Date {
+        timestamp: i64,
+        format: String,
+        link: Option<String>,
+        fallback: String,
+    },
+    /// `<https://example.com|Example>`, or an automatically linked URL or email address.
+    Link { url: String, label: Option<String> },
+    /// `*bold*`
+    Bold(Vec<Node>),
+    /// `_italic_`
+    Italic(Vec<Node>),
+    /// `~strike~`
+    Strike(Vec<Node>),
+    /// `` `code` ``, unescaped and without any further markup.
+    Code(String),
+    /// A block fenced with ` ``` `, unescaped and without any further markup.
+    CodeBlock(String),
+    /// Lines starting with `>`, or the rest of the message after `>>>`.
+    Quote(Vec<Node>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SpecialMention {
+    Here,
+    Channel,
+    Everyone,
+    /// A special command this parser doesn't know.
+    Other(String),
+}
+
+/// Replaces the `&amp;`, `&lt;` and `&gt;` escapes Slack uses with the characters they represent.
+pub fn unescape<'a>(text: &'a str) -> Cow<'a, str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(i) = rest.find('&') {
+        unescaped.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let (c, len) = if rest.starts_with("&amp;") {
+            ('&', 5)
+        } else if rest.starts_with("&lt;") {
+            ('<', 4)
+        } else if rest.starts_with("&gt;") {
+            ('>', 4)
+        } else {
+            ('&', 1)
+        };
+        unescaped.push(c);
+        rest = &rest[len..];
+    }
+    unescaped.push_str(rest);
+    Cow::Owned(unescaped)
+}
+
+/// Parses message text into a tree of nodes.
+///
+/// Markup which isn't closed is kept as text, so parsing never fails.
+pub fn parse(text: &str) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut pos = 0;
+    while pos < text.len() {
+        match code_block(text, pos) {
+            Some((span, content)) => {
+                lines(text, pos, span.start, &mut nodes);
+                nodes.push(Node {
+                    kind: NodeKind::CodeBlock(unescape(&text[content]).into_owned()),
+                    span: span.clone(),
+                });
+                pos = span.end;
+            }
+            None => {
+                lines(text, pos, text.len(), &mut nodes);
+                pos = text.len();
+            }
+        }
+    }
+    nodes
+}
+
+/// Calls `f` with every node of `nodes` and their descendants, parents first.
+pub fn walk<'a, F>(nodes: &'a [Node], f: &mut F)
+where
+    F: FnMut(&'a Node),
+{
+    for node in nodes {
+        f(node);
+        walk(node.children(), f);
+    }
+}
+
+/// Finds the next code block at or after `pos`, returning its span and the range of its content.
+fn code_block(text: &str, pos: usize) -> Option<(Range<usize>, Range<usize>)> {
+    let open = pos + text[pos..].find("```")?;
+    let close = open + 3 + text[open + 3..].find("```")?;
+    let mut content = open + 3..close;
+    if text[content.clone()].starts_with('\n') {
+        content.start += 1;
+    }
+    if content.end > content.start && text[content.clone()].ends_with('\n') {
+        content.end -= 1;
+    }
+    Some((open..close + 3, content))
+}
+
+/// Parses the lines in `start..end`, which contains no code blocks, grouping quoted lines.
+fn lines(text: &str, start: usize, end: usize, out: &mut Vec<Node>) {
+    const QUOTE: &str = "&gt;";
+    const QUOTE_REST: &str = "&gt;&gt;&gt;";
+
+    let line_end = |from: usize| text[from..end].find('\n').map_or(end, |i| from + i);
+    let at_line_start = |i: usize| i == 0 || text.as_bytes()[i - 1] == b'\n';
+    let content_start = |from: usize, prefix: &str| {
+        let i = from + prefix.len();
+        if text[i..end].starts_with(' ') {
+            i + 1
+        } else {
+            i
+        }
+    };
+
+    let mut plain = start;
+    let mut line = start;
+    while line < end {
+        if !at_line_start(line) || !text[line..end].starts_with(QUOTE) {
+            line = line_end(line) + 1;
+            continue;
+        }
+
+        inline(text, plain, line, out);
+        if text[line..end].starts_with(QUOTE_REST) {
+            let children = inline_nodes(text, content_start(line, QUOTE_REST), end);
+            out.push(Node { kind: NodeKind::Quote(children), span: line..end });
+            return;
+        }
+
+        let quote_start = line;
+        let mut children = Vec::new();
+        loop {
+            let this_end = line_end(line);
+            inline(text, content_start(line, QUOTE), this_end, &mut children);
+            line = this_end;
+            let next = &text[(line + 1).min(end)..end];
+            if line < end && next.starts_with(QUOTE) && !next.starts_with(QUOTE_REST) {
+                children.push(Node { kind: NodeKind::Text("\n".to_owned()), span: line..line + 1 });
+                line += 1;
+            } else {
+                break;
+            }
+        }
+        out.push(Node { kind: NodeKind::Quote(children), span: quote_start..line });
+        plain = line;
+        line += 1;
+    }
+    inline(text, plain, end, out);
+}
+
+fn inline_nodes(text: &str, start: usize, end: usize) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    inline(text, start, end, &mut nodes);
+    nodes
+}
+
+/// Parses mentions, links, code and formatting in `start..end`.
+fn inline(text: &str, start: usize, end: usize, out: &mut Vec<Node>) {
+    let bytes = text.as_bytes();
+    let mut plain = start;
+    let mut i = start;
+    while i < end {
+        let parsed = match bytes[i] {
+            b'<' => angle(text, i, end),
+            b'`' => code(text, i, end),
+            b'*' | b'_' | b'~' => emphasis(text, start, i, end),
+            _ => None,
+        };
+        match parsed {
+            Some((kind, node_end)) => {
+                push_text(text, plain, i, out);
+                out.push(Node { kind, span: i..node_end });
+                i = node_end;
+                plain = i;
+            }
+            None => i += 1,
+        }
+    }
+    push_text(text, plain, end, out);
+}
+
+fn push_text(text: &str, start: usize, end: usize, out: &mut Vec<Node>) {
+    if start < end {
+        out.push(Node { kind: NodeKind::Text(unescape(&text[start..end]).into_owned()), span: start..end });
+    }
+}
+
+/// Parses a `<…>` token starting at `i`.
+fn angle(text: &str, i: usize, end: usize) -> Option<(NodeKind, usize)> {
+    let close = i + 1 + text[i + 1..end].find(&['>', '<', '\n'][..])?;
+    if text.as_bytes()[close] != b'>' || close == i + 1 {
+        return None;
+    }
+
+    let inner = &text[i + 1..close];
+    let (target, label) = match inner.find('|') {
+        Some(bar) => (&inner[..bar], Some(unescape(&inner[bar + 1..]).into_owned())),
+        None => (inner, None),
+    };
+    let owned = |s: &str| unescape(s).into_owned();
+
+    let kind = if let Some(id) = target.strip_prefix('@') {
+        NodeKind::User { id: owned(id), label }
+    } else if let Some(id) = target.strip_prefix('#') {
+        NodeKind::Channel { id: owned(id), name: label }
+    } else if let Some(id) = target.strip_prefix("!subteam^") {
+        NodeKind::Usergroup { id: owned(id), handle: label }
+    } else if let Some(date) = target.strip_prefix("!date^") {
+        let mut parts = date.splitn(3, '^');
+        let timestamp = parts.next()?.parse().ok()?;
+        let format = owned(parts.next()?);
+        let link = parts.next().map(owned);
+        NodeKind::Date { timestamp, format, link, fallback: label.unwrap_or_default() }
+    } else if let Some(command) = target.strip_prefix('!') {
+        NodeKind::Special(match command {
+            "here" => SpecialMention::Here,
+            "channel" => SpecialMention::Channel,
+            "everyone" => SpecialMention::Everyone,
+            other => SpecialMention::Other(owned(other)),
+        })
+    } else {
+        NodeKind::Link { url: owned(target), label }
+    };
+    Some((kind, close + 1))
+}
+
+/// Parses `` `code` `` starting at `i`.
+fn code(text: &str, i: usize, end: usize) -> Option<(NodeKind, usize)> {
+    let close = i + 1 + text[i + 1..end].find(&['`', '\n'][..])?;
+    if text.as_bytes()[close] != b'`' || close == i + 1 {
+        return None;
+    }
+    Some((NodeKind::Code(unescape(&text[i + 1..close]).into_owned()), close + 1))
+}
+
+/// Parses `*bold*`, `_italic_` or `~strike~` starting at `i`.
+///
+/// Like Slack, markers only count at word boundaries and must hug the text they format.
+fn emphasis(text: &str, start: usize, i: usize, end: usize) -> Option<(NodeKind, usize)> {
+    let marker = text.as_bytes()[i];
+    let is_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    let is_space = |c: Option<char>| c.map_or(true, char::is_whitespace);
+
+    let before = if i > start { text[..i].chars().next_back() } else { None };
+    if is_word(before) || before == Some(marker as char) || is_space(text[i + 1..end].chars().next()) {
+        return None;
+    }
+
+    let bytes = text.as_bytes();
+    let mut j = i + 1;
+    while j < end && bytes[j] != b'\n' {
+        match bytes[j] {
+            b'<' | b'`' => {
+                let skip = if bytes[j] == b'<' { angle(text, j, end) } else { code(text, j, end) };
+                j = skip.map_or(j + 1, |(_, skip_end)| skip_end);
+                continue;
+            }
+            c if c == marker
+                && j > i + 1
+                && !is_space(text[..j].chars().next_back())
+                && !is_word(text[j + 1..].chars().next()) =>
+            {
+                let children = inline_nodes(text, i + 1, j);
+                let kind = match marker {
+                    b'*' => NodeKind::Bold(children),
+                    b'_' => NodeKind::Italic(children),
+                    _ => NodeKind::Strike(children),
+                };
+                return Some((kind, j + 1));
+            }
+            _ => j += 1,
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(nodes: &[Node]) -> Vec<NodeKind> {
+        nodes.iter().map(|n| n.kind.clone()).collect()
+    }
+
+    fn text(s: &str) -> NodeKind {
+        NodeKind::Text(s.to_owned())
+    }
+
+    #[test]
+    fn test_parse_mentions_and_links() {
+        let source = "hi <@U1|bob> &amp; <!here>, see <#C1|general> or <https://x.test/?a=1&amp;b=2|docs &lt;v2&gt;> <!subteam^S1|@ops> <!date^1392734382^{date}|Feb 18>";
+        let nodes = parse(source);
+
+        assert_eq!(
+            vec![
+                text("hi "),
+                NodeKind::User { id: "U1".into(), label: Some("bob".into()) },
+                text(" & "),
+                NodeKind::Special(SpecialMention::Here),
+                text(", see "),
+                NodeKind::Channel { id: "C1".into(), name: Some("general".into()) },
+                text(" or "),
+                NodeKind::Link { url: "https://x.test/?a=1&b=2".into(), label: Some("docs <v2>".into()) },
+                text(" "),
+                NodeKind::Usergroup { id: "S1".into(), handle: Some("@ops".into()) },
+                text(" "),
+                NodeKind::Date { timestamp: 1392734382, format: "{date}".into(), link: None, fallback: "Feb 18".into() },
+            ],
+            kinds(&nodes)
+        );
+        assert_eq!("<@U1|bob>", &source[nodes[1].span.clone()]);
+        assert_eq!(" &amp; ", &source[nodes[2].span.clone()]);
+    }
+
+    #[test]
+    fn test_parse_formatting_code_and_quotes() {
+        let source = "*bold _it_* a*b ~x~ `*no*`\n&gt; quoted *q*\n&gt; two\nafter\n```\nfn &lt;T&gt;\n```";
+        let nodes = parse(source);
+
+        assert_eq!(
+            vec![
+                NodeKind::Bold(vec![
+                    Node { kind: text("bold "), span: 1..6 },
+                    Node { kind: NodeKind::Italic(vec![Node { kind: text("it"), span: 7..9 }]), span: 6..10 },
+                ]),
+                text(" a*b "),
+                NodeKind::Strike(vec![Node { kind: text("x"), span: 17..18 }]),
+                text(" "),
+                NodeKind::Code("*no*".into()),
+                text("\n"),
+            ],
+            kinds(&nodes[..6])
+        );
+
+        let quote = &nodes[6];
+        assert_eq!("&gt; quoted *q*\n&gt; two", &source[quote.span.clone()]);
+        assert_eq!(
+            vec![text("quoted "), NodeKind::Bold(vec![Node { kind: text("q"), span: 40..41 }]), text("\n"), text("two")],
+            kinds(quote.children())
+        );
+        assert_eq!(vec![text("\nafter\n"), NodeKind::CodeBlock("fn <T>".into())], kinds(&nodes[7..]));
+    }
+
+    #[test]
+    fn test_unclosed_markup_is_text() {
+        let nodes = parse("&lt;not closed *nor this `or this");
+        assert_eq!(vec![text("<not closed *nor this `or this")], kinds(&nodes));
+
+        let nodes = parse("intro\n&gt;&gt;&gt; all\nof this");
+        assert_eq!(
+            vec![text("intro\n"), NodeKind::Quote(vec![Node { kind: text("all\nof this"), span: 19..30 }])],
+            kinds(&nodes)
+        );
+    }
+}
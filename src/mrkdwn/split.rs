@@ -0,0 +1,115 @@
+use std::cmp;
+
+/// The longest text Slack recommends sending in a single message.
+pub const MAX_MESSAGE_CHARS: usize = 4000;
+
+const FENCE: &str = "```";
+
+/// Splits mrkdwn text into chunks of at most `max_chars` characters which can be sent as separate
+/// messages.
+///
+/// Text is split between paragraphs where possible, and otherwise between lines or words. A chunk
+/// which ends inside a code block has the block closed, and the next chunk reopens it. Mentions,
+/// links and other `<…>` tokens are never cut, nor are escapes such as `&amp;`, unless a single
+/// token is longer than a whole chunk.
+///
+/// `max_chars` must leave room for the fences, so it is treated as at least 16.
+pub fn split(text: &str, max_chars: usize) -> Vec<String> {
+    let max_chars = cmp::max(max_chars, 16);
+    if text.chars().count() <= max_chars {
+        return vec![text.to_owned()];
+    }
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut in_code = false;
+    while start < text.len() {
+        let opening = if in_code { "```\n" } else { "" };
+        // Room is always left for a closing fence, since where the chunk ends isn't known yet.
+        let budget = max_chars - opening.len() - 4;
+        let rest = &text[start..];
+        let (end, next) = if rest.chars().count() + opening.len() <= max_chars {
+            (text.len(), text.len())
+        } else {
+            let end_of = |n: usize| start + rest.char_indices().nth(n).map_or(rest.len(), |(i, _)| i);
+            break_point(text, start, end_of(budget), end_of(budget + 1))
+        };
+
+        let piece = text[start..end].trim_end_matches(' ');
+        let ends_in_code = in_code != (piece.matches(FENCE).count() % 2 == 1);
+        let mut chunk = format!("{}{}", opening, piece);
+        if ends_in_code {
+            chunk = format!("{}\n{}", chunk.trim_end_matches('\n'), FENCE);
+        }
+        if !chunk.trim().is_empty() {
+            chunks.push(chunk);
+        }
+        in_code = ends_in_code;
+        start = next;
+        if !in_code {
+            start = text.len() - text[start..].trim_start_matches('\n').len();
+        }
+    }
+    chunks
+}
+
+/// Finds where to end a chunk which starts at `start` and can reach `window`, returning the end of
+/// the chunk and the start of the next one. The break itself may be the character at `window`,
+/// which ends at `search`.
+fn break_point(text: &str, start: usize, window: usize, search: usize) -> (usize, usize) {
+    let slice = &text[start..search];
+    let found = slice.rfind("\n\n").map(|i| (i, i + 2))
+        .or_else(|| slice.rfind('\n').map(|i| (i, i + 1)))
+        .or_else(|| slice.rfind(' ').map(|i| (i, i + 1)))
+        .filter(|&(i, _)| i > 0);
+    let (end, next) = match found {
+        Some((end, next)) => (start + end, start + next),
+        None => (window, window),
+    };
+
+    // Move back to before a token or escape the break would cut in two.
+    let before = &text[start..end];
+    let token = match (before.rfind('<'), before.rfind('>')) {
+        (Some(open), close) if close.map_or(true, |close| close < open) => Some(open),
+        _ => None,
+    };
+    let escape = match (before.rfind('&'), before.rfind(';')) {
+        (Some(amp), semi) if before.len() - amp < 6 && semi.map_or(true, |semi| semi < amp) => Some(amp),
+        _ => None,
+    };
+    match token.or(escape) {
+        Some(cut) if cut > 0 => (start + cut, start + cut),
+        _ => (end, next),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_prefers_paragraphs_and_lines() {
+        assert_eq!(vec!["short"], split("short", 100));
+        let text = "first paragraph\n\nsecond paragraph\nwith two lines";
+        assert_eq!(vec!["first paragraph", "second paragraph", "with two lines"], split(text, 20));
+    }
+
+    #[test]
+    fn test_split_reopens_code_blocks() {
+        let text = "look:\n```\nline one\nline two\nline three\n```\ndone";
+        let chunks = split(text, 24);
+        assert_eq!(vec!["look:\n```\nline one\n```", "```\nline two\n```", "```\nline three\n```\ndone"], chunks);
+        assert!(chunks.iter().all(|c| c.chars().count() <= 24));
+    }
+
+    #[test]
+    fn test_split_never_cuts_tokens() {
+        let text = "hello <https://x.test/a/b|some link text> &amp; more";
+        let chunks = split(text, 40);
+        for chunk in &chunks {
+            assert_eq!(chunk.matches('<').count(), chunk.matches('>').count(), "{:?}", chunks);
+            assert!(!chunk.ends_with("&am"));
+        }
+        assert_eq!(text.replace(' ', ""), chunks.concat().replace(' ', ""));
+    }
+}
@@ -0,0 +1,248 @@
+//! Iterators that transparently follow Slack's pagination schemes.
+//!
+//! Methods that return their results a page at a time have a generated `*_iter` companion (for
+//! example [`users::list_iter`](../users/fn.list_iter.html)) which yields individual items and
+//! requests the next page whenever the current one runs out.
+//!
+//! The iterators are blocking, like the [`SlackWebRequestSender`] they call; there are no async
+//! versions.
+//!
+//! [`SlackWebRequestSender`]: ../requests/trait.SlackWebRequestSender.html
+
+use std::vec;
+
+use types::{Message, Paging, ResponseMetadata};
+
+/// Where the next page of a paginated method starts.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NextPage {
+    /// A page number, for methods that return a `Paging` object.
+    Page(u32),
+    /// An opaque cursor, for methods that return `response_metadata.next_cursor`.
+    Cursor(String),
+    /// The `latest` timestamp of the next window of messages, for history methods that return
+    /// `has_more`.
+    Latest(String),
+}
+
+impl NextPage {
+    /// The page number to request, if this is a numbered page.
+    pub fn page(&self) -> Option<u32> {
+        match *self {
+            NextPage::Page(page) => Some(page),
+            _ => None,
+        }
+    }
+
+    /// The cursor to request, if this is a cursor.
+    pub fn cursor(&self) -> Option<&str> {
+        match *self {
+            NextPage::Cursor(ref cursor) => Some(cursor),
+            _ => None,
+        }
+    }
+
+    /// The `latest` timestamp to request, if this is a window of messages.
+    pub fn latest(&self) -> Option<&str> {
+        match *self {
+            NextPage::Latest(ref latest) => Some(latest),
+            _ => None,
+        }
+    }
+}
+
+/// A single page of results.
+#[derive(Clone, Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// The page following this one, or `None` if this is the last page.
+    pub next: Option<NextPage>,
+}
+
+/// Returns the page after the one described by `paging`, if there is one.
+pub fn next_page(paging: &Paging) -> Option<NextPage> {
+    match (paging.page, paging.pages) {
+        (Some(page), Some(pages)) if page >= 1 && page < pages => Some(NextPage::Page(page as u32 + 1)),
+        _ => None,
+    }
+}
+
+/// Returns the cursor for the next page described by `metadata`, if there is one.
+///
+/// Slack signals the last page with either a missing or an empty `next_cursor`.
+pub fn next_cursor(metadata: &ResponseMetadata) -> Option<NextPage> {
+    match metadata.next_cursor {
+        Some(ref cursor) if !cursor.is_empty() => Some(NextPage::Cursor(cursor.clone())),
+        _ => None,
+    }
+}
+
+/// Returns the window of messages before `messages`, if `has_more` says there is one.
+///
+/// History is returned newest first, so the next window ends at the oldest message received.
+pub fn next_window(has_more: Option<bool>, messages: Option<&[Message]>) -> Option<NextPage> {
+    if has_more != Some(true) {
+        return None;
+    }
+    let oldest = messages?.iter().filter_map(Message::ts).next_back()?;
+    Some(NextPage::Latest(oldest.to_owned()))
+}
+
+/// Requests a page, starting at the given position (or at the beginning if `None`).
+type Fetch<'a, T, E> = Box<dyn FnMut(Option<NextPage>) -> Result<Page<T>, E> + 'a>;
+
+/// An iterator over every item of a paginated method.
+///
+/// Each call to the underlying method yields a [`Page`](struct.Page.html); its items are returned
+/// one at a time before the next page is requested. If a request fails, the error is returned and
+/// iteration stops.
+pub struct Items<'a, T, E> {
+    fetch: Fetch<'a, T, E>,
+    buffer: vec::IntoIter<T>,
+    next: Option<NextPage>,
+    started: bool,
+    done: bool,
+}
+
+impl<'a, T, E> Items<'a, T, E> {
+    /// Creates an iterator from a function which requests a page.
+    ///
+    /// `fetch` is called with `None` for the first page, and with the `next` value of the previous
+    /// page afterwards.
+    pub fn new<F>(fetch: F) -> Self
+    where
+        F: FnMut(Option<NextPage>) -> Result<Page<T>, E> + 'a,
+    {
+        Items {
+            fetch: Box::new(fetch),
+            buffer: Vec::new().into_iter(),
+            next: None,
+            started: false,
+            done: false,
+        }
+    }
+}
+
+impl<'a, T, E> Iterator for Items<'a, T, E> {
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Result<T, E>> {
+        loop {
+            if let Some(item) = self.buffer.next() {
+                return Some(Ok(item));
+            }
+            if self.done {
+                return None;
+            }
+
+            let next = self.next.take();
+            if self.started && next.is_none() {
+                self.done = true;
+                return None;
+            }
+            self.started = true;
+
+            match (self.fetch)(next) {
+                Ok(page) => {
+                    self.buffer = page.items.into_iter();
+                    self.next = page.next;
+                    if self.next.is_none() {
+                        self.done = true;
+                    }
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tests::{param, Replay};
+    use {im, search, users};
+
+    #[test]
+    fn test_cursor_iter_follows_next_cursor() {
+        let client = Replay::new(vec![
+            r#"{"ok": true, "members": [{"id": "U1"}, {"id": "U2"}], "response_metadata": {"next_cursor": "abc"}}"#,
+            r#"{"ok": true, "members": [{"id": "U3"}], "response_metadata": {"next_cursor": ""}}"#,
+        ]);
+        let ids = users::list_iter(&client, "token", &Default::default())
+            .map(|user| user.unwrap().id.unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["U1", "U2", "U3"], ids);
+        let requests = client.requests.borrow();
+        assert_eq!(2, requests.len());
+        assert_eq!(None, param(&requests[0], "cursor"));
+        assert_eq!(Some("abc".to_owned()), param(&requests[1], "cursor"));
+    }
+
+    #[test]
+    fn test_page_iter_follows_page_numbers() {
+        let client = Replay::new(vec![
+            r#"{"ok": true, "messages": {"matches": [{"text": "a"}], "paging": {"page": 1, "pages": 2}}}"#,
+            r#"{"ok": true, "messages": {"matches": [{"text": "b"}], "paging": {"page": 2, "pages": 2}}}"#,
+        ]);
+        let request = search::MessagesRequest::new("in:#general");
+        let count = search::messages_iter(&client, "token", &request).map(Result::unwrap).count();
+
+        assert_eq!(2, count);
+        assert_eq!(Some("2".to_owned()), param(&client.requests.borrow()[1], "page"));
+    }
+
+    #[test]
+    fn test_window_iter_moves_latest_back() {
+        let client = Replay::new(vec![
+            r#"{"ok": true, "messages": [{"text": "c", "ts": "3.0"}, {"text": "b", "ts": "2.0"}], "has_more": true}"#,
+            r#"{"ok": true, "messages": [{"text": "a", "ts": "1.0"}], "has_more": false}"#,
+        ]);
+        let request = im::HistoryRequest::new("D1").oldest("0.5");
+        let texts = im::history_iter(&client, "token", &request)
+            .map(|message| message.unwrap().text().unwrap().to_owned())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["c", "b", "a"], texts);
+        let requests = client.requests.borrow();
+        assert_eq!(None, param(&requests[0], "latest"));
+        assert_eq!(Some("2.0".to_owned()), param(&requests[1], "latest"));
+        assert_eq!(Some("0.5".to_owned()), param(&requests[1], "oldest"));
+    }
+
+    #[test]
+    fn test_window_iter_includes_latest_only_once() {
+        let client = Replay::new(vec![
+            r#"{"ok": true, "messages": [{"text": "d", "ts": "4.0"}], "has_more": true}"#,
+            r#"{"ok": true, "messages": [{"text": "c", "ts": "3.0"}, {"text": "b", "ts": "2.0"}], "has_more": true}"#,
+            r#"{"ok": true, "messages": [{"text": "a", "ts": "1.0"}], "has_more": false}"#,
+        ]);
+        let request = im::HistoryRequest::new("D1").latest("4.0").inclusive(true);
+        let texts = im::history_iter(&client, "token", &request)
+            .map(|message| message.unwrap().text().unwrap().to_owned())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["d", "c", "b", "a"], texts);
+        let requests = client.requests.borrow();
+        assert_eq!(Some("1".to_owned()), param(&requests[0], "inclusive"));
+        assert_eq!(Some("4.0".to_owned()), param(&requests[1], "latest"));
+        assert_eq!(None, param(&requests[1], "inclusive"));
+        assert_eq!(Some("2.0".to_owned()), param(&requests[2], "latest"));
+        assert_eq!(None, param(&requests[2], "inclusive"));
+    }
+
+    #[test]
+    fn test_iter_stops_after_error() {
+        let client = Replay::new(vec![r#"{"ok": false, "error": "invalid_cursor"}"#]);
+        let mut iter = users::list_iter(&client, "token", &Default::default());
+
+        match iter.next() {
+            Some(Err(users::ListError::InvalidCursor)) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(iter.next().is_none());
+    }
+}
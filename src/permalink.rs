@@ -0,0 +1,277 @@
+//! Parsing and building links to messages.
+//!
+//! Slack links to a message as `https://team.slack.com/archives/C0123/p1503435956000247`, where
+//! the last part is the message's `ts` without its decimal point. Replies add the thread to the
+//! query string:
+//!
+//! ```
+//! use slack_api::permalink::Permalink;
+//!
+//! let link: Permalink = "https://team.slack.com/archives/C0123/p1503435956000247?thread_ts=1503435950.000100&cid=C0123"
+//!     .parse()
+//!     .unwrap();
+//! assert_eq!("C0123", link.channel);
+//! assert_eq!(Some("1503435956.000247"), link.ts.as_deref());
+//! assert_eq!(Some("1503435950.000100"), link.thread_ts.as_deref());
+//! ```
+//!
+//! Links can also be requested from Slack with [`chat::get_permalink`].
+//!
+//! [`chat::get_permalink`]: ../chat/fn.get_permalink.html
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use form::percent_decode;
+use types::{Message, Team, Timestamp};
+
+/// A link to a channel, a message or a reply in a thread.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Permalink {
+    /// The workspace's domain, such as `team` for `team.slack.com`, if the link includes it.
+    pub domain: Option<String>,
+    /// The workspace's id, which `app_redirect` links may include instead of a domain.
+    pub team: Option<String>,
+    pub channel: String,
+    /// The `ts` of the message, or `None` for a link to the channel itself.
+    pub ts: Option<String>,
+    /// The `ts` of the parent of the thread, if the message is a reply.
+    pub thread_ts: Option<String>,
+}
+
+impl Permalink {
+    /// Creates a link to a message.
+    pub fn new(domain: impl Into<String>, channel: impl Into<String>, ts: impl Into<String>) -> Self {
+        Permalink {
+            domain: Some(domain.into()),
+            team: None,
+            channel: channel.into(),
+            ts: Some(ts.into()),
+            thread_ts: None,
+        }
+    }
+
+    /// Creates a link to a message posted in `channel` of the team, including its thread if it
+    /// is a reply.
+    ///
+    /// Returns `None` if the team has no domain or the message has no `ts`.
+    pub fn for_message(team: &Team, channel: impl Into<String>, message: &Message) -> Option<Self> {
+        let domain = team.domain.clone()?;
+        let ts = message.ts()?;
+        let mut link = Permalink::new(domain, channel, ts);
+        link.thread_ts = message.thread_ts().filter(|thread_ts| *thread_ts != ts).map(str::to_owned);
+        Some(link)
+    }
+
+    /// Sets the thread the message is a reply in.
+    pub fn thread_ts(mut self, thread_ts: impl Into<String>) -> Self {
+        self.thread_ts = Some(thread_ts.into());
+        self
+    }
+
+    /// The time the message was posted.
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.ts.as_ref().and_then(|ts| ts.parse().ok())
+    }
+
+    /// The time the parent of the thread was posted, if the message is a reply.
+    pub fn thread_timestamp(&self) -> Option<Timestamp> {
+        self.thread_ts.as_ref().and_then(|ts| ts.parse().ok())
+    }
+
+    /// Whether the link is to a reply in a thread.
+    pub fn is_reply(&self) -> bool {
+        self.thread_ts.is_some() && self.thread_ts != self.ts
+    }
+}
+
+/// Formats the link as an `archives` URL on the workspace's domain or, if only the team is known,
+/// as an `app_redirect` link. Without either, the `archives` URL is on `slack.com`.
+impl fmt::Display for Permalink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let (None, Some(ref team)) = (&self.domain, &self.team) {
+            write!(f, "https://slack.com/app_redirect?team={}&channel={}", team, self.channel)?;
+            if let Some(ref ts) = self.ts {
+                write!(f, "&message_ts={}", ts)?;
+            }
+            if let Some(ref thread_ts) = self.thread_ts {
+                write!(f, "&thread_ts={}", thread_ts)?;
+            }
+            return Ok(());
+        }
+        match self.domain {
+            Some(ref domain) => write!(f, "https://{}.slack.com/archives/{}", domain, self.channel)?,
+            None => write!(f, "https://slack.com/archives/{}", self.channel)?,
+        }
+        if let Some(ref ts) = self.ts {
+            write!(f, "/{}", message_id(ts))?;
+            if let Some(ref thread_ts) = self.thread_ts {
+                write!(f, "?thread_ts={}&cid={}", thread_ts, self.channel)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses `archives` links, and `app_redirect` links with a `channel` parameter.
+impl FromStr for Permalink {
+    type Err = ParsePermalinkError;
+
+    fn from_str(url: &str) -> Result<Self, Self::Err> {
+        let rest = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://")).unwrap_or(url);
+        let (host, rest) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let domain = if host == "slack.com" {
+            None
+        } else {
+            match host.strip_suffix(".slack.com") {
+                Some(domain) if !domain.is_empty() => Some(domain.to_owned()),
+                _ => return Err(ParsePermalinkError::NotSlack),
+            }
+        };
+        let (path, query) = match rest.find('?') {
+            Some(i) => (&rest[..i], Some(&rest[i + 1..])),
+            None => (rest, None),
+        };
+        let query = query.map(|query| query.split('#').next().unwrap_or(""));
+        let param = |name: &str| -> Option<String> {
+            query?.split('&').find_map(|pair| {
+                let (key, value) = pair.split_at(pair.find('=').unwrap_or(pair.len()));
+                if key == name {
+                    Some(percent_decode(value.trim_start_matches('=')))
+                } else {
+                    None
+                }
+            })
+        };
+
+        let segments = path.split('/').filter(|s| !s.is_empty()).collect::<Vec<_>>();
+        let (channel, ts) = match segments.as_slice() {
+            ["archives", channel] => (channel.to_string(), None),
+            ["archives", channel, message] => (channel.to_string(), Some(parse_message_id(message)?)),
+            ["app_redirect"] => {
+                let channel = param("channel").ok_or(ParsePermalinkError::MissingChannel)?;
+                (channel, param("message_ts"))
+            }
+            _ => return Err(ParsePermalinkError::UnknownPath),
+        };
+        if channel.is_empty() {
+            return Err(ParsePermalinkError::MissingChannel);
+        }
+
+        Ok(Permalink {
+            domain,
+            team: param("team"),
+            channel,
+            ts,
+            thread_ts: param("thread_ts"),
+        })
+    }
+}
+
+/// Converts a `ts` such as `1503435956.000247` to the `p1503435956000247` form, padding the
+/// fraction to the six digits Slack uses.
+fn message_id(ts: &str) -> String {
+    let (seconds, fraction) = match ts.find('.') {
+        Some(i) => (&ts[..i], &ts[i + 1..]),
+        None => (ts, ""),
+    };
+    format!("p{}{:0<6}", seconds, &fraction[..fraction.len().min(6)])
+}
+
+/// Converts the `p1503435956000247` form of a `ts` back to `1503435956.000247`.
+fn parse_message_id(id: &str) -> Result<String, ParsePermalinkError> {
+    match id.strip_prefix('p') {
+        Some(digits) if digits.len() > 6 && digits.bytes().all(|b| b.is_ascii_digit()) => {
+            let (seconds, micros) = digits.split_at(digits.len() - 6);
+            Ok(format!("{}.{}", seconds, micros))
+        }
+        _ => Err(ParsePermalinkError::InvalidTimestamp),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParsePermalinkError {
+    /// The URL isn't on `slack.com`.
+    NotSlack,
+    /// The URL is on `slack.com`, but isn't a link to a channel or message.
+    UnknownPath,
+    /// The link doesn't name a channel.
+    MissingChannel,
+    /// The message part of the link isn't a `p` followed by the digits of a `ts`.
+    InvalidTimestamp,
+}
+
+impl fmt::Display for ParsePermalinkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for ParsePermalinkError {
+    fn description(&self) -> &str {
+        match *self {
+            ParsePermalinkError::NotSlack => "not a slack.com URL",
+            ParsePermalinkError::UnknownPath => "not a link to a channel or message",
+            ParsePermalinkError::MissingChannel => "the link has no channel",
+            ParsePermalinkError::InvalidTimestamp => "the link has an invalid message timestamp",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_parse() {
+        let link: Permalink = "https://team.slack.com/archives/C0123/p1503435956000247".parse().unwrap();
+        assert_eq!(Permalink::new("team", "C0123", "1503435956.000247"), link);
+        assert_eq!(Some(1503435956.000247), link.timestamp().map(|ts| ts.as_f64()));
+        assert!(!link.is_reply());
+
+        let link: Permalink = "https://org.enterprise.slack.com/archives/G1/p1503435956000247?thread_ts=1503435950.000100&cid=G1"
+            .parse()
+            .unwrap();
+        assert_eq!(Some("org.enterprise"), link.domain.as_deref());
+        assert_eq!(Some("1503435950.000100"), link.thread_ts.as_deref());
+        assert!(link.is_reply());
+
+        let link: Permalink = "https://slack.com/app_redirect?team=T1&channel=C0123&message_ts=1503435956.000247"
+            .parse()
+            .unwrap();
+        assert_eq!((None, Some("T1")), (link.domain.as_deref(), link.team.as_deref()));
+        assert_eq!(("C0123", Some("1503435956.000247")), (&link.channel[..], link.ts.as_deref()));
+
+        let link: Permalink = "https://team.slack.com/archives/C0123".parse().unwrap();
+        assert_eq!(None, link.ts);
+
+        assert_eq!(Err(ParsePermalinkError::NotSlack), "https://example.com/archives/C1".parse::<Permalink>());
+        assert_eq!(Err(ParsePermalinkError::InvalidTimestamp), "https://a.slack.com/archives/C1/x12".parse::<Permalink>());
+        assert_eq!(Err(ParsePermalinkError::MissingChannel), "https://slack.com/app_redirect?app=A1".parse::<Permalink>());
+    }
+
+    #[test]
+    fn test_build() {
+        let team: Team = serde_json::from_str(r#"{"id": "T1", "domain": "team"}"#).unwrap();
+        let reply: Message = serde_json::from_str(
+            r#"{"type": "message", "ts": "1503435956.000247", "thread_ts": "1503435950.000100", "text": "hi"}"#,
+        ).unwrap();
+        let link = Permalink::for_message(&team, "C0123", &reply).unwrap();
+        let url = "https://team.slack.com/archives/C0123/p1503435956000247?thread_ts=1503435950.000100&cid=C0123";
+        assert_eq!(url, link.to_string());
+        assert_eq!(link, url.parse().unwrap());
+
+        let link = Permalink::new("team", "C0123", "1503435956.2");
+        assert_eq!("https://team.slack.com/archives/C0123/p1503435956200000", link.to_string());
+        assert_eq!(Some("1503435956.200000"), link.to_string().parse::<Permalink>().unwrap().ts.as_deref());
+
+        let mut link = Permalink::new("team", "C0123", "1503435956.000247").thread_ts("1503435950.000100");
+        link.domain = None;
+        link.team = Some("T1".to_owned());
+        let url = "https://slack.com/app_redirect?team=T1&channel=C0123&message_ts=1503435956.000247&thread_ts=1503435950.000100";
+        assert_eq!(url, link.to_string());
+        assert_eq!(link, url.parse().unwrap());
+    }
+}
@@ -0,0 +1,634 @@
+//! Rendering messages as plain text, HTML or text coloured for a terminal.
+//!
+//! Message text is parsed as mrkdwn, and the ids in mentions are replaced with names looked up
+//! through a [`NameResolver`]. [`Directory`] loads every user and channel name up front:
+//!
+//! ```no_run
+//! # fn run<R: slack_api::requests::SlackWebRequestSender>(client: &R, message: &slack_api::Message)
+//! # where R::Error: 'static {
+//! use slack_api::render::{render, Directory, Format};
+//!
+//! let directory = Directory::load(client, "xoxp-token").unwrap();
+//! println!("{}", render(message, &directory, Format::Ansi));
+//! # }
+//! ```
+//!
+//! [`NameResolver`]: trait.NameResolver.html
+//! [`Directory`]: struct.Directory.html
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use mrkdwn::{parse, Node, NodeKind, SpecialMention};
+use requests::SlackWebRequestSender;
+use types::{File, Message, MessageStandardAttachment, User};
+use {channels, users};
+
+/// The output produced by [`render`](fn.render.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Text without any markup.
+    Plain,
+    /// An HTML fragment. Mentions, attachments and files are given classes so they can be styled.
+    Html,
+    /// Text with ANSI escape sequences for bold, italics, colours and so on.
+    Ansi,
+}
+
+/// Looks up the names shown in place of ids.
+///
+/// When a name can't be found, the label included in the mention is shown if there is one, and
+/// otherwise the id.
+pub trait NameResolver {
+    /// The name of the user with the given id.
+    fn user_name(&self, id: &str) -> Option<String>;
+
+    /// The name of the channel with the given id, without the leading `#`.
+    fn channel_name(&self, id: &str) -> Option<String>;
+
+    /// The handle of the user group with the given id, without the leading `@`.
+    fn usergroup_handle(&self, _id: &str) -> Option<String> {
+        None
+    }
+}
+
+/// A resolver which knows no names, so mentions are shown with their labels or ids.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoResolver;
+
+impl NameResolver for NoResolver {
+    fn user_name(&self, _id: &str) -> Option<String> {
+        None
+    }
+
+    fn channel_name(&self, _id: &str) -> Option<String> {
+        None
+    }
+}
+
+/// The names of a workspace's users and channels.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Directory {
+    /// User names by id.
+    pub users: HashMap<String, String>,
+    /// Channel names by id.
+    pub channels: HashMap<String, String>,
+}
+
+impl Directory {
+    /// Loads every user with `users.list` and every channel with `channels.list`.
+    pub fn load<R>(client: &R, token: &str) -> Result<Directory, DirectoryError<R::Error>>
+    where
+        R: SlackWebRequestSender,
+    {
+        let mut directory = Directory::default();
+        for user in users::list_iter(client, token, &users::ListRequest::default()) {
+            let user = user.map_err(DirectoryError::Users)?;
+            if let (Some(id), Some(name)) = (user.id.clone(), user_name(&user)) {
+                directory.users.insert(id, name);
+            }
+        }
+        for channel in channels::list_iter(client, token, &channels::ListRequest::default()) {
+            let channel = channel.map_err(DirectoryError::Channels)?;
+            if let (Some(id), Some(name)) = (channel.id, channel.name) {
+                directory.channels.insert(id, name);
+            }
+        }
+        Ok(directory)
+    }
+}
+
+impl NameResolver for Directory {
+    fn user_name(&self, id: &str) -> Option<String> {
+        self.users.get(id).cloned()
+    }
+
+    fn channel_name(&self, id: &str) -> Option<String> {
+        self.channels.get(id).cloned()
+    }
+}
+
+/// The name Slack shows for a user: their display name if they've set one, and otherwise their
+/// real name or username.
+pub(crate) fn user_name(user: &User) -> Option<String> {
+    let profile = user.profile.as_ref();
+    let display_name = profile.and_then(|p| p.display_name.as_ref()).filter(|n| !n.is_empty());
+    let real_name = profile.and_then(|p| p.real_name.as_ref()).or(user.real_name.as_ref());
+    display_name.or(real_name.filter(|n| !n.is_empty())).or(user.name.as_ref()).cloned()
+}
+
+#[derive(Debug)]
+pub enum DirectoryError<E: Error> {
+    /// `users.list` failed.
+    Users(users::ListError<E>),
+    /// `channels.list` failed.
+    Channels(channels::ListError<E>),
+}
+
+impl<E: Error> fmt::Display for DirectoryError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for DirectoryError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            DirectoryError::Users(ref e) => e.description(),
+            DirectoryError::Channels(ref e) => e.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            DirectoryError::Users(ref e) => Some(e),
+            DirectoryError::Channels(ref e) => Some(e),
+        }
+    }
+}
+
+/// Renders a message: its author, text, files and attachments, and whether it has been edited.
+///
+/// Messages announcing events, such as `channel_join`, are shown without their author since their
+/// text already names them.
+pub fn render<N: NameResolver + ?Sized>(message: &Message, resolver: &N, format: Format) -> String {
+    let mut w = Writer { format, resolver, out: String::new() };
+    match *message {
+        Message::MeMessage(_) => {
+            w.push_styled(Style::Author, "* ");
+            w.author(message);
+            w.out.push(' ');
+            w.italic_text(message.text().unwrap_or(""));
+        }
+        Message::Standard(_) | Message::BotMessage(_) | Message::FileShare(_) |
+        Message::MessageChanged(_) | Message::MessageReplied(_) | Message::ThreadBroadcast(_) => {
+            if w.author(message) {
+                w.out.push_str(": ");
+            }
+            w.mrkdwn(message.text().unwrap_or(""));
+        }
+        Message::MessageDeleted(_) => w.push_styled(Style::Meta, "(message deleted)"),
+        _ => w.italic_text(message.text().unwrap_or("")),
+    }
+    if message.edited().is_some() {
+        w.out.push(' ');
+        w.push_styled(Style::Meta, "(edited)");
+    }
+    if let Message::FileShare(ref m) = *message {
+        if let Some(ref file) = m.file {
+            w.file(file);
+        }
+    }
+    for attachment in message.attachments() {
+        w.attachment(attachment);
+    }
+    w.out
+}
+
+/// Renders mrkdwn text on its own, such as the text of a message or attachment.
+pub fn render_text<N: NameResolver + ?Sized>(text: &str, resolver: &N, format: Format) -> String {
+    let mut w = Writer { format, resolver, out: String::new() };
+    w.mrkdwn(text);
+    w.out
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Style {
+    Author,
+    Mention,
+    Meta,
+}
+
+struct Writer<'a, N: NameResolver + ?Sized + 'a> {
+    format: Format,
+    resolver: &'a N,
+    out: String,
+}
+
+impl<'a, N: NameResolver + ?Sized> Writer<'a, N> {
+    /// Renders into a separate buffer, for content which is wrapped or prefixed afterwards.
+    fn nested<F: FnOnce(&mut Self)>(&mut self, f: F) -> String {
+        let out = ::std::mem::take(&mut self.out);
+        f(self);
+        ::std::mem::replace(&mut self.out, out)
+    }
+
+    fn push_text(&mut self, text: &str) {
+        match self.format {
+            Format::Html => self.out.push_str(&escape_html(text).replace('\n', "<br>\n")),
+            Format::Ansi => self.out.push_str(&strip_controls(text)),
+            Format::Plain => self.out.push_str(text),
+        }
+    }
+
+    fn push_styled(&mut self, style: Style, text: &str) {
+        let (class, ansi) = match style {
+            Style::Author => ("author", "\x1b[1m"),
+            Style::Mention => ("mention", "\x1b[33m"),
+            Style::Meta => ("meta", "\x1b[2m"),
+        };
+        match self.format {
+            Format::Plain => self.out.push_str(text),
+            Format::Html => self.out.push_str(&format!("<span class=\"{}\">{}</span>", class, escape_html(text))),
+            Format::Ansi => self.out.push_str(&format!("{}{}\x1b[0m", ansi, strip_controls(text))),
+        }
+    }
+
+    /// Writes the name of whoever posted the message, returning whether it is known.
+    fn author(&mut self, message: &Message) -> bool {
+        let name = match (message.user(), bot_name(message)) {
+            (Some(id), _) => self.resolver.user_name(id).unwrap_or_else(|| id.to_owned()),
+            (None, Some(name)) => name.to_owned(),
+            (None, None) => return false,
+        };
+        self.push_styled(Style::Author, &name);
+        true
+    }
+
+    fn italic_text(&mut self, text: &str) {
+        let nodes = parse(text);
+        self.wrap("_", ("<em>", "</em>"), ("\x1b[3m", "\x1b[23m"), &nodes);
+    }
+
+    fn mrkdwn(&mut self, text: &str) {
+        for node in parse(text) {
+            self.node(&node);
+        }
+    }
+
+    /// Writes `children` between the given markup for the plain, HTML and ANSI formats.
+    fn wrap(&mut self, plain: &str, html: (&str, &str), ansi: (&str, &str), children: &[Node]) {
+        let (open, close) = match self.format {
+            Format::Plain => (plain, plain),
+            Format::Html => html,
+            Format::Ansi => ansi,
+        };
+        self.out.push_str(open);
+        for child in children {
+            self.node(child);
+        }
+        self.out.push_str(close);
+    }
+
+    fn node(&mut self, node: &Node) {
+        match node.kind {
+            NodeKind::Text(ref text) => self.push_text(text),
+            NodeKind::User { ref id, ref label } => {
+                let name = self.resolver.user_name(id).or_else(|| label.clone()).unwrap_or_else(|| id.clone());
+                self.push_styled(Style::Mention, &format!("@{}", name));
+            }
+            NodeKind::Channel { ref id, ref name } => {
+                let name = self.resolver.channel_name(id).or_else(|| name.clone()).unwrap_or_else(|| id.clone());
+                self.push_styled(Style::Mention, &format!("#{}", name));
+            }
+            NodeKind::Usergroup { ref id, ref handle } => {
+                let handle = self.resolver.usergroup_handle(id).or_else(|| handle.clone()).unwrap_or_else(|| id.clone());
+                self.push_styled(Style::Mention, &format!("@{}", handle.trim_start_matches('@')));
+            }
+            NodeKind::Special(ref mention) => {
+                let name = match *mention {
+                    SpecialMention::Here => "here",
+                    SpecialMention::Channel => "channel",
+                    SpecialMention::Everyone => "everyone",
+                    SpecialMention::Other(ref name) => name,
+                };
+                self.push_styled(Style::Mention, &format!("@{}", name));
+            }
+            NodeKind::Date { ref link, ref fallback, .. } => match *link {
+                Some(ref url) => self.link(url, Some(fallback)),
+                None => self.push_text(fallback),
+            },
+            NodeKind::Link { ref url, ref label } => self.link(url, label.as_ref()),
+            NodeKind::Bold(ref children) => {
+                let plain = if self.format == Format::Plain { "" } else { "*" };
+                self.wrap(plain, ("<strong>", "</strong>"), ("\x1b[1m", "\x1b[22m"), children)
+            }
+            NodeKind::Italic(ref children) => {
+                let plain = if self.format == Format::Plain { "" } else { "_" };
+                self.wrap(plain, ("<em>", "</em>"), ("\x1b[3m", "\x1b[23m"), children)
+            }
+            NodeKind::Strike(ref children) => {
+                self.wrap("~", ("<del>", "</del>"), ("\x1b[9m", "\x1b[29m"), children)
+            }
+            NodeKind::Code(ref code) => match self.format {
+                Format::Plain => self.out.push_str(code),
+                Format::Html => self.out.push_str(&format!("<code>{}</code>", escape_html(code))),
+                Format::Ansi => self.out.push_str(&format!("\x1b[36m{}\x1b[39m", strip_controls(code))),
+            },
+            NodeKind::CodeBlock(ref code) => {
+                let code = code.trim_matches('\n');
+                if !self.out.is_empty() && !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+                match self.format {
+                    Format::Plain => self.out.push_str(code),
+                    Format::Html => self.out.push_str(&format!("<pre>{}</pre>", escape_html(code))),
+                    Format::Ansi => {
+                        let lines = code.lines().map(|line| format!("\x1b[36m{}\x1b[39m", strip_controls(line)));
+                        self.out.push_str(&lines.collect::<Vec<_>>().join("\n"))
+                    }
+                }
+            }
+            NodeKind::Quote(ref children) => {
+                let quoted = self.nested(|w| {
+                    for child in children {
+                        w.node(child);
+                    }
+                });
+                match self.format {
+                    Format::Html => self.out.push_str(&format!("<blockquote>{}</blockquote>", quoted)),
+                    _ => self.out.push_str(&prefix_lines(&quoted, &self.bar(None))),
+                }
+            }
+        }
+    }
+
+    /// Writes a link, or its text if the URL could run script when opened.
+    fn link(&mut self, url: &str, label: Option<&String>) {
+        let label = label.filter(|label| !label.is_empty() && *label != url);
+        match self.format {
+            Format::Html if is_safe_url(url) => self.out.push_str(&format!(
+                "<a href=\"{}\">{}</a>",
+                escape_html(url),
+                escape_html(label.map_or(url, |label| &label[..]))
+            )),
+            Format::Ansi if is_safe_url(url) => {
+                let (url, label) = (strip_controls(url), strip_controls(label.map_or(url, |label| &label[..])));
+                self.out.push_str(&format!("\x1b]8;;{}\x1b\\\x1b[4;34m{}\x1b[24;39m\x1b]8;;\x1b\\", url, label))
+            }
+            _ => match label {
+                Some(label) => self.push_text(&format!("{} ({})", label, url)),
+                None => self.push_text(url),
+            },
+        }
+    }
+
+    /// The prefix of the lines of a quote or attachment in the plain and ANSI formats.
+    fn bar(&self, color: Option<&str>) -> String {
+        match (self.format, color) {
+            (Format::Ansi, Some(color)) => format!("{}│\x1b[0m ", ansi_color(color)),
+            (Format::Ansi, None) => "\x1b[2m│\x1b[0m ".to_owned(),
+            _ => "> ".to_owned(),
+        }
+    }
+
+    fn file(&mut self, file: &File) {
+        let name = file.title.as_ref().or(file.name.as_ref()).map_or("file", |name| &name[..]);
+        let url = file.permalink.as_ref().or(file.url_private.as_ref());
+        self.out.push('\n');
+        match self.format {
+            Format::Html => {
+                self.out.push_str("<div class=\"file\">");
+                match url {
+                    Some(url) => self.link(url, Some(&name.to_owned())),
+                    None => self.push_text(name),
+                }
+                self.out.push_str("</div>");
+            }
+            _ => {
+                self.push_styled(Style::Meta, "[file] ");
+                match url {
+                    Some(url) => self.link(url, Some(&name.to_owned())),
+                    None => self.push_text(name),
+                }
+            }
+        }
+    }
+
+    fn attachment(&mut self, attachment: &MessageStandardAttachment) {
+        let body = self.nested(|w| w.attachment_body(attachment));
+        self.out.push('\n');
+        match self.format {
+            Format::Html => {
+                let style = attachment.color.as_ref().and_then(|color| html_color(color)).map_or(String::new(), |color| {
+                    format!(" style=\"border-left-color: {}\"", color)
+                });
+                self.out.push_str(&format!("<div class=\"attachment\"{}>{}</div>", style, body));
+            }
+            _ => {
+                let bar = self.bar(Some(attachment.color.as_ref().map_or("", |color| &color[..])));
+                self.out.push_str(&prefix_lines(&body, &bar));
+            }
+        }
+    }
+
+    fn attachment_body(&mut self, attachment: &MessageStandardAttachment) {
+        let mut first = true;
+        let mut line = |w: &mut Self| {
+            if !first {
+                w.out.push_str(if w.format == Format::Html { "<br>\n" } else { "\n" });
+            }
+            first = false;
+        };
+
+        if let Some(ref pretext) = attachment.pretext {
+            line(self);
+            self.mrkdwn(pretext);
+        }
+        if let Some(ref author) = attachment.author_name {
+            line(self);
+            match attachment.author_link {
+                Some(ref url) => self.link(url, Some(author)),
+                None => self.push_styled(Style::Author, author),
+            }
+        }
+        if let Some(ref title) = attachment.title {
+            line(self);
+            match attachment.title_link {
+                Some(ref url) => self.link(url, Some(title)),
+                None => self.wrap("", ("<strong>", "</strong>"), ("\x1b[1m", "\x1b[22m"), &parse(title)),
+            }
+        }
+        if let Some(ref text) = attachment.text {
+            line(self);
+            self.mrkdwn(text);
+        }
+        for field in attachment.fields.as_deref().unwrap_or(&[]) {
+            line(self);
+            if let Some(ref title) = field.title {
+                self.wrap("", ("<strong>", "</strong>"), ("\x1b[1m", "\x1b[22m"), &parse(title));
+                self.out.push_str(": ");
+            }
+            self.mrkdwn(field.value.as_ref().map_or("", |value| &value[..]));
+        }
+        if let Some(ref url) = attachment.image_url {
+            line(self);
+            self.link(url, None);
+        }
+        if let Some(ref footer) = attachment.footer {
+            line(self);
+            self.push_styled(Style::Meta, footer);
+        }
+        if first {
+            if let Some(ref fallback) = attachment.fallback {
+                self.push_text(fallback);
+            }
+        }
+    }
+}
+
+/// The name shown for a message posted by a bot without a user.
+fn bot_name(message: &Message) -> Option<&str> {
+    match *message {
+        Message::BotMessage(ref m) => m.username.as_deref().or(m.bot_id.as_deref()),
+        _ => None,
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn prefix_lines(text: &str, prefix: &str) -> String {
+    text.split('\n').map(|line| format!("{}{}", prefix, line)).collect::<Vec<_>>().join("\n")
+}
+
+/// Removes control characters other than newlines and tabs, so that text can't send escape
+/// sequences to a terminal.
+fn strip_controls(text: &str) -> String {
+    text.chars().filter(|&c| !c.is_control() || c == '\n' || c == '\t').collect()
+}
+
+/// Whether a link may be followed from rendered output. Other schemes, such as `javascript:`,
+/// are shown as text.
+fn is_safe_url(url: &str) -> bool {
+    let scheme = url.split(':').next().unwrap_or("").to_ascii_lowercase();
+    url.contains(':') && ["http", "https", "mailto"].contains(&&scheme[..])
+}
+
+/// The CSS colour of an attachment's `color`, which may be `good`, `warning`, `danger` or a hex
+/// colour. Anything else is ignored, so that it can't add to the style.
+fn html_color(color: &str) -> Option<String> {
+    match color {
+        "good" => Some("#2eb67d".to_owned()),
+        "warning" => Some("#ecb22e".to_owned()),
+        "danger" => Some("#e01e5a".to_owned()),
+        c => {
+            let hex = c.strip_prefix('#').unwrap_or(c);
+            if (3..=6).contains(&hex.len()) && hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                Some(format!("#{}", hex))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// The closest of the basic terminal colours to an attachment's `color`.
+fn ansi_color(color: &str) -> &'static str {
+    match color {
+        "good" => "\x1b[32m",
+        "warning" => "\x1b[33m",
+        "danger" => "\x1b[31m",
+        _ => {
+            let hex = color.trim_start_matches('#');
+            let channel = |i: usize| hex.get(i..i + 2).and_then(|c| u8::from_str_radix(c, 16).ok());
+            match (channel(0), channel(2), channel(4)) {
+                (Some(r), Some(g), Some(b)) => {
+                    let bits = (r > 127) as usize | ((g > 127) as usize) << 1 | ((b > 127) as usize) << 2;
+                    ["\x1b[90m", "\x1b[31m", "\x1b[32m", "\x1b[33m", "\x1b[34m", "\x1b[35m", "\x1b[36m", "\x1b[37m"][bits]
+                }
+                _ => "\x1b[2m",
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    use tests::Replay;
+
+    fn parse_message(json: &str) -> Message {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn directory() -> Directory {
+        let client = Replay::new(vec![
+            r#"{"ok": true, "members": [
+                {"id": "U1", "name": "ann", "profile": {"display_name": "Annie", "real_name": "Ann Smith"}},
+                {"id": "U2", "name": "bob", "profile": {"display_name": "", "real_name": "Bob <B>"}}
+            ]}"#,
+            r#"{"ok": true, "channels": [{"id": "C1", "name": "general"}]}"#,
+        ]);
+        Directory::load(&client, "token").unwrap()
+    }
+
+    #[test]
+    fn test_directory_load() {
+        let directory = directory();
+        assert_eq!(Some("Annie".to_owned()), directory.user_name("U1"));
+        assert_eq!(Some("Bob <B>".to_owned()), directory.user_name("U2"));
+        assert_eq!(Some("general".to_owned()), directory.channel_name("C1"));
+    }
+
+    #[test]
+    fn test_render_formats() {
+        let message = parse_message(r#"{"type": "message", "ts": "1.0", "user": "U1",
+            "text": "hi <@U2> in <#C1> and <#C9|old> &amp; *bold* <https://x.test|site>",
+            "edited": {"user": "U1", "ts": "2.0"}}"#);
+        let directory = directory();
+
+        assert_eq!(
+            "Annie: hi @Bob <B> in #general and #old & bold site (https://x.test) (edited)",
+            render(&message, &directory, Format::Plain)
+        );
+        assert_eq!(
+            "<span class=\"author\">Annie</span>: hi \
+             <span class=\"mention\">@Bob &lt;B&gt;</span> in <span class=\"mention\">#general</span> and \
+             <span class=\"mention\">#old</span> &amp; <strong>bold</strong> \
+             <a href=\"https://x.test\">site</a> <span class=\"meta\">(edited)</span>",
+            render(&message, &directory, Format::Html)
+        );
+        let ansi = render(&message, &NoResolver, Format::Ansi);
+        assert!(ansi.starts_with("\x1b[1mU1\x1b[0m"));
+        assert!(ansi.contains("\x1b[33m@U2\x1b[0m"));
+        assert!(ansi.contains("\x1b[1mbold\x1b[22m"));
+    }
+
+    #[test]
+    fn test_render_files_and_attachments() {
+        let message = parse_message(r#"{"type": "message", "subtype": "file_share", "ts": "1.0", "user": "U1",
+            "text": "uploaded", "file": {"id": "F1", "title": "report.pdf", "permalink": "https://x.test/f"}}"#);
+        assert_eq!("U1: uploaded\n[file] report.pdf (https://x.test/f)", render(&message, &NoResolver, Format::Plain));
+
+        let message = parse_message(r#"{"type": "message", "subtype": "bot_message", "ts": "1.0", "username": "ci",
+            "text": "", "attachments": [{"fallback": "Build", "color": "danger", "title": "Build 42",
+            "fields": [{"title": "Branch", "value": "main"}]}]}"#);
+        assert_eq!("ci: \n> Build 42\n> Branch: main", render(&message, &NoResolver, Format::Plain));
+        assert!(render(&message, &NoResolver, Format::Ansi).contains("\x1b[31m│\x1b[0m \x1b[1mBuild 42"));
+        assert!(render(&message, &NoResolver, Format::Html)
+            .contains("<div class=\"attachment\" style=\"border-left-color: #e01e5a\"><strong>Build 42</strong>"));
+    }
+
+    #[test]
+    fn test_render_sanitizes_untrusted_text() {
+        let message = parse_message(r#"{"type": "message", "ts": "1.0", "user": "U1",
+            "text": "<javascript:alert(1)|click> <mailto:a@x.test|mail> \u001b]0;owned\u0007done",
+            "attachments": [{"fallback": "x", "color": "000;background:url(http://evil)", "title": "T"}]}"#);
+        let html = render(&message, &NoResolver, Format::Html);
+        assert!(!html.contains("href=\"javascript"));
+        assert!(html.contains("click (javascript:alert(1))"));
+        assert!(html.contains("<a href=\"mailto:a@x.test\">mail</a>"));
+        assert!(html.contains("<div class=\"attachment\"><strong>T</strong>"));
+
+        let ansi = render(&message, &NoResolver, Format::Ansi);
+        assert!(ansi.contains("]0;owneddone"));
+        assert!(!ansi.contains("\x1b]0;"));
+        assert!(!ansi.contains('\x07'));
+        assert!(!ansi.contains("\x1b]8;;javascript"));
+    }
+}
@@ -14,6 +14,15 @@ pub trait SlackWebRequestSender {
     fn send(&self, method: &str, params: &[(&str, &str)]) -> Result<String, Self::Error>;
 }
 
+/// Functionality for POSTing JSON to URLs given by Slack, such as the `response_url` of a slash
+/// command or interaction, which accept a message instead of API parameters.
+pub trait SlackJsonPoster {
+    type Error: error::Error;
+
+    /// POST `body` to `url` as `application/json`, returning the body of the response.
+    fn post_json(&self, url: &str, body: &str) -> Result<String, Self::Error>;
+}
+
 #[cfg(feature = "reqwest")]
 mod reqwest_support {
     extern crate reqwest;
@@ -22,7 +31,7 @@ mod reqwest_support {
 
     use std::io::Read;
 
-    use super::SlackWebRequestSender;
+    use super::{SlackJsonPoster, SlackWebRequestSender};
 
     impl SlackWebRequestSender for reqwest::Client {
         type Error = reqwest::Error;
@@ -40,6 +49,21 @@ mod reqwest_support {
         }
     }
 
+    impl SlackJsonPoster for reqwest::Client {
+        type Error = reqwest::Error;
+
+        fn post_json(&self, url: &str, body: &str) -> Result<String, Self::Error> {
+            let mut response = self.post(url)
+                .header(reqwest::header::ContentType::json())
+                .body(body.to_owned())
+                .send()?;
+            let mut res_str = String::new();
+            response.read_to_string(&mut res_str).map_err(reqwest::HyperError::from)?;
+
+            Ok(res_str)
+        }
+    }
+
     /// Provides a default `reqwest` client to give to the API functions to send requests.
     ///
     /// # Examples
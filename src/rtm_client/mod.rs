@@ -0,0 +1,733 @@
+//! A client for the [Real Time Messaging API](https://api.slack.com/rtm).
+//!
+//! [`Client`] opens the websocket returned by `rtm.connect` and yields the [events] Slack sends
+//! over it. It keeps the connection alive with pings, and reconnects with a fresh URL when Slack
+//! says `goodbye` or the connection drops:
+//!
+//! ```no_run
+//! # fn run<R: slack_api::requests::SlackWebRequestSender>(client: &R) {
+//! use slack_api::rtm_client::Client;
+//!
+//! let rtm = Client::connect(client, "xoxb-token").unwrap();
+//! for event in rtm {
+//!     println!("{:?}", event.unwrap());
+//! }
+//! # }
+//! ```
+//!
+//! Messages can be sent over the same connection. [`Client::post_message`] waits for Slack to
+//! acknowledge the message, and posts it with `chat.postMessage` instead if the websocket is down.
+//!
+//! [`Session`] wraps a client and, after reconnecting, fetches from history the messages posted
+//! while the connection was down.
+//!
+//! [`PresenceTracker`] manages presence subscriptions for connections which are
+//! `batch_presence_aware`.
+//!
+//! This module requires the `rtm` feature.
+//!
+//! [`Client`]: struct.Client.html
+//! [events]: ../event/enum.Event.html
+//! [`Client::post_message`]: struct.Client.html#method.post_message
+//! [`Session`]: struct.Session.html
+//! [`PresenceTracker`]: struct.PresenceTracker.html
+
+use std::cmp;
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::net::TcpStream;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde_json::{self, Value};
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{self, Message as Frame, WebSocket};
+
+use chat::{self, PostMessageRequest};
+use event::{ErrorEventError, Event};
+use requests::SlackWebRequestSender;
+use rtm;
+
+mod presence;
+mod session;
+pub use self::presence::{Presence, PresenceTracker};
+pub use self::session::{Session, SessionError, SessionEvent};
+
+type Socket = WebSocket<MaybeTlsStream<TcpStream>>;
+
+/// Returns the URL of a new websocket.
+type UrlSource<'a, E> = Box<dyn FnMut() -> Result<String, RtmError<E>> + 'a>;
+
+/// Posts a message to a channel without the websocket.
+type Fallback<'a, E> = Box<dyn FnMut(&str, &str) -> Result<Reply, RtmError<E>> + 'a>;
+
+/// How the connection is kept alive and re-established.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// How long the connection may be idle before a ping is sent.
+    pub ping_interval: Duration,
+    /// How long to wait for `hello` after connecting, and for the reply to a ping, before the
+    /// connection is considered dead.
+    pub timeout: Duration,
+    /// Whether to reconnect when the connection is lost.
+    pub reconnect: bool,
+    /// How long to wait before the first attempt to reconnect. The wait doubles after each
+    /// failed attempt.
+    pub initial_backoff: Duration,
+    /// The longest wait between attempts to reconnect.
+    pub max_backoff: Duration,
+    /// How many attempts in a row may fail before the client gives up, or `None` to keep trying.
+    pub max_reconnect_attempts: Option<u32>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            ping_interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(30),
+            reconnect: true,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_reconnect_attempts: None,
+        }
+    }
+}
+
+/// Slack's acknowledgement of a message sent over the websocket.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct Reply {
+    /// The `ts` of the message posted.
+    pub ts: Option<String>,
+    /// The text of the message, as formatted by Slack.
+    pub text: Option<String>,
+}
+
+/// A connection to the Real Time Messaging API, which is an iterator over the events received.
+///
+/// The first event after every (re)connection is `hello`. Pongs are handled by the client and
+/// aren't yielded. A message which can't be parsed is yielded as `MalformedEvent` and the
+/// connection is kept. When the connection can't be re-established, the error is yielded and
+/// iteration ends.
+pub struct Client<'a, E: Error> {
+    source: UrlSource<'a, E>,
+    fallback: Option<Fallback<'a, E>>,
+    config: Config,
+    socket: Option<Socket>,
+    pending: VecDeque<Result<Value, serde_json::Error>>,
+    next_id: u64,
+    last_received: Instant,
+    /// The id of the unanswered ping, and when it was sent.
+    ping: Option<(u64, Instant)>,
+    /// When each message which hasn't been waited for was sent.
+    unacknowledged: HashMap<u64, Instant>,
+    /// Acknowledgements of those messages which have arrived.
+    replies: HashMap<u64, Result<Reply, ErrorEventError>>,
+    /// Why the last connection was lost.
+    lost: Option<RtmError<E>>,
+    done: bool,
+}
+
+impl<'a, E: Error> Client<'a, E> {
+    /// Connects to a websocket URL requested with `rtm.connect`. Messages are posted with
+    /// `chat.postMessage` when the websocket is down.
+    pub fn connect<R>(client: &'a R, token: &str) -> Result<Client<'a, R::Error>, RtmError<R::Error>>
+    where
+        R: SlackWebRequestSender<Error = E>,
+    {
+        let connect_token = token.to_owned();
+        let mut rtm = Client::connect_with(Config::default(), move || {
+            let response = rtm::connect(client, &connect_token).map_err(RtmError::Connect)?;
+            response.url.ok_or(RtmError::MissingUrl)
+        })?;
+        let token = token.to_owned();
+        rtm.set_fallback(move |channel, text| {
+            let request = PostMessageRequest::new(channel, text);
+            let response = chat::post_message(client, &token, &request).map_err(RtmError::PostMessage)?;
+            Ok(Reply {
+                ts: response.ts,
+                text: response.message.as_ref().and_then(|message| message.text()).map(str::to_owned),
+            })
+        });
+        Ok(rtm)
+    }
+
+    /// Connects to the websocket URLs returned by `source`, which is called again before every
+    /// attempt to reconnect.
+    pub fn connect_with<F>(config: Config, source: F) -> Result<Self, RtmError<E>>
+    where
+        F: FnMut() -> Result<String, RtmError<E>> + 'a,
+    {
+        let mut client = Client {
+            source: Box::new(source),
+            fallback: None,
+            config,
+            socket: None,
+            pending: VecDeque::new(),
+            next_id: 1,
+            last_received: Instant::now(),
+            ping: None,
+            unacknowledged: HashMap::new(),
+            replies: HashMap::new(),
+            lost: None,
+            done: false,
+        };
+        client.open()?;
+        Ok(client)
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Changes how the connection is kept alive and re-established from now on.
+    pub fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+
+    /// Sets how [`post_message`] posts messages while the websocket is down.
+    ///
+    /// [`post_message`]: #method.post_message
+    pub fn set_fallback<F>(&mut self, fallback: F)
+    where
+        F: FnMut(&str, &str) -> Result<Reply, RtmError<E>> + 'a,
+    {
+        self.fallback = Some(Box::new(fallback));
+    }
+
+    /// Returns a new id for a message sent to Slack, which Slack includes as `reply_to` in its
+    /// reply.
+    pub fn next_id(&mut self) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    /// Sends a JSON message over the websocket.
+    pub fn send_json(&mut self, message: &Value) -> Result<(), RtmError<E>> {
+        match self.socket {
+            Some(ref mut socket) => socket.send(Frame::Text(message.to_string())).map_err(RtmError::from),
+            None => Err(tungstenite::Error::AlreadyClosed.into()),
+        }
+    }
+
+    /// Sends a message to a channel, returning its id without waiting for Slack to acknowledge it.
+    ///
+    /// Only plain text can be sent this way, and Slack formats it as if the user had typed it.
+    pub fn send_message(&mut self, channel: &str, text: &str) -> Result<u64, RtmError<E>> {
+        let id = self.next_id();
+        self.send_json(&::serde_json::json!({"id": id, "type": "message", "channel": channel, "text": text}))?;
+        let now = Instant::now();
+        let timeout = self.config.timeout;
+        self.unacknowledged.retain(|_, sent| now < *sent + timeout);
+        let unacknowledged = &self.unacknowledged;
+        self.replies.retain(|id, _| unacknowledged.contains_key(id));
+        self.unacknowledged.insert(id, now);
+        Ok(id)
+    }
+
+    /// Waits for Slack to acknowledge the message with the given id, for up to the configured
+    /// timeout after it was sent. Events received meanwhile are yielded by the iterator later.
+    ///
+    /// Acknowledgements are forgotten once that timeout has passed, so a reply which has already
+    /// arrived may still time out if this is called too late.
+    pub fn wait_for_reply(&mut self, id: u64) -> Result<Reply, RtmError<E>> {
+        loop {
+            let deadline = match self.unacknowledged.get(&id) {
+                Some(sent) => *sent + self.config.timeout,
+                None => return Err(RtmError::Timeout),
+            };
+            if let Some(reply) = self.replies.remove(&id) {
+                self.unacknowledged.remove(&id);
+                return reply.map_err(RtmError::Rejected);
+            }
+            if Instant::now() >= deadline {
+                self.unacknowledged.remove(&id);
+                return Err(RtmError::Timeout);
+            }
+            match self.receive(Some(deadline)) {
+                Ok(Received::Event(event)) => self.pending.push_back(Ok(event)),
+                Ok(Received::Malformed(e)) => self.pending.push_back(Err(e)),
+                Ok(Received::Reply) | Ok(Received::Idle) => {}
+                Ok(Received::Closed) => {
+                    self.socket = None;
+                    return Err(RtmError::Closed);
+                }
+                Err(e) => {
+                    self.socket = None;
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// Sends a message to a channel and waits for Slack to acknowledge it.
+    ///
+    /// If the websocket is down, the message is posted by the fallback set with
+    /// [`set_fallback`] instead, if there is one.
+    ///
+    /// [`set_fallback`]: #method.set_fallback
+    pub fn post_message(&mut self, channel: &str, text: &str) -> Result<Reply, RtmError<E>> {
+        let sent = match self.socket {
+            Some(_) => self.send_message(channel, text),
+            None => Err(RtmError::Closed),
+        };
+        match (sent, self.fallback.as_mut()) {
+            (Ok(id), _) => self.wait_for_reply(id),
+            (Err(_), Some(fallback)) => fallback(channel, text),
+            (Err(e), None) => Err(e),
+        }
+    }
+
+    /// Shows that the connected user is typing in a channel. Slack doesn't acknowledge this, and
+    /// the indicator disappears after a few seconds unless it is sent again.
+    pub fn send_typing(&mut self, channel: &str) -> Result<(), RtmError<E>> {
+        let id = self.next_id();
+        self.send_json(&::serde_json::json!({"id": id, "type": "typing", "channel": channel}))
+    }
+
+    /// Closes the connection. The iterator ends once Slack acknowledges the close.
+    pub fn close(&mut self) -> Result<(), RtmError<E>> {
+        self.config.reconnect = false;
+        match self.socket {
+            Some(ref mut socket) => socket.close(None).map_err(RtmError::from),
+            None => Ok(()),
+        }
+    }
+
+    /// Opens a websocket and waits for `hello`, which is queued to be yielded.
+    fn open(&mut self) -> Result<(), RtmError<E>> {
+        self.socket = None;
+        self.ping = None;
+        let url = (self.source)()?;
+        let (mut socket, _) = tungstenite::connect(url.as_str()).map_err(RtmError::from)?;
+
+        let deadline = Instant::now() + self.config.timeout;
+        loop {
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(RtmError::Timeout);
+            }
+            set_read_timeout(&socket, deadline - now).map_err(|e| RtmError::from(tungstenite::Error::from(e)))?;
+            match read(&mut socket)? {
+                Some(Err(e)) => return Err(RtmError::MalformedEvent(e)),
+                Some(Ok(event)) => match event_type(&event) {
+                    Some("hello") => {
+                        self.pending.push_back(Ok(event));
+                        break;
+                    }
+                    Some("error") => return Err(RtmError::Slack(event)),
+                    _ => {}
+                },
+                None => continue,
+            }
+        }
+
+        self.last_received = Instant::now();
+        self.socket = Some(socket);
+        Ok(())
+    }
+
+    /// Reads the next event or acknowledgement, sending a ping if the connection has been idle
+    /// too long, and giving up at `until`.
+    fn receive(&mut self, until: Option<Instant>) -> Result<Received, RtmError<E>> {
+        loop {
+            let now = Instant::now();
+            if until.is_some_and(|until| now >= until) {
+                return Ok(Received::Idle);
+            }
+            let wait = match self.ping {
+                Some((_, sent)) if now >= sent + self.config.timeout => return Err(RtmError::Timeout),
+                Some((_, sent)) => sent + self.config.timeout - now,
+                None if now >= self.last_received + self.config.ping_interval => {
+                    let id = self.next_id();
+                    self.send_json(&json_ping(id))?;
+                    self.ping = Some((id, now));
+                    continue;
+                }
+                None => self.last_received + self.config.ping_interval - now,
+            };
+            let wait = match until {
+                Some(until) => cmp::min(wait, until - now),
+                None => wait,
+            };
+
+            let socket = match self.socket {
+                Some(ref mut socket) => socket,
+                None => return Ok(Received::Closed),
+            };
+            set_read_timeout(socket, wait).map_err(|e| RtmError::from(tungstenite::Error::from(e)))?;
+            match read(socket) {
+                Ok(Some(Ok(event))) => {
+                    self.last_received = Instant::now();
+                    let is_pong = event_type(&event) == Some("pong");
+                    let reply_to = event.get("reply_to").and_then(Value::as_u64);
+                    if is_pong {
+                        if self.ping.map(|(id, _)| id) == reply_to {
+                            self.ping = None;
+                        }
+                        continue;
+                    }
+                    match reply_to {
+                        Some(id) if event_type(&event).is_none() => {
+                            if self.unacknowledged.contains_key(&id) {
+                                match parse_reply(event) {
+                                    Ok(reply) => self.replies.insert(id, reply),
+                                    Err(e) => return Ok(Received::Malformed(e)),
+                                };
+                            }
+                            return Ok(Received::Reply);
+                        }
+                        _ => return Ok(Received::Event(event)),
+                    }
+                }
+                Ok(Some(Err(e))) => {
+                    self.last_received = Instant::now();
+                    return Ok(Received::Malformed(e));
+                }
+                Ok(None) => continue,
+                Err(RtmError::WebSocket(ref e))
+                    if matches!(**e, tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed) =>
+                {
+                    return Ok(Received::Closed)
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Reconnects, waiting longer after each failed attempt.
+    fn reconnect(&mut self, mut error: RtmError<E>) -> Result<(), RtmError<E>> {
+        let mut backoff = self.config.initial_backoff;
+        let mut attempts = 0;
+        loop {
+            if !self.config.reconnect || self.config.max_reconnect_attempts.is_some_and(|max| attempts >= max) {
+                return Err(error);
+            }
+            thread::sleep(backoff);
+            attempts += 1;
+            match self.open() {
+                Ok(()) => return Ok(()),
+                Err(e) => error = e,
+            }
+            backoff = cmp::min(backoff * 2, self.config.max_backoff);
+        }
+    }
+}
+
+impl<'a, E: Error> Iterator for Client<'a, E> {
+    type Item = Result<Event, RtmError<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some(event.and_then(serde_json::from_value).map_err(RtmError::MalformedEvent));
+            }
+            if self.done {
+                return None;
+            }
+
+            if self.socket.is_none() {
+                let lost = self.lost.take().unwrap_or(RtmError::Closed);
+                if let Err(e) = self.reconnect(lost) {
+                    self.done = true;
+                    return match e {
+                        RtmError::Closed if !self.config.reconnect => None,
+                        e => Some(Err(e)),
+                    };
+                }
+                continue;
+            }
+
+            match self.receive(None) {
+                Ok(Received::Event(event)) => {
+                    if event_type(&event) == Some("goodbye") {
+                        // Slack is about to close the connection, so replace it straight away.
+                        self.socket = None;
+                    }
+                    return Some(serde_json::from_value(event).map_err(RtmError::MalformedEvent));
+                }
+                Ok(Received::Malformed(e)) => return Some(Err(RtmError::MalformedEvent(e))),
+                Ok(Received::Reply) | Ok(Received::Idle) => {}
+                Ok(Received::Closed) => self.socket = None,
+                Err(e) => {
+                    self.socket = None;
+                    self.lost = Some(e);
+                }
+            }
+        }
+    }
+}
+
+enum Received {
+    Event(Value),
+    /// A message which isn't JSON, or an acknowledgement which doesn't match its format.
+    Malformed(serde_json::Error),
+    /// An acknowledgement of a message sent.
+    Reply,
+    /// Nothing arrived in time.
+    Idle,
+    /// The connection was closed by Slack.
+    Closed,
+}
+
+fn parse_reply(reply: Value) -> Result<Result<Reply, ErrorEventError>, serde_json::Error> {
+    #[derive(Deserialize)]
+    struct Ack {
+        #[serde(default)]
+        ok: bool,
+        #[serde(default)]
+        error: ErrorEventError,
+        #[serde(flatten)]
+        reply: Reply,
+    }
+
+    let ack: Ack = serde_json::from_value(reply)?;
+    Ok(if ack.ok { Ok(ack.reply) } else { Err(ack.error) })
+}
+
+fn json_ping(id: u64) -> Value {
+    ::serde_json::json!({"id": id, "type": "ping"})
+}
+
+fn event_type(event: &Value) -> Option<&str> {
+    event.get("type").and_then(Value::as_str)
+}
+
+/// Reads a frame, returning the event it contains (or why it isn't JSON), or `None` if it was a
+/// control frame or the read timed out.
+fn read<E: Error>(socket: &mut Socket) -> Result<Option<Result<Value, serde_json::Error>>, RtmError<E>> {
+    match socket.read() {
+        Ok(Frame::Text(text)) => Ok(Some(serde_json::from_str(&text))),
+        Ok(_) => Ok(None),
+        Err(tungstenite::Error::Io(ref e))
+            if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn set_read_timeout(socket: &Socket, timeout: Duration) -> io::Result<()> {
+    // A zero timeout means none at all, so wait at least a millisecond.
+    let timeout = Some(cmp::max(timeout, Duration::from_millis(1)));
+    match *socket.get_ref() {
+        MaybeTlsStream::Plain(ref stream) => stream.set_read_timeout(timeout),
+        MaybeTlsStream::NativeTls(ref stream) => stream.get_ref().set_read_timeout(timeout),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug)]
+pub enum RtmError<E: Error> {
+    /// `rtm.connect` failed.
+    Connect(rtm::ConnectError<E>),
+    /// `rtm.connect` didn't return a websocket URL.
+    MissingUrl,
+    /// The websocket failed.
+    WebSocket(Box<tungstenite::Error>),
+    /// Slack sent a message which isn't JSON, or an event which doesn't match its type.
+    MalformedEvent(serde_json::Error),
+    /// Slack sent an `error` event instead of `hello`.
+    Slack(Value),
+    /// Slack refused a message sent over the websocket.
+    Rejected(ErrorEventError),
+    /// Posting a message with `chat.postMessage` failed.
+    PostMessage(chat::PostMessageError<E>),
+    /// Slack didn't send `hello` or reply to a ping in time.
+    Timeout,
+    /// The connection was closed.
+    Closed,
+}
+
+impl<E: Error> From<tungstenite::Error> for RtmError<E> {
+    fn from(e: tungstenite::Error) -> Self {
+        RtmError::WebSocket(Box::new(e))
+    }
+}
+
+impl<E: Error> fmt::Display for RtmError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RtmError::Slack(ref event) => write!(f, "Slack sent an error instead of hello: {}", event),
+            RtmError::Rejected(ErrorEventError { msg: Some(ref msg), .. }) => {
+                write!(f, "Slack refused the message: {}", msg)
+            }
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl<E: Error> Error for RtmError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            RtmError::Connect(ref e) => e.description(),
+            RtmError::MissingUrl => "rtm.connect didn't return a websocket URL",
+            RtmError::WebSocket(ref e) => e.description(),
+            RtmError::MalformedEvent(ref e) => e.description(),
+            RtmError::Slack(_) => "Slack sent an error instead of hello",
+            RtmError::Rejected(_) => "Slack refused the message",
+            RtmError::PostMessage(ref e) => e.description(),
+            RtmError::Timeout => "Slack didn't respond in time",
+            RtmError::Closed => "the connection was closed",
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            RtmError::Connect(ref e) => Some(e),
+            RtmError::PostMessage(ref e) => Some(e),
+            RtmError::WebSocket(ref e) => Some(&**e),
+            RtmError::MalformedEvent(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use tests::NoError;
+
+    pub type Script = fn(&mut WebSocket<TcpStream>);
+
+    /// Serves one connection with each script in turn, returning the server's URL.
+    pub fn serve(scripts: Vec<Script>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            for script in scripts {
+                let (stream, _) = listener.accept().unwrap();
+                let mut socket = tungstenite::accept(stream).unwrap();
+                script(&mut socket);
+            }
+        });
+        url
+    }
+
+    pub fn send(socket: &mut WebSocket<TcpStream>, event: Value) {
+        socket.send(Frame::Text(event.to_string())).unwrap();
+    }
+
+    #[test]
+    fn test_client_pings_and_reconnects() {
+        let url = serve(vec![
+            |socket| {
+                send(socket, ::serde_json::json!({"type": "hello"}));
+                let ping: Value = match socket.read().unwrap() {
+                    Frame::Text(text) => serde_json::from_str(&text).unwrap(),
+                    other => panic!("unexpected {:?}", other),
+                };
+                assert_eq!("ping", ping["type"]);
+                send(socket, ::serde_json::json!({"type": "pong", "reply_to": ping["id"]}));
+                send(socket, ::serde_json::json!({"type": "message", "text": "first"}));
+                send(socket, ::serde_json::json!({"type": "goodbye"}));
+            },
+            |socket| {
+                send(socket, ::serde_json::json!({"type": "hello"}));
+                send(socket, ::serde_json::json!({"type": "message", "text": "second"}));
+                socket.close(None).unwrap();
+                while socket.read().is_ok() {}
+            },
+        ]);
+
+        let config = Config {
+            ping_interval: Duration::from_millis(50),
+            timeout: Duration::from_secs(5),
+            initial_backoff: Duration::from_millis(10),
+            max_reconnect_attempts: Some(1),
+            ..Config::default()
+        };
+        let mut connections = 0;
+        let client = Client::<NoError>::connect_with(config, || {
+            connections += 1;
+            Ok(url.clone())
+        }).unwrap();
+
+        let events = client.collect::<Vec<_>>();
+        let types = events.iter()
+            .map(|event| match *event {
+                Ok(ref event) => event.ty().to_owned(),
+                Err(_) => "error".to_owned(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["hello", "message", "goodbye", "hello", "message", "error"], types);
+        match events[4] {
+            Ok(Event::Message(ref message)) => assert_eq!(Some("second"), message.text()),
+            ref other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(3, connections);
+    }
+
+    #[test]
+    fn test_client_keeps_connection_after_malformed_event() {
+        let url = serve(vec![|socket| {
+            send(socket, ::serde_json::json!({"type": "hello"}));
+            socket.send(Frame::Text("not json".into())).unwrap();
+            send(socket, ::serde_json::json!({"type": "message", "text": "still here"}));
+            socket.close(None).unwrap();
+            while socket.read().is_ok() {}
+        }]);
+
+        let config = Config { reconnect: false, ..Config::default() };
+        let mut connections = 0;
+        let client = Client::<NoError>::connect_with(config, || {
+            connections += 1;
+            Ok(url.clone())
+        }).unwrap();
+
+        let events = client.collect::<Vec<_>>();
+        assert_eq!(3, events.len());
+        match events[1] {
+            Err(RtmError::MalformedEvent(_)) => {}
+            ref other => panic!("unexpected {:?}", other),
+        }
+        match events[2] {
+            Ok(Event::Message(ref message)) => assert_eq!(Some("still here"), message.text()),
+            ref other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(1, connections);
+    }
+
+    pub fn read_json(socket: &mut WebSocket<TcpStream>) -> Value {
+        match socket.read().unwrap() {
+            Frame::Text(text) => serde_json::from_str(&text).unwrap(),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_client_sends_messages() {
+        let url = serve(vec![|socket| {
+            send(socket, ::serde_json::json!({"type": "hello"}));
+            let message = read_json(socket);
+            assert_eq!(::serde_json::json!(["message", "C1", "hi"]), ::serde_json::json!([message["type"], message["channel"], message["text"]]));
+            send(socket, ::serde_json::json!({"type": "user_typing", "channel": "C1", "user": "U2"}));
+            send(socket, ::serde_json::json!({"ok": true, "reply_to": message["id"], "ts": "1.0", "text": "hi"}));
+            let message = read_json(socket);
+            send(socket, ::serde_json::json!({"ok": false, "reply_to": message["id"], "error": {"code": 2, "msg": "message text is missing"}}));
+            let typing = read_json(socket);
+            assert_eq!("typing", typing["type"]);
+            socket.close(None).unwrap();
+            while socket.read().is_ok() {}
+        }]);
+
+        let config = Config { reconnect: false, ..Config::default() };
+        let mut client = Client::<NoError>::connect_with(config, || Ok(url.clone())).unwrap();
+        client.set_fallback(|channel, text| Ok(Reply { ts: Some("2.0".to_owned()), text: Some(format!("{} {}", channel, text)) }));
+
+        let reply = client.post_message("C1", "hi").unwrap();
+        assert_eq!(Some("1.0"), reply.ts.as_deref());
+        let id = client.send_message("C1", "").unwrap();
+        match client.wait_for_reply(id) {
+            Err(RtmError::Rejected(ref error)) => assert_eq!(Some(2), error.code),
+            ref other => panic!("unexpected {:?}", other),
+        }
+        client.send_typing("C1").unwrap();
+
+        let types = client.by_ref().map(|event| event.unwrap().ty().to_owned()).collect::<Vec<_>>();
+        assert_eq!(vec!["hello", "user_typing"], types);
+        let reply = client.post_message("C1", "later").unwrap();
+        assert_eq!(Some("C1 later"), reply.text.as_deref());
+    }
+}
@@ -0,0 +1,184 @@
+//! Subscribing to the presence of users.
+//!
+//! When a connection is opened with `batch_presence_aware`, Slack only sends `presence_change`
+//! for the users the client has subscribed to with a `presence_sub` frame, and batches them.
+//! Subscriptions only last as long as the connection. To open such a connection, pass
+//! `Client::connect_with` a source which calls `rtm::start` with `batch_presence_aware` set.
+
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+
+use super::{Client, RtmError};
+use event::Event;
+
+/// Whether a user is online.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Presence {
+    Active,
+    Away,
+}
+
+impl Presence {
+    /// Parses the `presence` of an event or `users.getPresence`.
+    pub fn parse(presence: &str) -> Option<Self> {
+        match presence {
+            "active" => Some(Presence::Active),
+            "away" => Some(Presence::Away),
+            _ => None,
+        }
+    }
+}
+
+/// The set of users subscribed to, and the last presence received for each.
+///
+/// Every event should be passed to [`handle`], and [`sync`] called afterwards to send any change
+/// to the subscriptions, including subscribing again after a reconnect:
+///
+/// ```no_run
+/// # fn run<R: slack_api::requests::SlackWebRequestSender>(client: &R) {
+/// use slack_api::rtm_client::{Client, PresenceTracker};
+///
+/// let mut rtm = Client::connect(client, "xoxb-token").unwrap();
+/// let mut presence = PresenceTracker::new();
+/// presence.subscribe("U024BE7LH");
+/// while let Some(event) = rtm.next() {
+///     let event = event.unwrap();
+///     presence.handle(&event);
+///     presence.sync(&mut rtm).unwrap();
+///     println!("{:?}", presence.presence("U024BE7LH"));
+/// }
+/// # }
+/// ```
+///
+/// [`handle`]: #method.handle
+/// [`sync`]: #method.sync
+#[derive(Clone, Debug, Default)]
+pub struct PresenceTracker {
+    subscribed: BTreeSet<String>,
+    presence: HashMap<String, Presence>,
+    /// Whether the subscriptions have changed since they were last sent.
+    changed: bool,
+}
+
+impl PresenceTracker {
+    pub fn new() -> Self {
+        PresenceTracker::default()
+    }
+
+    /// Adds a user to the subscriptions, returning `false` if they were already subscribed.
+    pub fn subscribe(&mut self, user: impl Into<String>) -> bool {
+        let added = self.subscribed.insert(user.into());
+        self.changed |= added;
+        added
+    }
+
+    /// Removes a user from the subscriptions and forgets their presence, returning `false` if
+    /// they weren't subscribed.
+    pub fn unsubscribe(&mut self, user: &str) -> bool {
+        self.presence.remove(user);
+        let removed = self.subscribed.remove(user);
+        self.changed |= removed;
+        removed
+    }
+
+    /// The users subscribed to, in order of id.
+    pub fn subscribed(&self) -> impl Iterator<Item = &str> {
+        self.subscribed.iter().map(String::as_str)
+    }
+
+    /// The last presence received for a subscribed user.
+    pub fn presence(&self, user: &str) -> Option<Presence> {
+        self.presence.get(user).cloned()
+    }
+
+    /// Updates the presence of subscribed users from a `presence_change` event, and notes that
+    /// the subscriptions must be sent again after `hello` on a new connection.
+    pub fn handle(&mut self, event: &Event) {
+        match *event {
+            Event::Hello(_) => {
+                self.changed = !self.subscribed.is_empty();
+                self.presence.clear();
+            }
+            Event::PresenceChange(ref change) => {
+                let presence = match change.presence.as_ref().and_then(|p| Presence::parse(p)) {
+                    Some(presence) => presence,
+                    None => return,
+                };
+                for user in change.user.iter().chain(change.users.iter().flatten()) {
+                    if self.subscribed.contains(user) {
+                        self.presence.insert(user.clone(), presence);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Sends the subscriptions if they have changed, replacing those Slack has for the
+    /// connection. Slack replies with the presence of any newly subscribed users.
+    pub fn sync<E: Error>(&mut self, rtm: &mut Client<E>) -> Result<(), RtmError<E>> {
+        if !self.changed {
+            return Ok(());
+        }
+        let ids = self.subscribed.iter().collect::<Vec<_>>();
+        rtm.send_json(&::serde_json::json!({"type": "presence_sub", "ids": ids}))?;
+        self.changed = false;
+        Ok(())
+    }
+
+    /// Asks Slack for the current presence of some users, which arrives as `presence_change`.
+    /// Only subscribed users are tracked.
+    pub fn query<E: Error>(&self, rtm: &mut Client<E>, users: &[&str]) -> Result<(), RtmError<E>> {
+        rtm.send_json(&::serde_json::json!({"type": "presence_query", "ids": users}))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{read_json, send, serve};
+    use super::super::Config;
+    use super::*;
+    use std::time::Duration;
+    use tests::NoError;
+
+    #[test]
+    fn test_tracker_resubscribes_after_reconnect() {
+        let url = serve(vec![
+            |socket| {
+                send(socket, ::serde_json::json!({"type": "hello"}));
+                assert_eq!(::serde_json::json!({"type": "presence_sub", "ids": ["U1", "U2"]}), read_json(socket));
+                send(socket, ::serde_json::json!({"type": "presence_change", "users": ["U1", "U2", "U3"], "presence": "away"}));
+                send(socket, ::serde_json::json!({"type": "presence_change", "user": "U1", "presence": "active"}));
+                send(socket, ::serde_json::json!({"type": "goodbye"}));
+            },
+            |socket| {
+                send(socket, ::serde_json::json!({"type": "hello"}));
+                assert_eq!(::serde_json::json!({"type": "presence_sub", "ids": ["U1", "U2"]}), read_json(socket));
+                socket.close(None).unwrap();
+                while socket.read().is_ok() {}
+            },
+        ]);
+        let config = Config {
+            initial_backoff: Duration::from_millis(10),
+            max_reconnect_attempts: Some(1),
+            ..Config::default()
+        };
+        let mut rtm = Client::<NoError>::connect_with(config, || Ok(url.clone())).unwrap();
+        let mut tracker = PresenceTracker::new();
+        assert!(tracker.subscribe("U2"));
+        assert!(tracker.subscribe("U1"));
+        assert!(!tracker.subscribe("U1"));
+
+        let mut seen = Vec::new();
+        while let Some(Ok(event)) = rtm.next() {
+            tracker.handle(&event);
+            tracker.sync(&mut rtm).unwrap();
+            if let Event::PresenceChange(_) = event {
+                seen.push((tracker.presence("U1"), tracker.presence("U2"), tracker.presence("U3")));
+            }
+        }
+        let (active, away) = (Some(Presence::Active), Some(Presence::Away));
+        assert_eq!(vec![(away, away, None), (active, away, None)], seen);
+        assert_eq!(vec!["U1", "U2"], tracker.subscribed().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,296 @@
+//! Catching up on messages missed while the connection was down.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+
+use super::{Client, RtmError};
+use conversations;
+use event::Event;
+use history::{self, ConversationKind, Direction, HistoryError, WalkRequest};
+use requests::SlackWebRequestSender;
+use types::{Message, Timestamp};
+
+/// What a [`Session`](struct.Session.html) yields.
+#[derive(Clone, Debug)]
+pub struct SessionEvent {
+    pub event: Event,
+    /// For a message posted while the connection was down and read from history, the channel it
+    /// was posted in, which history doesn't include in the message. `None` for events received
+    /// over the websocket.
+    pub missed_in: Option<String>,
+}
+
+/// An RTM connection which, after reconnecting, fetches the messages it missed.
+///
+/// The session remembers the `ts` of the last message seen in each channel. When Slack says
+/// `hello` on a new connection, the history of each of those channels since then is fetched and
+/// yielded with `missed_in` set, oldest first, straight after the `hello`. Messages which arrive live as
+/// well are only yielded once.
+///
+/// Only channels the session has seen a message in are caught up, and replies in threads are
+/// not included unless they were also sent to the channel. The kind of each conversation is looked
+/// up with `conversations.info` the first time it is caught up, unless it was given to [`track`].
+///
+/// [`track`]: #method.track
+pub struct Session<'a, R: 'a + SlackWebRequestSender> {
+    client: &'a R,
+    token: String,
+    rtm: Client<'a, R::Error>,
+    /// The conversations seen or tracked, their kind if known, and the `ts` of the last message
+    /// seen in each.
+    channels: HashMap<String, (Option<ConversationKind>, Option<String>)>,
+    /// The timestamps yielded by the last catch-up, to drop them if they also arrive live.
+    missed: HashSet<(String, String)>,
+    pending: VecDeque<Result<SessionEvent, SessionError<R::Error>>>,
+    connected: bool,
+}
+
+impl<'a, R: SlackWebRequestSender> Session<'a, R> {
+    /// Connects to a websocket URL requested with `rtm.connect`.
+    pub fn connect(client: &'a R, token: &str) -> Result<Self, RtmError<R::Error>> {
+        let rtm = Client::connect(client, token)?;
+        Ok(Session::new(client, token, rtm))
+    }
+
+    /// Wraps an existing connection. `client` and `token` are used to read history.
+    pub fn new(client: &'a R, token: &str, rtm: Client<'a, R::Error>) -> Self {
+        Session {
+            client,
+            token: token.to_owned(),
+            rtm,
+            channels: HashMap::new(),
+            missed: HashSet::new(),
+            pending: VecDeque::new(),
+            connected: false,
+        }
+    }
+
+    /// The connection, for sending messages.
+    pub fn rtm(&mut self) -> &mut Client<'a, R::Error> {
+        &mut self.rtm
+    }
+
+    /// Sets the kind of a conversation, and catches up on it after reconnecting from `since`,
+    /// unless a later message has been seen.
+    pub fn track(&mut self, channel: impl Into<String>, kind: ConversationKind, since: Option<&str>) {
+        let entry = self.channels.entry(channel.into()).or_insert((None, None));
+        entry.0 = Some(kind);
+        if let Some(since) = since {
+            if entry.1.as_ref().map_or(true, |last| is_later(since, last)) {
+                entry.1 = Some(since.to_owned());
+            }
+        }
+    }
+
+    /// The `ts` of the last message seen in a channel.
+    pub fn last_seen(&self, channel: &str) -> Option<&str> {
+        self.channels.get(channel).and_then(|entry| entry.1.as_deref())
+    }
+
+    /// Records a live message, returning `false` if it was already yielded by the catch-up.
+    fn see(&mut self, message: &Message) -> bool {
+        let (channel, ts) = match (message.channel(), message.ts()) {
+            (Some(channel), Some(ts)) => (channel, ts),
+            _ => return true,
+        };
+        if self.missed.contains(&(channel.to_owned(), ts.to_owned())) {
+            return false;
+        }
+        let entry = self.channels.entry(channel.to_owned()).or_insert((None, None));
+        if entry.1.as_ref().map_or(true, |last| is_later(ts, last)) {
+            entry.1 = Some(ts.to_owned());
+        }
+        true
+    }
+
+    /// Looks up the kind of every conversation that needs catching up but whose kind isn't known.
+    fn look_up_kinds(&mut self) -> Result<(), SessionError<R::Error>> {
+        let unknown = self
+            .channels
+            .iter()
+            .filter(|&(_, entry)| entry.0.is_none() && entry.1.is_some())
+            .map(|(channel, _)| channel.clone())
+            .collect::<Vec<_>>();
+        for channel in unknown {
+            let request = conversations::InfoRequest::new(&channel[..]);
+            let response = conversations::info(self.client, &self.token, &request).map_err(SessionError::Info)?;
+            if let (Some(conversation), Some(entry)) = (response.channel, self.channels.get_mut(&channel)) {
+                entry.0 = Some(ConversationKind::of(&conversation));
+            }
+        }
+        Ok(())
+    }
+
+    /// Fetches the messages posted since the last one seen in every channel and queues them
+    /// oldest first, followed by the first error if any channel failed.
+    fn catch_up(&mut self) {
+        self.missed.clear();
+        let mut missed = Vec::new();
+        let mut error = self.look_up_kinds().err();
+        for (channel, &(kind, ref last)) in &self.channels {
+            let (kind, last) = match (kind, last) {
+                (Some(kind), Some(last)) => (kind, last),
+                _ => continue,
+            };
+            let oldest = match last.parse::<Timestamp>() {
+                Ok(oldest) => oldest,
+                Err(_) => continue,
+            };
+            let request = WalkRequest::new(kind, &channel[..]).oldest(oldest).direction(Direction::OldestFirst);
+            for message in history::walk(self.client, &self.token, &request) {
+                match message {
+                    Ok(ref message) if message.ts().map_or(true, |ts| !is_later(ts, last)) => {}
+                    Ok(message) => missed.push((channel.clone(), message)),
+                    Err(e) => {
+                        error = error.or(Some(SessionError::History(e)));
+                        break;
+                    }
+                }
+            }
+        }
+
+        missed.sort_by(|a, b| history::compare_ts(&a.1, &b.1));
+        for (channel, message) in missed {
+            if let Some(ts) = message.ts() {
+                self.missed.insert((channel.clone(), ts.to_owned()));
+                if let Some(entry) = self.channels.get_mut(&channel) {
+                    if entry.1.as_ref().map_or(true, |last| is_later(ts, last)) {
+                        entry.1 = Some(ts.to_owned());
+                    }
+                }
+            }
+            self.pending.push_back(Ok(SessionEvent { event: Event::Message(message), missed_in: Some(channel) }));
+        }
+        if let Some(e) = error {
+            self.pending.push_back(Err(e));
+        }
+    }
+}
+
+impl<'a, R: SlackWebRequestSender> Iterator for Session<'a, R> {
+    type Item = Result<SessionEvent, SessionError<R::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(event) = self.pending.pop_front() {
+            return Some(event);
+        }
+        loop {
+            let event = match self.rtm.next()? {
+                Ok(event) => event,
+                Err(e) => return Some(Err(SessionError::Rtm(e))),
+            };
+            match event {
+                Event::Hello(_) if self.connected => self.catch_up(),
+                Event::Hello(_) => self.connected = true,
+                Event::Message(ref message) if !self.see(message) => continue,
+                _ => {}
+            }
+            return Some(Ok(SessionEvent { event, missed_in: None }));
+        }
+    }
+}
+
+fn is_later(ts: &str, than: &str) -> bool {
+    match (ts.parse::<Timestamp>(), than.parse::<Timestamp>()) {
+        (Ok(ts), Ok(than)) if ts != than => ts > than,
+        // Timestamps which are equal as floats may still differ in the last digits.
+        _ => (ts.len(), ts) > (than.len(), than),
+    }
+}
+
+#[derive(Debug)]
+pub enum SessionError<E: Error> {
+    /// The connection failed.
+    Rtm(RtmError<E>),
+    /// Reading the history of a channel failed, so some missed messages weren't yielded.
+    History(HistoryError<E>),
+    /// Looking up the kind of a channel failed, so it wasn't caught up.
+    Info(conversations::InfoError<E>),
+}
+
+impl<E: Error> fmt::Display for SessionError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SessionError::Rtm(ref e) => write!(f, "{}", e),
+            SessionError::History(ref e) => write!(f, "{}", e),
+            SessionError::Info(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<E: Error> Error for SessionError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            SessionError::Rtm(ref e) => e.description(),
+            SessionError::History(ref e) => e.description(),
+            SessionError::Info(ref e) => e.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            SessionError::Rtm(ref e) => Some(e),
+            SessionError::History(ref e) => Some(e),
+            SessionError::Info(ref e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{send, serve};
+    use super::super::Config;
+    use super::*;
+    use std::time::Duration;
+    use tests::{param, Replay};
+
+    #[test]
+    fn test_session_catches_up_after_reconnect() {
+        let url = serve(vec![
+            |socket| {
+                send(socket, ::serde_json::json!({"type": "hello"}));
+                send(socket, ::serde_json::json!({"type": "message", "channel": "C1", "ts": "1500000001.000100", "text": "a"}));
+                send(socket, ::serde_json::json!({"type": "goodbye"}));
+            },
+            |socket| {
+                send(socket, ::serde_json::json!({"type": "hello"}));
+                send(socket, ::serde_json::json!({"type": "message", "channel": "C1", "ts": "1500000003.000100", "text": "c"}));
+                send(socket, ::serde_json::json!({"type": "message", "channel": "C1", "ts": "1500000004.000100", "text": "d"}));
+                socket.close(None).unwrap();
+                while socket.read().is_ok() {}
+            },
+        ]);
+        let client = Replay::new(vec![
+            r#"{"ok": true, "channel": {"id": "C1", "is_channel": true, "is_private": true}}"#,
+            r#"{"ok": true, "has_more": false, "messages": [
+                {"type": "message", "ts": "1500000003.000100", "text": "c"},
+                {"type": "message", "ts": "1500000002.000100", "text": "b"},
+                {"type": "message", "ts": "1500000001.000100", "text": "a"}]}"#,
+        ]);
+        let config = Config {
+            initial_backoff: Duration::from_millis(10),
+            max_reconnect_attempts: Some(1),
+            ..Config::default()
+        };
+        let rtm = Client::connect_with(config, || Ok(url.clone())).unwrap();
+        let session = Session::new(&client, "token", rtm);
+
+        let events = session
+            .filter_map(Result::ok)
+            .map(|item| match (item.event, item.missed_in) {
+                (Event::Message(message), Some(channel)) => format!("missed {} {}", channel, message.text().unwrap()),
+                (Event::Message(message), None) => format!("live {}", message.text().unwrap()),
+                (event, _) => event.ty().to_owned(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["hello", "live a", "goodbye", "hello", "missed C1 b", "missed C1 c", "live d"],
+            events
+        );
+        let requests = client.requests.borrow();
+        assert!(requests[0].0.ends_with("conversations.info"));
+        assert!(requests[1].0.ends_with("groups.history"));
+        assert_eq!(Some("C1".to_owned()), param(&requests[1], "channel"));
+    }
+}
@@ -0,0 +1,290 @@
+//! Verifying that requests to an app's endpoints came from Slack.
+//!
+//! Slack signs every request it sends to the Events API, slash command and interactivity URLs
+//! with the app's signing secret. The signature is an HMAC-SHA256 of `v0:{timestamp}:{body}`,
+//! sent in the `X-Slack-Signature` header with the timestamp in `X-Slack-Request-Timestamp`:
+//!
+//! ```
+//! use slack_api::signature::Verifier;
+//!
+//! # let verifier = Verifier::new("8f742231b10e8888abcd99yyyzzz85a5");
+//! # let body = b"token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J";
+//! # let timestamp = std::time::SystemTime::now()
+//! #     .duration_since(std::time::UNIX_EPOCH).unwrap().as_secs().to_string();
+//! # let signature = verifier.sign(&timestamp, body);
+//! // With the headers and raw body of the request:
+//! let verifier = Verifier::new("8f742231b10e8888abcd99yyyzzz85a5");
+//! verifier.verify(Some(&timestamp), Some(&signature), body).unwrap();
+//! ```
+//!
+//! [`Receiver`] combines the check with parsing the body, for the payloads of the Events API,
+//! slash commands and interactions. This module requires the `signing` feature.
+//!
+//! [`Receiver`]: struct.Receiver.html
+
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use serde_json;
+use sha2::Sha256;
+
+/// The header holding the time the request was sent, in seconds since the Unix epoch.
+pub const TIMESTAMP_HEADER: &str = "X-Slack-Request-Timestamp";
+/// The header holding the signature.
+pub const SIGNATURE_HEADER: &str = "X-Slack-Signature";
+
+/// The only version of the signature scheme, which prefixes the signature and the signed text.
+const VERSION: &str = "v0";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Checks request signatures with an app's signing secret.
+#[derive(Clone, Debug)]
+pub struct Verifier {
+    secret: String,
+    max_age: Duration,
+}
+
+impl Verifier {
+    /// Creates a verifier which accepts requests sent up to five minutes ago, as Slack recommends.
+    pub fn new(signing_secret: impl Into<String>) -> Self {
+        Verifier { secret: signing_secret.into(), max_age: Duration::from_secs(5 * 60) }
+    }
+
+    /// Sets how far the timestamp of a request may be from the current time. Older requests are
+    /// rejected so that a captured request can't be replayed later.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Computes the signature Slack would send for a body sent at `timestamp`.
+    pub fn sign(&self, timestamp: &str, body: &[u8]) -> String {
+        let bytes = self.mac(timestamp, body).finalize().into_bytes();
+        let mut signature = format!("{}=", VERSION);
+        for byte in bytes {
+            signature.push_str(&format!("{:02x}", byte));
+        }
+        signature
+    }
+
+    /// Checks the values of the timestamp and signature headers against the raw body of a
+    /// request, which must not have been parsed and re-encoded.
+    pub fn verify(&self, timestamp: Option<&str>, signature: Option<&str>, body: &[u8]) -> Result<(), SignatureError> {
+        self.verify_at(timestamp, signature, body, SystemTime::now())
+    }
+
+    /// Like [`verify`](#method.verify), but as if the current time were `now`.
+    pub fn verify_at(
+        &self,
+        timestamp: Option<&str>,
+        signature: Option<&str>,
+        body: &[u8],
+        now: SystemTime,
+    ) -> Result<(), SignatureError> {
+        let timestamp = timestamp.ok_or(SignatureError::MissingTimestamp)?;
+        let signature = signature.ok_or(SignatureError::MissingSignature)?;
+
+        let sent = timestamp.trim().parse::<u64>().map_err(|_| SignatureError::InvalidTimestamp)?;
+        let now = now.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        if now.max(sent) - now.min(sent) > self.max_age.as_secs() {
+            return Err(SignatureError::Expired);
+        }
+
+        let expected = signature
+            .strip_prefix(VERSION)
+            .and_then(|s| s.strip_prefix('='))
+            .and_then(decode_hex)
+            .ok_or(SignatureError::Mismatch)?;
+        // Compares in constant time, so the signature can't be guessed a byte at a time.
+        self.mac(timestamp, body).verify_slice(&expected).map_err(|_| SignatureError::Mismatch)
+    }
+
+    fn mac(&self, timestamp: &str, body: &[u8]) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(self.secret.as_bytes()).expect("HMAC takes keys of any length");
+        mac.update(VERSION.as_bytes());
+        mac.update(b":");
+        mac.update(timestamp.as_bytes());
+        mac.update(b":");
+        mac.update(body);
+        mac
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| hex.get(i..i + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()))
+        .collect()
+}
+
+/// Compares a legacy verification token with the one sent in a payload, in constant time.
+///
+/// Verification tokens are deprecated in favour of signatures, but are still sent in the body of
+/// every request.
+pub fn verify_token(expected: &str, token: Option<&str>) -> bool {
+    let token = match token {
+        Some(token) if token.len() == expected.len() => token,
+        _ => return false,
+    };
+    token.bytes().zip(expected.bytes()).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+/// A payload Slack signs, which can be parsed from the body of a request.
+pub trait SignedPayload: Sized {
+    /// Parses the raw body of a request.
+    fn parse(body: &[u8]) -> Result<Self, serde_json::Error>;
+
+    /// The legacy verification token included in the payload.
+    fn token(&self) -> Option<&str>;
+}
+
+/// Checks requests against the app's signing secret, and optionally also against the legacy
+/// verification token, before parsing them as `T`.
+#[derive(Clone, Debug)]
+pub struct Receiver<T> {
+    verifier: Verifier,
+    token: Option<String>,
+    payload: PhantomData<fn() -> T>,
+}
+
+impl<T: SignedPayload> Receiver<T> {
+    /// Creates a receiver which checks signatures with the app's signing secret.
+    pub fn new(signing_secret: impl Into<String>) -> Self {
+        Receiver::with_verifier(Verifier::new(signing_secret))
+    }
+
+    /// Creates a receiver which checks signatures with a configured verifier.
+    pub fn with_verifier(verifier: Verifier) -> Self {
+        Receiver { verifier, token: None, payload: PhantomData }
+    }
+
+    /// Also requires payloads to include the legacy verification token.
+    pub fn verification_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Checks a request, given the values of its `X-Slack-Request-Timestamp` and
+    /// `X-Slack-Signature` headers and its raw body, and parses the payload.
+    pub fn receive(&self, timestamp: Option<&str>, signature: Option<&str>, body: &[u8]) -> Result<T, ReceiveError> {
+        self.receive_at(timestamp, signature, body, SystemTime::now())
+    }
+
+    /// Like [`receive`](#method.receive), but as if the current time were `now`.
+    pub fn receive_at(
+        &self,
+        timestamp: Option<&str>,
+        signature: Option<&str>,
+        body: &[u8],
+        now: SystemTime,
+    ) -> Result<T, ReceiveError> {
+        self.verifier.verify_at(timestamp, signature, body, now).map_err(ReceiveError::Signature)?;
+        let payload = T::parse(body).map_err(ReceiveError::MalformedPayload)?;
+        match self.token {
+            Some(ref token) if !verify_token(token, payload.token()) => Err(ReceiveError::InvalidToken),
+            _ => Ok(payload),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ReceiveError {
+    /// The request wasn't signed by Slack.
+    Signature(SignatureError),
+    /// The payload doesn't include the verification token.
+    InvalidToken,
+    /// The body isn't a valid payload.
+    MalformedPayload(serde_json::Error),
+}
+
+impl fmt::Display for ReceiveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for ReceiveError {
+    fn description(&self) -> &str {
+        match *self {
+            ReceiveError::Signature(ref e) => e.description(),
+            ReceiveError::InvalidToken => "the verification token doesn't match",
+            ReceiveError::MalformedPayload(ref e) => e.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            ReceiveError::Signature(ref e) => Some(e),
+            ReceiveError::InvalidToken => None,
+            ReceiveError::MalformedPayload(ref e) => Some(e),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureError {
+    /// The request has no `X-Slack-Request-Timestamp` header.
+    MissingTimestamp,
+    /// The request has no `X-Slack-Signature` header.
+    MissingSignature,
+    /// The timestamp isn't a number of seconds.
+    InvalidTimestamp,
+    /// The request was sent too long ago, or claims to have been sent in the future.
+    Expired,
+    /// The signature doesn't match the body.
+    Mismatch,
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for SignatureError {
+    fn description(&self) -> &str {
+        match *self {
+            SignatureError::MissingTimestamp => "the request has no timestamp",
+            SignatureError::MissingSignature => "the request has no signature",
+            SignatureError::InvalidTimestamp => "the request timestamp is invalid",
+            SignatureError::Expired => "the request timestamp is too far from the current time",
+            SignatureError::Mismatch => "the request signature doesn't match",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify() {
+        // The example from Slack's documentation.
+        let verifier = Verifier::new("8f742231b10e8888abcd99yyyzzz85a5");
+        let body = b"token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+        let signature = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+        let now = UNIX_EPOCH + Duration::from_secs(1531420618 + 60);
+
+        assert_eq!(signature, verifier.sign("1531420618", body));
+        assert_eq!(Ok(()), verifier.verify_at(Some("1531420618"), Some(signature), body, now));
+        assert_eq!(Err(SignatureError::Mismatch), verifier.verify_at(Some("1531420618"), Some(signature), b"token=x", now));
+        assert_eq!(Err(SignatureError::Mismatch), verifier.verify_at(Some("1531420618"), Some("v1=a2"), body, now));
+        assert_eq!(
+            Err(SignatureError::Expired),
+            verifier.verify_at(Some("1531420618"), Some(signature), body, now + Duration::from_secs(600))
+        );
+        assert_eq!(Err(SignatureError::MissingSignature), verifier.verify_at(Some("1531420618"), None, body, now));
+        assert_eq!(Err(SignatureError::InvalidTimestamp), verifier.verify_at(Some("soon"), Some(signature), body, now));
+
+        assert!(verify_token("abc", Some("abc")));
+        assert!(!verify_token("abc", Some("abd")));
+        assert!(!verify_token("abc", None));
+    }
+}
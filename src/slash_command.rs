@@ -0,0 +1,232 @@
+//! Requests sent to an app's endpoint when a user invokes one of its
+//! [slash commands](https://api.slack.com/interactivity/slash-commands), and the replies to them.
+//!
+//! Slack POSTs the command as a form. The endpoint can reply immediately in the body of the
+//! response, and may post up to five more replies to the command's `response_url` within
+//! thirty minutes:
+//!
+//! ```no_run
+//! # fn run<P: slack_api::requests::SlackJsonPoster>(poster: &P, body: &[u8]) {
+//! use slack_api::slash_command::{Response, SlashCommand};
+//!
+//! let command = SlashCommand::parse(body).unwrap();
+//! // The body of the response to the request:
+//! let reply = Response::ephemeral(format!("Deploying {}...", command.text)).to_json();
+//! // Later:
+//! command.respond(poster, &Response::in_channel("Deployed").replace_original(true)).unwrap();
+//! # let _ = reply;
+//! # }
+//! ```
+//!
+//! Commands should be received through [`Receiver`] when the `signing` feature is enabled, so
+//! that forged requests are rejected.
+//!
+//! [`Receiver`]: type.Receiver.html
+
+use serde_json::{self, Map, Value};
+
+use attachments::Attachment;
+use blocks::Block;
+use form;
+use requests::SlackJsonPoster;
+#[cfg(feature = "signing")]
+use signature::{self, SignedPayload};
+
+/// An invocation of a slash command.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SlashCommand {
+    /// The command, including the leading `/`.
+    pub command: String,
+    /// Everything the user typed after the command.
+    #[serde(default)]
+    pub text: String,
+    pub user_id: String,
+    pub channel_id: String,
+    pub team_id: String,
+    /// Opens a modal in response to the command, within three seconds.
+    pub trigger_id: String,
+    /// Where delayed replies are posted.
+    pub response_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_app_id: Option<String>,
+    /// `"true"` if the app is installed across an Enterprise Grid organization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enterprise_install: Option<String>,
+    /// The legacy verification token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+impl SlashCommand {
+    /// Parses the form-encoded body of a request. Fields this crate doesn't know are ignored.
+    pub fn parse(body: &[u8]) -> Result<Self, serde_json::Error> {
+        let fields = form::parse(&String::from_utf8_lossy(body))
+            .into_iter()
+            .map(|(name, value)| (name, Value::String(value)))
+            .collect::<Map<_, _>>();
+        serde_json::from_value(Value::Object(fields))
+    }
+
+    /// Posts a delayed reply to the command's `response_url`.
+    pub fn respond<P: SlackJsonPoster>(&self, poster: &P, response: &Response) -> Result<(), P::Error> {
+        response.send_to(poster, &self.response_url)
+    }
+}
+
+/// Who can see a reply.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseType {
+    /// Only the user who invoked the command. This is the default.
+    Ephemeral,
+    /// Everyone in the channel, along with the command itself.
+    InChannel,
+}
+
+/// A reply to a slash command or interaction, sent either as the body of the response to the
+/// request or to its `response_url`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Response {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_type: Option<ResponseType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Block>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<Attachment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+    /// Replaces the message the reply is for, which must have been sent by the app.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replace_original: Option<bool>,
+    /// Deletes the message the reply is for, which must have been sent by the app.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_original: Option<bool>,
+}
+
+impl Response {
+    /// Creates a reply only the user can see.
+    pub fn ephemeral(text: impl Into<String>) -> Self {
+        Response { response_type: Some(ResponseType::Ephemeral), text: Some(text.into()), ..Default::default() }
+    }
+
+    /// Creates a reply everyone in the channel can see.
+    pub fn in_channel(text: impl Into<String>) -> Self {
+        Response { response_type: Some(ResponseType::InChannel), text: Some(text.into()), ..Default::default() }
+    }
+
+    /// Creates a request to delete the message the reply would be for.
+    pub fn delete_original() -> Self {
+        Response { delete_original: Some(true), ..Default::default() }
+    }
+
+    /// Serializes the reply, as the body of the response to the request or a `response_url` post.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("responses always serialize to JSON")
+    }
+
+    /// Posts the reply to a `response_url`.
+    pub fn send_to<P: SlackJsonPoster>(&self, poster: &P, response_url: &str) -> Result<(), P::Error> {
+        poster.post_json(response_url, &self.to_json()).map(|_| ())
+    }
+}
+
+setters!(Response {
+    response_type: ResponseType,
+    text: String,
+    blocks: Vec<Block>,
+    attachments: Vec<Attachment>,
+    thread_ts: String,
+    /// Replaces the message the reply is for, which must have been sent by the app.
+    replace_original: bool,
+});
+
+/// Checks and parses requests to a slash command endpoint. Requires the `signing` feature.
+#[cfg(feature = "signing")]
+pub type Receiver = signature::Receiver<SlashCommand>;
+
+#[cfg(feature = "signing")]
+impl SignedPayload for SlashCommand {
+    fn parse(body: &[u8]) -> Result<Self, serde_json::Error> {
+        SlashCommand::parse(body)
+    }
+
+    fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use tests::NoError;
+
+    // The example from Slack's documentation.
+    const BODY: &[u8] = b"token=gIkuvaNzQIHg97ATvDxqgjtO&team_id=T0001&team_domain=example&enterprise_id=E0001&enterprise_name=Globular%20Construct%20Inc&channel_id=C2147483705&channel_name=test&user_id=U2147483697&user_name=Steve&command=/weather&text=94070&response_url=https://hooks.slack.com/commands/1234/5678&trigger_id=13345224609.738474920.8088930838d88f008e0&api_app_id=A123456";
+
+    #[derive(Default)]
+    struct Recorder {
+        posts: RefCell<Vec<(String, String)>>,
+    }
+
+    impl SlackJsonPoster for Recorder {
+        type Error = NoError;
+
+        fn post_json(&self, url: &str, body: &str) -> Result<String, NoError> {
+            self.posts.borrow_mut().push((url.to_owned(), body.to_owned()));
+            Ok("ok".to_owned())
+        }
+    }
+
+    #[test]
+    fn test_parse_and_respond() {
+        let command = SlashCommand::parse(BODY).unwrap();
+        assert_eq!("/weather", command.command);
+        assert_eq!("94070", command.text);
+        assert_eq!(Some("Globular Construct Inc"), command.enterprise_name.as_deref());
+        assert_eq!("https://hooks.slack.com/commands/1234/5678", command.response_url);
+        assert!(SlashCommand::parse(b"command=/weather&text=").is_err());
+
+        assert_eq!(r#"{"response_type":"ephemeral","text":"Checking..."}"#, Response::ephemeral("Checking...").to_json());
+        let poster = Recorder::default();
+        command.respond(&poster, &Response::in_channel("Sunny").replace_original(true)).unwrap();
+        command.respond(&poster, &Response::delete_original()).unwrap();
+        assert_eq!(
+            vec![
+                (command.response_url.clone(), r#"{"response_type":"in_channel","text":"Sunny","replace_original":true}"#.to_owned()),
+                (command.response_url.clone(), r#"{"delete_original":true}"#.to_owned()),
+            ],
+            *poster.posts.borrow()
+        );
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn test_receiver() {
+        use signature::{ReceiveError, Verifier};
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let now = UNIX_EPOCH + Duration::from_secs(1600000000);
+        let signature = Verifier::new("secret").sign("1600000000", BODY);
+        let receiver = Receiver::new("secret").verification_token("gIkuvaNzQIHg97ATvDxqgjtO");
+        let command = receiver.receive_at(Some("1600000000"), Some(&signature), BODY, now).unwrap();
+        assert_eq!("/weather", command.command);
+
+        match Receiver::new("secret").receive_at(Some("1600000000"), Some(&signature), b"command=/weather", now) {
+            Err(ReceiveError::Signature(_)) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
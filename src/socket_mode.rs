@@ -0,0 +1,600 @@
+//! A client for [Socket Mode](https://api.slack.com/apis/connections/socket), which delivers
+//! events and interactions over a websocket instead of to a public HTTP endpoint.
+//!
+//! [`Client`] opens the websocket returned by `apps.connections.open`, which needs an app-level
+//! token, and yields each [`Envelope`] Slack sends. Every envelope must be acknowledged with
+//! [`Client::ack`] within a few seconds, or Slack will send it again. [`Client::run`] does this
+//! for you after passing each payload to a [`Handler`]:
+//!
+//! ```no_run
+//! # fn run<R: slack_api::requests::SlackWebRequestSender>(client: &R) {
+//! use slack_api::events_api::Payload;
+//! use slack_api::socket_mode::{Client, Handler};
+//!
+//! struct Printer;
+//!
+//! impl Handler for Printer {
+//!     fn events_api(&mut self, payload: &Payload) -> Option<serde_json::Value> {
+//!         println!("{:?}", payload);
+//!         None
+//!     }
+//! }
+//!
+//! let mut socket = Client::connect(client, "xapp-token").unwrap();
+//! socket.run(&mut Printer).unwrap();
+//! # }
+//! ```
+//!
+//! The client reconnects with a fresh URL when Slack asks it to refresh the connection, or when
+//! the connection drops. This module requires the `socket-mode` feature.
+//!
+//! [`Client`]: struct.Client.html
+//! [`Envelope`]: struct.Envelope.html
+//! [`Client::ack`]: struct.Client.html#method.ack
+//! [`Client::run`]: struct.Client.html#method.run
+//! [`Handler`]: trait.Handler.html
+
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+use std::net::TcpStream;
+use std::thread;
+use std::time::Duration;
+
+use serde_json::{self, Value};
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{self, Message as Frame, WebSocket};
+
+use apps_connections;
+use events_api;
+use interaction::{Interaction, ViewResponse};
+use requests::SlackWebRequestSender;
+use slash_command::{Response, SlashCommand};
+
+type Socket = WebSocket<MaybeTlsStream<TcpStream>>;
+
+/// Returns the URL of a new websocket.
+type UrlSource<'a, E> = Box<dyn FnMut() -> Result<String, SocketModeError<E>> + 'a>;
+
+/// How the connection is re-established.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// Whether to reconnect when the connection is lost.
+    pub reconnect: bool,
+    /// How long to wait before the first attempt to reconnect after the connection was lost. The
+    /// wait doubles after each failed attempt. Refreshes requested by Slack happen immediately.
+    pub initial_backoff: Duration,
+    /// The longest wait between attempts to reconnect.
+    pub max_backoff: Duration,
+    /// How many attempts in a row may fail before the client gives up, or `None` to keep trying.
+    pub max_reconnect_attempts: Option<u32>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            reconnect: true,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_reconnect_attempts: None,
+        }
+    }
+}
+
+/// A payload delivered over Socket Mode, which must be acknowledged.
+#[derive(Clone, Debug)]
+pub struct Envelope {
+    /// Identifies the envelope in its acknowledgement.
+    pub envelope_id: String,
+    pub request: Request,
+    /// Whether the acknowledgement may include a response, such as the reply to a slash command.
+    pub accepts_response_payload: bool,
+    /// How many times Slack has sent the envelope before.
+    pub retry_attempt: Option<u32>,
+    pub retry_reason: Option<String>,
+}
+
+/// The payload of an envelope, distinguished by the envelope's `type`.
+#[derive(Clone, Debug)]
+pub enum Request {
+    /// What the Events API would have sent to a request URL.
+    EventsApi(events_api::Payload),
+    /// An interaction with a block, shortcut or modal.
+    Interactive(Interaction),
+    /// An invocation of a slash command.
+    SlashCommands(SlashCommand),
+    /// A type this crate does not model yet, or a payload which doesn't match the model of its
+    /// type.
+    Unknown {
+        ty: String,
+        payload: Value,
+    },
+}
+
+/// Handles each kind of payload. The value returned is sent back in the acknowledgement, if the
+/// envelope accepts a response.
+///
+/// Slack expects the acknowledgement within three seconds, so slow work should be done
+/// elsewhere.
+pub trait Handler {
+    fn events_api(&mut self, _payload: &events_api::Payload) -> Option<Value> {
+        None
+    }
+
+    /// Returns the response to a `view_submission`, if any.
+    fn interactive(&mut self, _interaction: &Interaction) -> Option<ViewResponse> {
+        None
+    }
+
+    /// Returns the immediate reply to the command, if any.
+    fn slash_commands(&mut self, _command: &SlashCommand) -> Option<Response> {
+        None
+    }
+
+    /// Called for envelopes of a type this crate doesn't model yet, and for payloads which
+    /// don't match the model of their type.
+    fn unknown(&mut self, _ty: &str, _payload: &Value) -> Option<Value> {
+        None
+    }
+}
+
+/// The parts of a message from Slack shared by envelopes, `hello` and `disconnect`.
+#[derive(Deserialize)]
+struct Incoming {
+    #[serde(rename = "type")]
+    ty: String,
+    envelope_id: Option<String>,
+    #[serde(default)]
+    payload: Value,
+    #[serde(default)]
+    accepts_response_payload: bool,
+    retry_attempt: Option<u32>,
+    retry_reason: Option<String>,
+    /// Why Slack is about to close the connection, for `disconnect`.
+    reason: Option<String>,
+}
+
+/// A Socket Mode connection, which is an iterator over the envelopes received.
+///
+/// A message which can't be parsed is yielded as `MalformedEnvelope` and the connection is kept.
+/// When the connection can't be re-established, the error is yielded and iteration ends.
+pub struct Client<'a, E: Error> {
+    source: UrlSource<'a, E>,
+    config: Config,
+    socket: Option<Socket>,
+    /// Why the last connection was lost, or `None` if Slack asked for it to be refreshed.
+    lost: Option<SocketModeError<E>>,
+    done: bool,
+}
+
+impl<'a, E: Error> Client<'a, E> {
+    /// Connects to a websocket URL requested with `apps.connections.open`.
+    pub fn connect<R>(client: &'a R, app_token: &str) -> Result<Client<'a, R::Error>, SocketModeError<R::Error>>
+    where
+        R: SlackWebRequestSender<Error = E>,
+    {
+        let app_token = app_token.to_owned();
+        Client::connect_with(Config::default(), move || {
+            let response = apps_connections::open(client, &app_token).map_err(SocketModeError::Open)?;
+            response.url.ok_or(SocketModeError::MissingUrl)
+        })
+    }
+
+    /// Connects to the websocket URLs returned by `source`, which is called again before every
+    /// attempt to reconnect.
+    pub fn connect_with<F>(config: Config, source: F) -> Result<Self, SocketModeError<E>>
+    where
+        F: FnMut() -> Result<String, SocketModeError<E>> + 'a,
+    {
+        let mut client = Client { source: Box::new(source), config, socket: None, lost: None, done: false };
+        client.open()?;
+        Ok(client)
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Changes how the connection is re-established from now on.
+    pub fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+
+    /// Acknowledges an envelope, with a response if it accepts one.
+    pub fn ack(&mut self, envelope_id: &str, payload: Option<&Value>) -> Result<(), SocketModeError<E>> {
+        let mut ack = ::serde_json::json!({ "envelope_id": envelope_id });
+        if let Some(payload) = payload {
+            ack["payload"] = payload.clone();
+        }
+        match self.socket {
+            Some(ref mut socket) => socket.send(Frame::Text(ack.to_string())).map_err(SocketModeError::from),
+            None => Err(tungstenite::Error::AlreadyClosed.into()),
+        }
+    }
+
+    /// Passes every envelope to `handler` and acknowledges it, until the connection is closed
+    /// or can't be re-established. Messages which can't be parsed are skipped.
+    pub fn run<H: Handler>(&mut self, handler: &mut H) -> Result<(), SocketModeError<E>> {
+        while let Some(envelope) = self.next() {
+            let envelope = match envelope {
+                Ok(envelope) => envelope,
+                // Without an envelope id there is nothing to acknowledge.
+                Err(SocketModeError::MalformedEnvelope(_)) => continue,
+                Err(e) => return Err(e),
+            };
+            let response = match envelope.request {
+                Request::EventsApi(ref payload) => handler.events_api(payload),
+                Request::Interactive(ref interaction) => handler
+                    .interactive(interaction)
+                    .map(|response| serde_json::to_value(response).expect("view responses always serialize to JSON")),
+                Request::SlashCommands(ref command) => handler
+                    .slash_commands(command)
+                    .map(|response| serde_json::to_value(response).expect("responses always serialize to JSON")),
+                Request::Unknown { ref ty, ref payload } => handler.unknown(ty, payload),
+            };
+            let response = response.filter(|_| envelope.accepts_response_payload);
+            self.ack(&envelope.envelope_id, response.as_ref())?;
+        }
+        Ok(())
+    }
+
+    /// Closes the connection. The iterator ends once Slack acknowledges the close.
+    pub fn close(&mut self) -> Result<(), SocketModeError<E>> {
+        self.config.reconnect = false;
+        match self.socket {
+            Some(ref mut socket) => socket.close(None).map_err(SocketModeError::from),
+            None => Ok(()),
+        }
+    }
+
+    /// Opens a websocket. Slack's `hello` is read along with the envelopes.
+    fn open(&mut self) -> Result<(), SocketModeError<E>> {
+        self.socket = None;
+        let url = (self.source)()?;
+        let (socket, _) = tungstenite::connect(url.as_str()).map_err(SocketModeError::from)?;
+        self.socket = Some(socket);
+        Ok(())
+    }
+
+    /// Reconnects, immediately if Slack asked for a refresh and otherwise waiting longer after
+    /// each failed attempt.
+    fn reconnect(&mut self) -> Result<(), SocketModeError<E>> {
+        let mut error = self.lost.take();
+        let mut backoff = if error.is_some() { self.config.initial_backoff } else { Duration::from_secs(0) };
+        let mut attempts = 0;
+        loop {
+            if !self.config.reconnect || self.config.max_reconnect_attempts.is_some_and(|max| attempts >= max) {
+                return Err(error.unwrap_or(SocketModeError::Closed));
+            }
+            thread::sleep(backoff);
+            attempts += 1;
+            match self.open() {
+                Ok(()) => return Ok(()),
+                Err(e) => error = Some(e),
+            }
+            backoff = cmp::min(cmp::max(backoff * 2, self.config.initial_backoff), self.config.max_backoff);
+        }
+    }
+
+    /// Reads the next message from Slack, or `None` if the connection was closed.
+    fn receive(&mut self) -> Result<Option<Incoming>, SocketModeError<E>> {
+        let socket = match self.socket {
+            Some(ref mut socket) => socket,
+            None => return Ok(None),
+        };
+        loop {
+            match socket.read() {
+                Ok(Frame::Text(text)) => return serde_json::from_str(&text).map(Some).map_err(SocketModeError::MalformedEnvelope),
+                Ok(_) => continue,
+                Err(tungstenite::Error::ConnectionClosed) | Err(tungstenite::Error::AlreadyClosed) => return Ok(None),
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+impl<'a, E: Error> Iterator for Client<'a, E> {
+    type Item = Result<Envelope, SocketModeError<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.done {
+                return None;
+            }
+            if self.socket.is_none() {
+                if let Err(e) = self.reconnect() {
+                    self.done = true;
+                    return match e {
+                        SocketModeError::Closed if !self.config.reconnect => None,
+                        e => Some(Err(e)),
+                    };
+                }
+                continue;
+            }
+
+            let incoming = match self.receive() {
+                Ok(Some(incoming)) => incoming,
+                Ok(None) => {
+                    self.socket = None;
+                    self.lost = Some(SocketModeError::Closed);
+                    continue;
+                }
+                Err(e @ SocketModeError::MalformedEnvelope(_)) => return Some(Err(e)),
+                Err(e) => {
+                    self.socket = None;
+                    self.lost = Some(e);
+                    continue;
+                }
+            };
+            match (&incoming.ty[..], incoming.envelope_id) {
+                ("disconnect", _) => {
+                    self.socket = None;
+                    if incoming.reason.as_deref() == Some("link_disabled") {
+                        self.done = true;
+                        return Some(Err(SocketModeError::LinkDisabled));
+                    }
+                }
+                (_, Some(envelope_id)) => {
+                    // A payload which doesn't match its model is still yielded, so that it is
+                    // acknowledged rather than redelivered.
+                    let payload = incoming.payload;
+                    let request = match &incoming.ty[..] {
+                        "events_api" => serde_json::from_value(payload.clone()).map(Request::EventsApi).ok(),
+                        "interactive" => serde_json::from_value(payload.clone()).map(Request::Interactive).ok(),
+                        "slash_commands" => serde_json::from_value(payload.clone()).map(Request::SlashCommands).ok(),
+                        _ => None,
+                    };
+                    let request = request.unwrap_or(Request::Unknown { ty: incoming.ty, payload });
+                    return Some(Ok(Envelope {
+                        envelope_id,
+                        request,
+                        accepts_response_payload: incoming.accepts_response_payload,
+                        retry_attempt: incoming.retry_attempt,
+                        retry_reason: incoming.retry_reason,
+                    }));
+                }
+                // `hello`, which carries nothing that needs handling.
+                _ => {}
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SocketModeError<E: Error> {
+    /// `apps.connections.open` failed.
+    Open(apps_connections::OpenError<E>),
+    /// `apps.connections.open` didn't return a websocket URL.
+    MissingUrl,
+    /// The websocket failed.
+    WebSocket(Box<tungstenite::Error>),
+    /// Slack sent a message which isn't a valid envelope.
+    MalformedEnvelope(serde_json::Error),
+    /// Slack disabled the connection, for example because Socket Mode was turned off for the app.
+    LinkDisabled,
+    /// The connection was closed.
+    Closed,
+}
+
+impl<E: Error> From<tungstenite::Error> for SocketModeError<E> {
+    fn from(e: tungstenite::Error) -> Self {
+        SocketModeError::WebSocket(Box::new(e))
+    }
+}
+
+impl<E: Error> fmt::Display for SocketModeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SocketModeError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            SocketModeError::Open(ref e) => e.description(),
+            SocketModeError::MissingUrl => "apps.connections.open didn't return a websocket URL",
+            SocketModeError::WebSocket(ref e) => e.description(),
+            SocketModeError::MalformedEnvelope(ref e) => e.description(),
+            SocketModeError::LinkDisabled => "Slack disabled the Socket Mode connection",
+            SocketModeError::Closed => "the connection was closed",
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            SocketModeError::Open(ref e) => Some(e),
+            SocketModeError::WebSocket(ref e) => Some(&**e),
+            SocketModeError::MalformedEnvelope(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use event::Event;
+    use std::net::TcpListener;
+    use tests::NoError;
+
+    type Script = fn(&mut WebSocket<TcpStream>);
+
+    /// Serves one connection with each script in turn, returning the server's URL.
+    fn serve(scripts: Vec<Script>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            for script in scripts {
+                let (stream, _) = listener.accept().unwrap();
+                let mut socket = tungstenite::accept(stream).unwrap();
+                script(&mut socket);
+            }
+        });
+        url
+    }
+
+    fn send(socket: &mut WebSocket<TcpStream>, message: Value) {
+        socket.send(Frame::Text(message.to_string())).unwrap();
+    }
+
+    fn read_json(socket: &mut WebSocket<TcpStream>) -> Value {
+        match socket.read().unwrap() {
+            Frame::Text(text) => serde_json::from_str(&text).unwrap(),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        seen: Vec<String>,
+    }
+
+    impl Handler for Recorder {
+        fn events_api(&mut self, payload: &events_api::Payload) -> Option<Value> {
+            if let events_api::Payload::EventCallback(ref callback) = *payload {
+                if let Event::AppMention(ref mention) = *callback.event {
+                    self.seen.push(format!("mention {}", mention.text.as_deref().unwrap_or("")));
+                }
+            }
+            None
+        }
+
+        fn slash_commands(&mut self, command: &SlashCommand) -> Option<Response> {
+            self.seen.push(format!("command {}", command.command));
+            Some(Response::ephemeral("done"))
+        }
+
+        fn interactive(&mut self, interaction: &Interaction) -> Option<ViewResponse> {
+            self.seen.push(format!("interaction {}", interaction.ty()));
+            Some(ViewResponse::Clear)
+        }
+
+        fn unknown(&mut self, ty: &str, _payload: &Value) -> Option<Value> {
+            self.seen.push(format!("unknown {}", ty));
+            None
+        }
+    }
+
+    #[test]
+    fn test_client_acks_and_refreshes() {
+        let url = serve(vec![
+            |socket| {
+                send(socket, ::serde_json::json!({"type": "hello", "num_connections": 1}));
+                send(socket, ::serde_json::json!({
+                    "envelope_id": "e1",
+                    "type": "events_api",
+                    "accepts_response_payload": false,
+                    "payload": {"type": "event_callback", "event": {"type": "app_mention", "text": "hi"}},
+                }));
+                assert_eq!(::serde_json::json!({"envelope_id": "e1"}), read_json(socket));
+                send(socket, ::serde_json::json!({"type": "disconnect", "reason": "refresh_requested"}));
+            },
+            |socket| {
+                send(socket, ::serde_json::json!({"type": "hello", "num_connections": 1}));
+                send(socket, ::serde_json::json!({
+                    "envelope_id": "e2",
+                    "type": "slash_commands",
+                    "accepts_response_payload": true,
+                    "payload": {
+                        "command": "/deploy",
+                        "text": "now",
+                        "user_id": "U1",
+                        "channel_id": "C1",
+                        "team_id": "T1",
+                        "trigger_id": "1.2.3",
+                        "response_url": "https://hooks.slack.com/commands/T1/1/2",
+                    },
+                }));
+                assert_eq!(
+                    ::serde_json::json!({"envelope_id": "e2", "payload": {"response_type": "ephemeral", "text": "done"}}),
+                    read_json(socket)
+                );
+                send(socket, ::serde_json::json!({
+                    "envelope_id": "e3",
+                    "type": "interactive",
+                    "accepts_response_payload": true,
+                    "payload": {"type": "view_submission", "user": {"id": "U1"}, "view": {"type": "modal", "blocks": []}},
+                }));
+                assert_eq!(
+                    ::serde_json::json!({"envelope_id": "e3", "payload": {"response_action": "clear"}}),
+                    read_json(socket)
+                );
+                send(socket, ::serde_json::json!({"type": "disconnect", "reason": "link_disabled"}));
+            },
+        ]);
+
+        let mut connections = 0;
+        let mut client = Client::<NoError>::connect_with(Config::default(), || {
+            connections += 1;
+            Ok(url.clone())
+        }).unwrap();
+        let mut recorder = Recorder::default();
+        match client.run(&mut recorder) {
+            Err(SocketModeError::LinkDisabled) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+        drop(client);
+        assert_eq!(vec!["mention hi", "command /deploy", "interaction view_submission"], recorder.seen);
+        assert_eq!(2, connections);
+    }
+
+    #[test]
+    fn test_client_acks_malformed_payloads() {
+        let url = serve(vec![|socket| {
+            send(socket, ::serde_json::json!({"type": "hello", "num_connections": 1}));
+            send(socket, ::serde_json::json!({
+                "envelope_id": "e1",
+                "type": "slash_commands",
+                "accepts_response_payload": true,
+                "payload": {"command": "/deploy"},
+            }));
+            assert_eq!(::serde_json::json!({"envelope_id": "e1"}), read_json(socket));
+            socket.send(Frame::Text("not json".into())).unwrap();
+            send(socket, ::serde_json::json!({
+                "envelope_id": "e2",
+                "type": "events_api",
+                "payload": {"type": "event_callback", "event": {"type": "app_mention", "text": "still here"}},
+            }));
+            assert_eq!(::serde_json::json!({"envelope_id": "e2"}), read_json(socket));
+            send(socket, ::serde_json::json!({"type": "disconnect", "reason": "link_disabled"}));
+        }]);
+
+        let mut client = Client::<NoError>::connect_with(Config::default(), || Ok(url.clone())).unwrap();
+        let mut recorder = Recorder::default();
+        match client.run(&mut recorder) {
+            Err(SocketModeError::LinkDisabled) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(vec!["unknown slash_commands", "mention still here"], recorder.seen);
+    }
+
+    #[test]
+    fn test_client_keeps_connection_after_malformed_message() {
+        let url = serve(vec![|socket| {
+            send(socket, ::serde_json::json!({"type": "hello", "num_connections": 1}));
+            socket.send(Frame::Text("not json".into())).unwrap();
+            send(socket, ::serde_json::json!({"envelope_id": "e1", "type": "events_api", "payload": {}}));
+            socket.close(None).unwrap();
+            while socket.read().is_ok() {}
+        }]);
+
+        let config = Config { reconnect: false, ..Config::default() };
+        let mut connections = 0;
+        let client = Client::<NoError>::connect_with(config, || {
+            connections += 1;
+            Ok(url.clone())
+        }).unwrap();
+
+        let envelopes = client.collect::<Vec<_>>();
+        assert_eq!(2, envelopes.len());
+        match envelopes[0] {
+            Err(SocketModeError::MalformedEnvelope(_)) => {}
+            ref other => panic!("unexpected {:?}", other),
+        }
+        match envelopes[1] {
+            Ok(ref envelope) => assert_eq!("e1", envelope.envelope_id),
+            ref other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(1, connections);
+    }
+}
@@ -0,0 +1,172 @@
+//! Work with a message thread as a unit.
+//!
+//! A [`Thread`] holds a thread's parent message and its replies in chronological order, and knows
+//! where to post new replies.
+//!
+//! [`Thread`]: struct.Thread.html
+
+use history::{self, ConversationKind, HistoryError};
+use requests::SlackWebRequestSender;
+use types::{Message, ThreadInfo};
+use chat;
+
+/// A thread parent together with all of its replies.
+#[derive(Clone, Debug)]
+pub struct Thread {
+    /// The kind of conversation `channel` refers to.
+    pub kind: ConversationKind,
+    /// The conversation the thread lives in.
+    pub channel: String,
+    /// The `ts` of the parent message, which identifies the thread.
+    pub thread_ts: String,
+    /// The message that started the thread, if Slack returned it.
+    pub parent: Option<Message>,
+    /// Every reply to the parent, oldest first.
+    pub replies: Vec<Message>,
+    /// Reply count and completeness as reported by Slack.
+    pub info: Option<ThreadInfo>,
+}
+
+impl Thread {
+    /// Loads the parent and replies of the thread started by `thread_ts` in `channel`.
+    pub fn load<R>(
+        client: &R,
+        token: &str,
+        kind: ConversationKind,
+        channel: &str,
+        thread_ts: &str,
+    ) -> Result<Thread, HistoryError<R::Error>>
+    where
+        R: SlackWebRequestSender,
+    {
+        let (messages, info) = history::thread(client, token, kind, channel, thread_ts)?;
+        let mut parent = None;
+        let mut replies = Vec::with_capacity(messages.len());
+        for message in messages {
+            if parent.is_none() && message.ts() == Some(thread_ts) {
+                parent = Some(message);
+            } else {
+                replies.push(message);
+            }
+        }
+
+        Ok(Thread {
+            kind,
+            channel: channel.to_owned(),
+            thread_ts: thread_ts.to_owned(),
+            parent,
+            replies,
+            info,
+        })
+    }
+
+    /// Fetches the thread again, replacing the parent and replies with their current state.
+    pub fn refresh<R>(&mut self, client: &R, token: &str) -> Result<(), HistoryError<R::Error>>
+    where
+        R: SlackWebRequestSender,
+    {
+        *self = Thread::load(client, token, self.kind, &self.channel, &self.thread_ts)?;
+        Ok(())
+    }
+
+    /// The number of replies loaded.
+    pub fn reply_count(&self) -> usize {
+        self.replies.len()
+    }
+
+    /// The users who replied, in the order of their first reply and without duplicates.
+    ///
+    /// Replies posted by bots without a user are not included.
+    pub fn reply_users(&self) -> Vec<&str> {
+        let mut users = Vec::new();
+        for user in self.replies.iter().filter_map(Message::user) {
+            if !users.contains(&user) {
+                users.push(user);
+            }
+        }
+        users
+    }
+
+    /// The most recent reply, if there are any.
+    pub fn latest_reply(&self) -> Option<&Message> {
+        self.replies.last()
+    }
+
+    /// Returns a `chat.postMessage` request which replies to this thread with `text`.
+    ///
+    /// Use this to set further options before sending the request yourself.
+    pub fn reply_request<'a>(&'a self, text: &'a str) -> chat::PostMessageRequest<'a> {
+        chat::PostMessageRequest::new(&self.channel[..], text).thread_ts(&self.thread_ts[..])
+    }
+
+    /// Posts `text` as a reply to this thread.
+    pub fn reply<R>(
+        &self,
+        client: &R,
+        token: &str,
+        text: &str,
+    ) -> Result<chat::PostMessageResponse, chat::PostMessageError<R::Error>>
+    where
+        R: SlackWebRequestSender,
+    {
+        chat::post_message(client, token, &self.reply_request(text))
+    }
+
+    /// Posts `text` as a reply to this thread which is also shown in the conversation.
+    pub fn reply_broadcast<R>(
+        &self,
+        client: &R,
+        token: &str,
+        text: &str,
+    ) -> Result<chat::PostMessageResponse, chat::PostMessageError<R::Error>>
+    where
+        R: SlackWebRequestSender,
+    {
+        chat::post_message(client, token, &self.reply_request(text).reply_broadcast(true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tests::{param, Replay};
+
+    const REPLIES: &str = r#"{"ok": true, "thread_info": {"complete": true, "count": 3}, "messages": [
+        {"type": "message", "ts": "1500000003.000000", "thread_ts": "1500000001.000000", "user": "U1", "text": "c"},
+        {"type": "message", "ts": "1500000001.000000", "thread_ts": "1500000001.000000", "user": "U1", "text": "a"},
+        {"type": "message", "ts": "1500000004.000000", "thread_ts": "1500000001.000000", "user": "U2", "text": "d"},
+        {"type": "message", "ts": "1500000002.000000", "thread_ts": "1500000001.000000", "user": "U3", "text": "b"}]}"#;
+
+    fn text(message: &Message) -> &str {
+        match *message {
+            Message::Standard(ref m) => m.text.as_deref().unwrap(),
+            ref other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_orders_replies_and_separates_parent() {
+        let client = Replay::new(vec![REPLIES]);
+        let thread = Thread::load(&client, "token", ConversationKind::Im, "D1", "1500000001.000000").unwrap();
+
+        assert_eq!("a", text(thread.parent.as_ref().unwrap()));
+        assert_eq!(vec!["b", "c", "d"], thread.replies.iter().map(text).collect::<Vec<_>>());
+        assert_eq!(3, thread.reply_count());
+        assert_eq!(vec!["U3", "U1", "U2"], thread.reply_users());
+        assert_eq!("d", text(thread.latest_reply().unwrap()));
+        assert!(client.requests.borrow()[0].0.ends_with("im.replies"));
+    }
+
+    #[test]
+    fn test_reply_broadcast_posts_to_thread() {
+        let client = Replay::new(vec![REPLIES, r#"{"ok": true, "ts": "1500000005.000000"}"#]);
+        let thread = Thread::load(&client, "token", ConversationKind::Channel, "C1", "1500000001.000000").unwrap();
+        thread.reply_broadcast(&client, "token", "e").unwrap();
+
+        let requests = client.requests.borrow();
+        assert!(requests[1].0.ends_with("chat.postMessage"));
+        assert_eq!(Some("C1".to_owned()), param(&requests[1], "channel"));
+        assert_eq!(Some("1500000001.000000".to_owned()), param(&requests[1], "thread_ts"));
+        assert_eq!(Some("1".to_owned()), param(&requests[1], "reply_broadcast"));
+    }
+}
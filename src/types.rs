@@ -1,18 +1,56 @@
 use std::collections::HashMap;
 use std::fmt;
+use std::num::ParseFloatError;
+use std::str::FromStr;
 use serde::de::{Visitor, Error, Unexpected, Deserializer};
+use serde::{Serialize, Serializer};
 
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
 pub struct Timestamp {
     repr: f64,
 }
 
+impl Timestamp {
+    /// Creates a timestamp from (fractional) seconds since the Unix epoch.
+    pub fn new(seconds: f64) -> Self {
+        Timestamp { repr: seconds }
+    }
+
+    /// Returns the number of (fractional) seconds since the Unix epoch.
+    pub fn as_f64(&self) -> f64 {
+        self.repr
+    }
+}
+
+impl From<f64> for Timestamp {
+    fn from(seconds: f64) -> Self {
+        Timestamp::new(seconds)
+    }
+}
+
+/// Parses a timestamp in the `"1503435956.000247"` format Slack uses for message `ts` fields.
+impl FromStr for Timestamp {
+    type Err = ParseFloatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Timestamp::new)
+    }
+}
+
 impl fmt::Display for Timestamp {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.repr)
     }
 }
 
+/// Serializes as a number of seconds, which is what Slack expects in requests (for example for
+/// the `ts` of an attachment).
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.repr)
+    }
+}
+
 /// Deserialize a maybe-string timestamp into a Timestamp.
 pub fn deserialize_timestamp<'d, D: Deserializer<'d>>(d: D) -> Result<Option<Timestamp>, D::Error> {
     struct TimestampVisitor;
@@ -45,167 +83,381 @@ pub fn deserialize_timestamp<'d, D: Deserializer<'d>>(d: D) -> Result<Option<Tim
     d.deserialize_any(TimestampVisitor)
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Bot {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icons: Option<BotIcons>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BotIcons {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_36: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_48: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_72: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Channel {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub accepted_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_channel: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_general: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_member: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_moved: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_mpim: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_org_shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_pending_ext_shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_read: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub latest: Option<::Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub members: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name_normalized: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub num_members: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub previous_names: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub purpose: Option<ChannelPurpose>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<ChannelTopic>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unlinked: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count_display: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChannelPurpose {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_set: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChannelTopic {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_set: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Conversation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_channel: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_ext_shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_general: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_im: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_member: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_mpim: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_open: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_org_shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_pending_ext_shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_user_deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<::Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_normalized: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_members: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_names: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<ConversationPurpose>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<ConversationTopic>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unlinked: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_count_display: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ConversationPurpose {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_set: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ConversationTopic {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_set: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct File {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub comments_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub display_as_bot: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edit_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub editable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub external_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub filetype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub groups: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ims: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub initial_comment: Option<::FileComment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_external: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_public: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_starred: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lines: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lines_more: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mimetype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub num_stars: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub permalink: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub permalink_public: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pinned_to: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pretty_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub preview: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub preview_highlight: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub public_url_shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reactions: Option<Vec<::Reaction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_160: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_360: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_360_gif: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_360_h: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_360_w: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_480: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_480_h: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_480_w: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_64: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_80: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url_private: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url_private_download: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FileComment {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reactions: Option<Vec<::Reaction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Group {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_group: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_mpim: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_read: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub latest: Option<::Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub members: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub purpose: Option<GroupPurpose>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<GroupTopic>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count_display: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GroupPurpose {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_set: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GroupTopic {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_set: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Im {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_im: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_open: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_user_deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_count_display: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
@@ -237,6 +489,19 @@ pub enum Message {
     PinnedItem(MessagePinnedItem),
     ReplyBroadcast(MessageReplyBroadcast),
     UnpinnedItem(MessageUnpinnedItem),
+    BotAdd(MessageBotAdd),
+    BotRemove(MessageBotRemove),
+    ChannelConvertToPrivate(MessageChannelConvertToPrivate),
+    ShRoomCreated(MessageShRoomCreated),
+    ThreadBroadcast(MessageThreadBroadcast),
+    Tombstone(MessageTombstone),
+    /// A message with a subtype this crate does not model yet.
+    Unknown {
+        /// The unrecognised `subtype`.
+        subtype: String,
+        /// The complete message as received.
+        raw: ::serde_json::Value,
+    },
 }
 
 impl<'de> ::serde::Deserialize<'de> for Message {
@@ -246,35 +511,6 @@ impl<'de> ::serde::Deserialize<'de> for Message {
     {
         use serde::de::Error as SerdeError;
 
-        const VARIANTS: &'static [&'static str] = &[
-            "standard",
-            "bot_message",
-            "channel_archive",
-            "channel_join",
-            "channel_leave",
-            "channel_name",
-            "channel_purpose",
-            "channel_topic",
-            "channel_unarchive",
-            "file_comment",
-            "file_mention",
-            "file_share",
-            "group_archive",
-            "group_join",
-            "group_leave",
-            "group_name",
-            "group_purpose",
-            "group_topic",
-            "group_unarchive",
-            "me_message",
-            "message_changed",
-            "message_deleted",
-            "message_replied",
-            "pinned_item",
-            "reply_broadcast",
-            "unpinned_item",
-        ];
-
         let value = ::serde_json::Value::deserialize(deserializer)?;
         if let Some(ty_val) = value.get("subtype") {
             if let Some(ty) = ty_val.as_str() {
@@ -409,7 +645,40 @@ impl<'de> ::serde::Deserialize<'de> for Message {
                             .map(Message::UnpinnedItem)
                             .map_err(|e| D::Error::custom(&format!("{}", e)))
                     }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
+                    "bot_add" => {
+                        ::serde_json::from_value::<MessageBotAdd>(value.clone())
+                            .map(Message::BotAdd)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "bot_remove" => {
+                        ::serde_json::from_value::<MessageBotRemove>(value.clone())
+                            .map(Message::BotRemove)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "channel_convert_to_private" => {
+                        ::serde_json::from_value::<MessageChannelConvertToPrivate>(value.clone())
+                            .map(Message::ChannelConvertToPrivate)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "sh_room_created" => {
+                        ::serde_json::from_value::<MessageShRoomCreated>(value.clone())
+                            .map(Message::ShRoomCreated)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "thread_broadcast" => {
+                        ::serde_json::from_value::<MessageThreadBroadcast>(value.clone())
+                            .map(Message::ThreadBroadcast)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "tombstone" => {
+                        ::serde_json::from_value::<MessageTombstone>(value.clone())
+                            .map(Message::Tombstone)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    _ => Ok(Message::Unknown {
+                        subtype: ty.to_owned(),
+                        raw: value.clone(),
+                    }),
                 }
             } else {
                 Err(D::Error::invalid_type(
@@ -425,672 +694,1325 @@ impl<'de> ::serde::Deserialize<'de> for Message {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl ::serde::Serialize for Message {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        match *self {
+            Message::Standard(ref inner) => inner.serialize(serializer),
+            Message::BotMessage(ref inner) => inner.serialize(serializer),
+            Message::ChannelArchive(ref inner) => inner.serialize(serializer),
+            Message::ChannelJoin(ref inner) => inner.serialize(serializer),
+            Message::ChannelLeave(ref inner) => inner.serialize(serializer),
+            Message::ChannelName(ref inner) => inner.serialize(serializer),
+            Message::ChannelPurpose(ref inner) => inner.serialize(serializer),
+            Message::ChannelTopic(ref inner) => inner.serialize(serializer),
+            Message::ChannelUnarchive(ref inner) => inner.serialize(serializer),
+            Message::FileComment(ref inner) => inner.serialize(serializer),
+            Message::FileMention(ref inner) => inner.serialize(serializer),
+            Message::FileShare(ref inner) => inner.serialize(serializer),
+            Message::GroupArchive(ref inner) => inner.serialize(serializer),
+            Message::GroupJoin(ref inner) => inner.serialize(serializer),
+            Message::GroupLeave(ref inner) => inner.serialize(serializer),
+            Message::GroupName(ref inner) => inner.serialize(serializer),
+            Message::GroupPurpose(ref inner) => inner.serialize(serializer),
+            Message::GroupTopic(ref inner) => inner.serialize(serializer),
+            Message::GroupUnarchive(ref inner) => inner.serialize(serializer),
+            Message::MeMessage(ref inner) => inner.serialize(serializer),
+            Message::MessageChanged(ref inner) => inner.serialize(serializer),
+            Message::MessageDeleted(ref inner) => inner.serialize(serializer),
+            Message::MessageReplied(ref inner) => inner.serialize(serializer),
+            Message::PinnedItem(ref inner) => inner.serialize(serializer),
+            Message::ReplyBroadcast(ref inner) => inner.serialize(serializer),
+            Message::UnpinnedItem(ref inner) => inner.serialize(serializer),
+            Message::BotAdd(ref inner) => inner.serialize(serializer),
+            Message::BotRemove(ref inner) => inner.serialize(serializer),
+            Message::ChannelConvertToPrivate(ref inner) => inner.serialize(serializer),
+            Message::ShRoomCreated(ref inner) => inner.serialize(serializer),
+            Message::ThreadBroadcast(ref inner) => inner.serialize(serializer),
+            Message::Tombstone(ref inner) => inner.serialize(serializer),
+            Message::Unknown { ref raw, .. } => raw.serialize(serializer),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageBotAdd {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageBotMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<::MessageStandardAttachment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<::blocks::Block>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icons: Option<MessageBotMessageIcons>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<::Reaction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageBotMessageIcons {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_36: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_48: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_72: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageBotRemove {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelArchive {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub members: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageChannelConvertToPrivate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelJoin {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inviter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelLeave {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelName {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub old_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelPurpose {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub purpose: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelTopic {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelUnarchive {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageFileComment {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<::FileComment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file: Option<::File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageFileMention {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file: Option<::File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageFileShare {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file: Option<::File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<::Reaction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub upload: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupArchive {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub members: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupJoin {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inviter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupLeave {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupName {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub old_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupPurpose {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub purpose: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupTopic {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupUnarchive {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMeMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<::blocks::Block>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<::Reaction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChanged {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub event_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<MessageMessageChangedMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub previous_message: Option<MessageMessageChangedPreviousMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<::blocks::Block>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited: Option<MessageMessageChangedMessageEdited>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_read: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub replies: Option<Vec<MessageMessageChangedMessageReply>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subscribed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thread_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedMessageEdited {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedMessageReply {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedPreviousMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited: Option<MessageMessageChangedPreviousMessageEdited>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_read: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub replies: Option<Vec<MessageMessageChangedPreviousMessageReply>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subscribed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thread_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedPreviousMessageEdited {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedPreviousMessageReply {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageDeleted {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deleted_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub event_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub previous_message: Option<MessageMessageDeletedPreviousMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageDeletedPreviousMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited: Option<MessageMessageDeletedPreviousMessageEdited>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_read: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub replies: Option<Vec<MessageMessageDeletedPreviousMessageReply>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subscribed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thread_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageDeletedPreviousMessageEdited {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageDeletedPreviousMessageReply {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageReplied {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub event_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<MessageMessageRepliedMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thread_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageRepliedMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited: Option<MessageMessageRepliedMessageEdited>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_read: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub replies: Option<Vec<MessageMessageRepliedMessageReply>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subscribed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thread_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageRepliedMessageEdited {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageRepliedMessageReply {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessagePinnedItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub item: Option<MessagePinnedItemItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub item_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessagePinnedItemItem {}
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageReplyBroadcast {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attachments: Option<Vec<MessageReplyBroadcastAttachment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub event_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageReplyBroadcastAttachment {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_subname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fallback: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub footer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub from_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mrkdwn_in: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageShRoomCreated {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_notifications: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub room: Option<MessageShRoomCreatedRoom>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageShRoomCreatedRoom {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_end: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_start: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_ended: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_dm_call: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub participants: Option<Vec<String>>,
+}
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageStandard {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attachments: Option<Vec<MessageStandardAttachment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<::blocks::Block>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited: Option<MessageStandardEdited>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub event_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<::Reaction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_broadcast: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub source_team: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thread_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct MessageStandardAttachment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<MessageStandardAttachmentAction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fallback: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<Vec<MessageStandardAttachmentField>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub footer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub footer_icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mrkdwn_in: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pretext: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title_link: Option<String>,
     #[serde(deserialize_with = "deserialize_timestamp")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct MessageStandardAttachmentAction {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct MessageStandardAttachmentField {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub short: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageStandardEdited {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageThreadBroadcast {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<::blocks::Block>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<::Reaction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root: Option<MessageThreadBroadcastRoot>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageThreadBroadcastRoot {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_reply: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_users: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_users_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageTombstone {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_reply: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageUnpinnedItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub item: Option<MessageUnpinnedItemItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub item_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageUnpinnedItemItem {}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Mpim {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_group: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_mpim: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_read: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub latest: Option<::Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub members: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count_display: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Paging {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub page: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pages: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub total: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Reaction {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub users: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Reminder {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub complete_ts: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub recurring: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub time: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ResponseMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Team {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub email_domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon: Option<TeamIcon>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TeamIcon {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_102: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_132: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_34: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_44: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_68: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_88: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_default: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ThreadInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub complete: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub has_2fa: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_admin: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_app_user: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_bot: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_owner: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_primary_owner: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_restricted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_ultra_restricted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub profile: Option<::UserProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub real_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub two_factor_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tz: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tz_label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tz_offset: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub updated: Option<f32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Usergroup {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub auto_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date_create: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date_delete: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date_update: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deleted_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_external: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_usergroup: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub prefs: Option<UsergroupPrefs>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user_count: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UsergroupPrefs {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub groups: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UserProfile {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub display_name_normalized: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     #[serde(deserialize_with = "::optional_struct_or_empty_array")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<HashMap<String, UserProfileFields>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub guest_channels: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_192: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_24: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_32: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_48: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_512: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_72: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_original: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub real_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub real_name_normalized: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub skype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_emoji: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UserProfileFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub alt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
@@ -0,0 +1,413 @@
+//! A live model of a workspace's users and conversations.
+//!
+//! `rtm.start` returns a snapshot of the workspace, which [`WorkspaceState`] keeps up to date by
+//! applying the events that follow:
+//!
+//! ```no_run
+//! # fn run<R: slack_api::requests::SlackWebRequestSender>(client: &R, events: Vec<slack_api::event::Event>) {
+//! use slack_api::rtm::{self, StartRequest};
+//! use slack_api::workspace::WorkspaceState;
+//!
+//! let start = rtm::start(client, "xoxb-token", &StartRequest::default()).unwrap();
+//! let mut state = WorkspaceState::new(start);
+//! for event in events {
+//!     state.apply(&event);
+//! }
+//! println!("{:?}", state.channel_by_name("#general").and_then(|c| c.id.as_ref()));
+//! # }
+//! ```
+//!
+//! [`WorkspaceState`]: struct.WorkspaceState.html
+
+use std::collections::HashMap;
+
+use event::{ConversationInfo, Event, MarkedEvent, MembershipEvent};
+use render::{self, NameResolver};
+use rtm::StartResponse;
+use types::{Bot, Channel, Group, Im, Mpim, Team, User};
+
+/// The users, bots and conversations of a workspace, by id.
+#[derive(Clone, Debug, Default)]
+pub struct WorkspaceState {
+    pub team: Option<Team>,
+    /// The connected user.
+    pub slf: Option<User>,
+    users: HashMap<String, User>,
+    bots: HashMap<String, Bot>,
+    channels: HashMap<String, Channel>,
+    groups: HashMap<String, Group>,
+    ims: HashMap<String, Im>,
+    mpims: HashMap<String, Mpim>,
+}
+
+/// Collects items with an id into a map.
+macro_rules! by_id {
+    ($items:expr) => {
+        $items
+            .into_iter()
+            .flatten()
+            .filter_map(|item| item.id.clone().map(|id| (id, item)))
+            .collect()
+    };
+}
+
+impl WorkspaceState {
+    /// Creates the state from the snapshot returned by `rtm.start`.
+    pub fn new(start: StartResponse) -> Self {
+        WorkspaceState {
+            team: start.team,
+            slf: start.slf,
+            users: by_id!(start.users),
+            bots: by_id!(start.bots),
+            channels: by_id!(start.channels),
+            groups: by_id!(start.groups),
+            ims: by_id!(start.ims),
+            mpims: by_id!(start.mpims),
+        }
+    }
+
+    pub fn user(&self, id: &str) -> Option<&User> {
+        self.users.get(id)
+    }
+
+    /// Finds a user by their username, or failing that their display name, with or without the
+    /// leading `@`.
+    pub fn user_by_name(&self, name: &str) -> Option<&User> {
+        let name = name.trim_start_matches('@');
+        let display_name = |user: &User| user.profile.as_ref().and_then(|p| p.display_name.clone());
+        self.users.values().find(|user| user.name.as_deref() == Some(name))
+            .or_else(|| self.users.values().find(|user| display_name(user).as_deref() == Some(name)))
+    }
+
+    pub fn users(&self) -> impl Iterator<Item = &User> {
+        self.users.values()
+    }
+
+    pub fn bot(&self, id: &str) -> Option<&Bot> {
+        self.bots.get(id)
+    }
+
+    /// A public channel.
+    pub fn channel(&self, id: &str) -> Option<&Channel> {
+        self.channels.get(id)
+    }
+
+    /// Finds a public channel by name, with or without the leading `#`.
+    pub fn channel_by_name(&self, name: &str) -> Option<&Channel> {
+        let name = name.trim_start_matches('#');
+        self.channels.values().find(|channel| channel.name.as_deref() == Some(name))
+    }
+
+    pub fn channels(&self) -> impl Iterator<Item = &Channel> {
+        self.channels.values()
+    }
+
+    /// A private channel.
+    pub fn group(&self, id: &str) -> Option<&Group> {
+        self.groups.get(id)
+    }
+
+    /// Finds a private channel by name, with or without the leading `#`.
+    pub fn group_by_name(&self, name: &str) -> Option<&Group> {
+        let name = name.trim_start_matches('#');
+        self.groups.values().find(|group| group.name.as_deref() == Some(name))
+    }
+
+    pub fn groups(&self) -> impl Iterator<Item = &Group> {
+        self.groups.values()
+    }
+
+    pub fn im(&self, id: &str) -> Option<&Im> {
+        self.ims.get(id)
+    }
+
+    /// The IM with a user.
+    pub fn im_with(&self, user: &str) -> Option<&Im> {
+        self.ims.values().find(|im| im.user.as_deref() == Some(user))
+    }
+
+    pub fn mpim(&self, id: &str) -> Option<&Mpim> {
+        self.mpims.get(id)
+    }
+
+    /// Updates the state from an event. Events which don't affect it are ignored.
+    pub fn apply(&mut self, event: &Event) {
+        match *event {
+            Event::ChannelCreated(ref e) => {
+                if let Some(channel) = e.channel.as_ref().and_then(new_channel) {
+                    let id = channel.id.clone().unwrap_or_default();
+                    self.channels.entry(id).or_insert(channel);
+                }
+            }
+            Event::ChannelJoined(ref e) => {
+                if let Some(mut channel) = e.channel.clone() {
+                    channel.is_member = Some(true);
+                    if let Some(id) = channel.id.clone() {
+                        self.channels.insert(id, channel);
+                    }
+                }
+            }
+            Event::ChannelLeft(ref e) => {
+                if let Some(channel) = e.channel.as_ref().and_then(|id| self.channels.get_mut(id)) {
+                    channel.is_member = Some(false);
+                }
+            }
+            Event::ChannelRename(ref e) => {
+                let info = e.channel.as_ref();
+                if let Some(channel) = info.and_then(|c| c.id.as_ref()).and_then(|id| self.channels.get_mut(id)) {
+                    channel.name = info.and_then(|c| c.name.clone());
+                    channel.name_normalized = channel.name.clone();
+                }
+            }
+            Event::ChannelArchive(ref e) | Event::ChannelUnarchive(ref e) => {
+                let archived = matches!(*event, Event::ChannelArchive(_));
+                if let Some(channel) = e.channel.as_ref().and_then(|id| self.channels.get_mut(id)) {
+                    channel.is_archived = Some(archived);
+                }
+            }
+            Event::ChannelDeleted(ref e) => {
+                if let Some(ref id) = e.channel {
+                    self.channels.remove(id);
+                }
+            }
+            Event::ChannelMarked(ref e) => {
+                if let Some(channel) = e.channel.as_ref().and_then(|id| self.channels.get_mut(id)) {
+                    mark(e, &mut channel.last_read, &mut channel.unread_count, &mut channel.unread_count_display);
+                }
+            }
+            Event::GroupJoined(ref e) => {
+                if let Some(group) = e.channel.clone() {
+                    if let Some(id) = group.id.clone() {
+                        self.groups.insert(id, group);
+                    }
+                }
+            }
+            Event::GroupLeft(ref e) => {
+                // Leaving a private channel also loses access to it.
+                if let Some(ref id) = e.channel {
+                    self.groups.remove(id);
+                }
+            }
+            Event::GroupRename(ref e) => {
+                let info = e.channel.as_ref();
+                if let Some(group) = info.and_then(|c| c.id.as_ref()).and_then(|id| self.groups.get_mut(id)) {
+                    group.name = info.and_then(|c| c.name.clone());
+                }
+            }
+            Event::GroupArchive(ref e) | Event::GroupUnarchive(ref e) => {
+                let archived = matches!(*event, Event::GroupArchive(_));
+                if let Some(group) = e.channel.as_ref().and_then(|id| self.groups.get_mut(id)) {
+                    group.is_archived = Some(archived);
+                }
+            }
+            Event::GroupMarked(ref e) => {
+                if let Some(group) = e.channel.as_ref().and_then(|id| self.groups.get_mut(id)) {
+                    mark(e, &mut group.last_read, &mut group.unread_count, &mut group.unread_count_display);
+                } else if let Some(mpim) = e.channel.as_ref().and_then(|id| self.mpims.get_mut(id)) {
+                    mark(e, &mut mpim.last_read, &mut mpim.unread_count, &mut mpim.unread_count_display);
+                }
+            }
+            Event::ImCreated(ref e) => {
+                if let Some(im) = e.channel.clone() {
+                    if let Some(id) = im.id.clone() {
+                        self.ims.insert(id, im);
+                    }
+                }
+            }
+            Event::ImOpen(ref e) | Event::ImClose(ref e) => {
+                let open = matches!(*event, Event::ImOpen(_));
+                if let Some(im) = e.channel.as_ref().and_then(|id| self.ims.get_mut(id)) {
+                    im.is_open = Some(open);
+                }
+            }
+            Event::ImMarked(ref e) => {
+                if let Some(im) = e.channel.as_ref().and_then(|id| self.ims.get_mut(id)) {
+                    mark(e, &mut im.last_read, &mut im.unread_count, &mut im.unread_count_display);
+                }
+            }
+            Event::MpimJoined(ref e) => {
+                if let Some(mpim) = e.channel.clone() {
+                    if let Some(id) = mpim.id.clone() {
+                        self.mpims.insert(id, mpim);
+                    }
+                }
+            }
+            Event::MemberJoinedChannel(ref e) => self.update_members(e, true),
+            Event::MemberLeftChannel(ref e) => self.update_members(e, false),
+            Event::TeamJoin(ref e) | Event::UserChange(ref e) => {
+                if let Some(user) = e.user.clone() {
+                    if let Some(id) = user.id.clone() {
+                        if self.slf.as_ref().is_some_and(|slf| slf.id.as_ref() == Some(&id)) {
+                            self.slf = Some(user.clone());
+                        }
+                        self.users.insert(id, user);
+                    }
+                }
+            }
+            Event::TeamRename(ref e) => {
+                if let Some(ref mut team) = self.team {
+                    team.name = e.name.clone();
+                }
+            }
+            Event::TeamDomainChange(ref e) => {
+                if let Some(ref mut team) = self.team {
+                    team.domain = e.domain.clone();
+                }
+            }
+            Event::BotAdded(ref e) | Event::BotChanged(ref e) => {
+                if let Some(bot) = e.bot.clone() {
+                    if let Some(id) = bot.id.clone() {
+                        self.bots.insert(id, bot);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn update_members(&mut self, event: &MembershipEvent, joined: bool) {
+        let (channel, user) = match (event.channel.as_ref(), event.user.as_ref()) {
+            (Some(channel), Some(user)) => (channel, user),
+            _ => return,
+        };
+        let is_self = self.slf.as_ref().and_then(|slf| slf.id.as_ref()) == Some(user);
+        if let Some(channel) = self.channels.get_mut(channel) {
+            if update_member_list(&mut channel.members, user, joined) {
+                channel.num_members = channel.num_members.map(|n| if joined { n + 1 } else { n - 1 });
+            }
+            if is_self {
+                channel.is_member = Some(joined);
+            }
+        } else if let Some(group) = self.groups.get_mut(channel) {
+            update_member_list(&mut group.members, user, joined);
+        } else if let Some(mpim) = self.mpims.get_mut(channel) {
+            update_member_list(&mut mpim.members, user, joined);
+        }
+    }
+}
+
+impl NameResolver for WorkspaceState {
+    fn user_name(&self, id: &str) -> Option<String> {
+        self.users.get(id).and_then(render::user_name)
+    }
+
+    fn channel_name(&self, id: &str) -> Option<String> {
+        match self.channels.get(id) {
+            Some(channel) => channel.name.clone(),
+            None => self.groups.get(id).and_then(|group| group.name.clone()),
+        }
+    }
+}
+
+/// Builds a channel from the parts `channel_created` includes.
+fn new_channel(info: &ConversationInfo) -> Option<Channel> {
+    info.id.as_ref()?;
+    let mut channel: Channel = ::serde_json::from_value(::serde_json::json!({"is_channel": true})).ok()?;
+    channel.id = info.id.clone();
+    channel.name = info.name.clone();
+    channel.created = info.created.map(|created| created as i32);
+    channel.creator = info.creator.clone();
+    Some(channel)
+}
+
+fn mark(event: &MarkedEvent, last_read: &mut Option<String>, unread: &mut Option<i32>, display: &mut Option<i32>) {
+    if event.ts.is_some() {
+        *last_read = event.ts.clone();
+    }
+    if event.unread_count.is_some() {
+        *unread = event.unread_count;
+    }
+    if event.unread_count_display.is_some() {
+        *display = event.unread_count_display;
+    }
+}
+
+/// Adds or removes a user from a list of members if it is known, returning whether it changed.
+fn update_member_list(members: &mut Option<Vec<String>>, user: &str, joined: bool) -> bool {
+    let members = match *members {
+        Some(ref mut members) => members,
+        None => return false,
+    };
+    let position = members.iter().position(|member| member == user);
+    match (position, joined) {
+        (None, true) => members.push(user.to_owned()),
+        (Some(i), false) => {
+            members.remove(i);
+        }
+        _ => return false,
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    fn state() -> WorkspaceState {
+        let start: StartResponse = serde_json::from_value(serde_json::json!({
+            "ok": true,
+            "self": {"id": "U1", "name": "me"},
+            "team": {"id": "T1", "name": "Team", "domain": "team"},
+            "users": [{"id": "U1", "name": "me"}, {"id": "U2", "name": "bob", "profile": {"display_name": "Bobby"}}],
+            "channels": [{"id": "C1", "name": "general", "is_member": true, "members": ["U1"], "num_members": 1}],
+            "groups": [{"id": "G1", "name": "secret", "members": ["U1"]}],
+            "ims": [{"id": "D1", "user": "U2"}],
+            "bots": [{"id": "B1", "name": "bot"}],
+        })).unwrap();
+        WorkspaceState::new(start)
+    }
+
+    fn apply(state: &mut WorkspaceState, event: serde_json::Value) {
+        state.apply(&serde_json::from_value(event).unwrap());
+    }
+
+    #[test]
+    fn test_lookups() {
+        let state = state();
+        assert_eq!(Some("C1"), state.channel_by_name("#general").and_then(|c| c.id.as_deref()));
+        assert_eq!(Some("G1"), state.group_by_name("secret").and_then(|g| g.id.as_deref()));
+        assert_eq!(Some("U2"), state.user_by_name("@Bobby").and_then(|u| u.id.as_deref()));
+        assert_eq!(Some("D1"), state.im_with("U2").and_then(|im| im.id.as_deref()));
+        assert_eq!(Some("Bobby".to_owned()), state.user_name("U2"));
+        assert_eq!(Some("secret".to_owned()), state.channel_name("G1"));
+        assert!(state.bot("B1").is_some());
+    }
+
+    #[test]
+    fn test_apply_events() {
+        let mut state = state();
+        apply(&mut state, serde_json::json!({"type": "channel_created", "channel": {"id": "C2", "name": "new", "created": 1, "creator": "U2"}}));
+        apply(&mut state, serde_json::json!({"type": "channel_rename", "channel": {"id": "C1", "name": "lobby"}}));
+        apply(&mut state, serde_json::json!({"type": "channel_archive", "channel": "C2", "user": "U2"}));
+        apply(&mut state, serde_json::json!({"type": "member_joined_channel", "channel": "C1", "user": "U2"}));
+        apply(&mut state, serde_json::json!({"type": "member_left_channel", "channel": "C1", "user": "U1"}));
+        apply(&mut state, serde_json::json!({"type": "channel_marked", "channel": "C1", "ts": "5.0", "unread_count": 0}));
+        apply(&mut state, serde_json::json!({"type": "group_left", "channel": "G1"}));
+        apply(&mut state, serde_json::json!({"type": "user_change", "user": {"id": "U1", "name": "renamed"}}));
+        apply(&mut state, serde_json::json!({"type": "team_join", "user": {"id": "U3", "name": "carol"}}));
+
+        assert_eq!(None, state.channel_by_name("general").map(|c| c.id.clone()));
+        let lobby = state.channel_by_name("lobby").unwrap();
+        assert_eq!(Some(vec!["U2".to_owned()]), lobby.members);
+        assert_eq!((Some(1), Some(false)), (lobby.num_members, lobby.is_member));
+        assert_eq!((Some("5.0"), Some(0)), (lobby.last_read.as_deref(), lobby.unread_count));
+        assert_eq!(Some(true), state.channel("C2").and_then(|c| c.is_archived));
+        assert!(state.group("G1").is_none());
+        assert_eq!(Some("renamed"), state.slf.as_ref().and_then(|u| u.name.as_deref()));
+        assert_eq!(Some("U3"), state.user_by_name("carol").and_then(|u| u.id.as_deref()));
+    }
+
+    #[test]
+    fn test_apply_im_events() {
+        let mut state = state();
+        apply(&mut state, serde_json::json!({"type": "im_open", "channel": "D1", "user": "U2"}));
+        apply(&mut state, serde_json::json!({"type": "im_marked", "channel": "D1", "ts": "7.0", "unread_count": 2, "unread_count_display": 1}));
+        let im = state.im("D1").unwrap();
+        assert_eq!(Some(true), im.is_open);
+        assert_eq!((Some("7.0"), Some(2), Some(1)), (im.last_read.as_deref(), im.unread_count, im.unread_count_display));
+
+        apply(&mut state, serde_json::json!({"type": "im_close", "channel": "D1", "user": "U2"}));
+        assert_eq!(Some(false), state.im_with("U2").and_then(|im| im.is_open));
+    }
+}